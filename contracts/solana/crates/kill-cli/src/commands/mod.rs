@@ -0,0 +1,7 @@
+//! Subcommand implementations.  Each returns the JSON report printed with
+//! `--json`; human-readable output goes through `Ctx::say`.
+
+pub mod play;
+pub mod setup;
+pub mod stats;
+pub mod wallet;
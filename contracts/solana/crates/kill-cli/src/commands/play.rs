@@ -0,0 +1,224 @@
+//! Gameplay: spawn, move and kill as the wallet, and listing stacks.
+
+use anyhow::{anyhow, Result};
+use kill_sdk::instructions::game::{self, Strike};
+use kill_sdk::instructions::GameKeys;
+use kill_sdk::kill_game::instructions::kill::KillGuards;
+use kill_sdk::kill_game::state::AgentStack;
+use kill_sdk::{accounts, pda, Pubkey, GAME_PROGRAM_ID};
+use serde_json::{json, Value};
+
+use crate::context::Ctx;
+use crate::display::{coord, fmt_kill};
+
+pub fn spawn(ctx: &Ctx, stack_id: u16, units: u64) -> Result<Value> {
+    let wallet = ctx.wallet()?;
+    let gc = ctx.game_config()?;
+    let keys = GameKeys::from_config(&gc);
+    let params = ctx
+        .rpc
+        .account_data(&pda::game_params().0)?
+        .map(|data| accounts::game_params(&data))
+        .transpose()?;
+
+    ctx.say(format!("\nWallet      : {wallet}"));
+    ctx.say(format!("Season      : {}", keys.season));
+    ctx.say(format!("Stack ID    : {stack_id} {}", coord(stack_id)));
+    ctx.say(format!("Units       : {units}"));
+    if let Some(p) = &params {
+        ctx.say(format!(
+            "Cost        : {} KILL ({} KILL × {units} units)\n",
+            fmt_kill(p.spawn_cost.saturating_mul(units)),
+            fmt_kill(p.spawn_cost)
+        ));
+    }
+
+    let (ata, create) = ctx.ensure_ata(&wallet, &keys.kill_mint)?;
+    let mut ixs: Vec<_> = create.into_iter().collect();
+    ixs.push(game::spawn(&keys, wallet, wallet, ata, stack_id, units));
+    let outcome = ctx.send("Spawned", &ixs, &[])?;
+    Ok(json!({ "season": keys.season, "stack_id": stack_id, "units": units, "tx": outcome.to_json() }))
+}
+
+/// Move `units`/`reapers` between two of the wallet's stacks; with no
+/// amounts the whole `from` stack moves.
+pub fn move_units(
+    ctx: &Ctx,
+    from: u16,
+    to: u16,
+    units: Option<u64>,
+    reapers: Option<u64>,
+    close_empty: bool,
+) -> Result<Value> {
+    let wallet = ctx.wallet()?;
+    let gc = ctx.game_config()?;
+    let keys = GameKeys::from_config(&gc);
+    let (units, reapers) = match units {
+        Some(units) => (units, reapers.unwrap_or(0)),
+        None => {
+            let data = ctx
+                .rpc
+                .account_data(&pda::agent_stack(keys.season, &wallet, from).0)?
+                .ok_or_else(|| anyhow!("no stack {from} for {wallet} in season {}", keys.season))?;
+            let stack = accounts::agent_stack(&data)?;
+            (stack.units, stack.reapers)
+        }
+    };
+
+    ctx.say(format!("\nWallet    : {wallet}"));
+    ctx.say(format!("Move      : stack {from} {} → stack {to} {}", coord(from), coord(to)));
+    ctx.say(format!("Sending   : {units} units, {reapers} reapers\n"));
+
+    let (ata, create) = ctx.ensure_ata(&wallet, &keys.kill_mint)?;
+    let mut ixs: Vec<_> = create.into_iter().collect();
+    ixs.push(game::move_units(&keys, wallet, wallet, ata, from, to, units, reapers, close_empty));
+    let outcome = ctx.send("Moved", &ixs, &[])?;
+    Ok(json!({
+        "season": keys.season,
+        "from_stack": from,
+        "to_stack": to,
+        "units": units,
+        "reapers": reapers,
+        "tx": outcome.to_json(),
+    }))
+}
+
+/// Attack `defender` on the shared `stack_id`.
+pub fn kill(
+    ctx: &Ctx,
+    stack_id: u16,
+    defender: Pubkey,
+    units: u64,
+    reapers: u64,
+    close_empty: bool,
+    guards: KillGuards,
+) -> Result<Value> {
+    let wallet = ctx.wallet()?;
+    let gc = ctx.game_config()?;
+    let keys = GameKeys::from_config(&gc);
+
+    ctx.say(format!("\nWallet   : {wallet}"));
+    ctx.say(format!("Attack   : stack {stack_id} {} vs defender {defender}", coord(stack_id)));
+    ctx.say(format!("Sending  : {units} units, {reapers} reapers\n"));
+
+    let (attacker_ata, create_attacker) = ctx.ensure_ata(&wallet, &keys.kill_mint)?;
+    let (defender_ata, create_defender) = ctx.ensure_ata(&defender, &keys.kill_mint)?;
+    let strike = Strike {
+        attacker: wallet,
+        authority: wallet,
+        attacker_token_account: attacker_ata,
+        defender,
+        defender_token_account: defender_ata,
+    };
+    let mut ixs: Vec<_> = create_attacker.into_iter().chain(create_defender).collect();
+    ixs.push(game::kill(&keys, &strike, stack_id, units, reapers, close_empty, guards));
+    let outcome = ctx.send("Kill submitted", &ixs, &[])?;
+    Ok(json!({
+        "season": keys.season,
+        "stack_id": stack_id,
+        "defender": defender.to_string(),
+        "units": units,
+        "reapers": reapers,
+        "tx": outcome.to_json(),
+    }))
+}
+
+/// `agent`'s stacks in `season`, plus any it still has from before seasons
+/// (the original 75-byte layout at the unseasoned seeds), flagged as legacy
+/// — those need `migrate_stack` to move them into the current season.
+fn season_stacks(ctx: &Ctx, agent: &Pubkey, season: u32) -> Result<Vec<(AgentStack, bool)>> {
+    let mut stacks = Vec::new();
+    for (size, legacy) in [(AgentStack::SPACE, false), (AgentStack::LEGACY_SPACE, true)] {
+        let filters = json!([
+            { "dataSize": size },
+            // Skip the 8-byte Anchor discriminator
+            { "memcmp": { "offset": 8, "bytes": agent.to_string() } },
+        ]);
+        for (_, data) in ctx.rpc.program_accounts(&GAME_PROGRAM_ID, filters)? {
+            // Legacy stacks decode with season 0
+            let stack = accounts::agent_stack(&data)?;
+            if legacy || stack.season == season {
+                stacks.push((stack, legacy));
+            }
+        }
+    }
+    stacks.sort_by_key(|(s, _)| s.stack_id);
+    Ok(stacks)
+}
+
+/// The current season's stacks of `agent` (default: the wallet).
+pub fn stacks(ctx: &Ctx, agent: Option<Pubkey>) -> Result<Value> {
+    let agent = match agent {
+        Some(agent) => agent,
+        None => ctx.wallet()?,
+    };
+    let gc = ctx.game_config()?;
+    let stacks = season_stacks(ctx, &agent, gc.current_season)?;
+
+    ctx.say(format!("\nStacks for {agent} (season {})\n", gc.current_season));
+    if stacks.is_empty() {
+        ctx.say("  No stacks found — spawn first.\n");
+    } else {
+        ctx.say(format!(
+            "{:<5} {:<9} {:>12} {:>10} {:>10} {:>12}",
+            "ID", "XYZ", "Units", "Reapers", "Progress", "Spawn slot"
+        ));
+        ctx.say("─".repeat(63));
+        for (s, legacy) in &stacks {
+            ctx.say(format!(
+                "{:<5} {:<9} {:>12} {:>10} {:>10} {:>12}{}",
+                s.stack_id,
+                coord(s.stack_id),
+                s.units,
+                s.reapers,
+                s.reaper_progress,
+                s.spawn_slot,
+                if *legacy { "  (legacy — run migrate-stacks)" } else { "" }
+            ));
+        }
+        ctx.say("");
+    }
+
+    let rows: Vec<Value> = stacks
+        .iter()
+        .map(|(s, legacy)| {
+            json!({
+                "stack_id": s.stack_id,
+                "units": s.units,
+                "reapers": s.reapers,
+                "reaper_progress": s.reaper_progress,
+                "spawn_slot": s.spawn_slot,
+                "kill_slot": s.kill_slot,
+                "legacy": legacy,
+            })
+        })
+        .collect();
+    Ok(json!({ "agent": agent.to_string(), "season": gc.current_season, "stacks": rows }))
+}
+
+/// Stack migrations sent per transaction.
+const MIGRATIONS_PER_TX: usize = 8;
+
+/// Move `agent`'s pre-season stacks into the current season (permissionless;
+/// the wallet pays for any new accounts, the legacy rent goes to `agent`).
+pub fn migrate_stacks(ctx: &Ctx, agent: Option<Pubkey>) -> Result<Value> {
+    let payer = ctx.wallet()?;
+    let agent = agent.unwrap_or(payer);
+    let gc = ctx.game_config()?;
+    let legacy: Vec<u16> = season_stacks(ctx, &agent, gc.current_season)?
+        .into_iter()
+        .filter(|(_, legacy)| *legacy)
+        .map(|(s, _)| s.stack_id)
+        .collect();
+
+    ctx.say(format!("\n{} legacy stack(s) for {agent} (season {})", legacy.len(), gc.current_season));
+    let mut txs = Vec::new();
+    for chunk in legacy.chunks(MIGRATIONS_PER_TX) {
+        let ixs: Vec<_> = chunk
+            .iter()
+            .map(|&id| game::migrate_stack(gc.current_season, agent, id, payer))
+            .collect();
+        txs.push(ctx.send(&format!("Migrated stacks {chunk:?}"), &ixs, &[])?.to_json());
+    }
+    Ok(json!({ "agent": agent.to_string(), "season": gc.current_season, "migrated": legacy, "txs": txs }))
+}
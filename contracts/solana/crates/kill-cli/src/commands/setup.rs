@@ -0,0 +1,162 @@
+//! Admin setup: program initialization, minting and vault funding.
+
+use std::path::Path;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, spl_token};
+use anyhow::{anyhow, Result};
+use kill_sdk::instructions::{faucet, game, token as kill_token};
+use kill_sdk::{accounts, pda, Pubkey};
+use serde_json::{json, Value};
+use solana_keypair::{read_keypair_file, write_keypair_file, Keypair};
+use solana_signer::Signer;
+
+use crate::context::Ctx;
+use crate::display::fmt_kill;
+
+/// Load the KILL mint keypair, generating (and saving) one if missing.
+/// Nothing is written with `--simulate`.
+fn load_or_generate_mint(ctx: &Ctx, path: &Path) -> Result<Keypair> {
+    if path.exists() {
+        ctx.say(format!("  Using existing mint keypair: {}", path.display()));
+        return read_keypair_file(path).map_err(|e| anyhow!("reading {}: {e}", path.display()));
+    }
+    let kp = Keypair::new();
+    if ctx.simulate {
+        ctx.say("  Generated a throwaway mint keypair (not saved with --simulate)");
+    } else {
+        write_keypair_file(&kp, path).map_err(|e| anyhow!("writing {}: {e}", path.display()))?;
+        ctx.say(format!("  Generated new mint keypair → {}", path.display()));
+    }
+    Ok(kp)
+}
+
+/// One-time setup of all three programs.  Re-running is safe: each step is
+/// skipped if its account already exists.
+pub fn init(ctx: &Ctx, mint_keypair: &Path, season_slots: u64) -> Result<Value> {
+    let admin = ctx.wallet()?;
+    ctx.say(format!("\nAdmin wallet : {admin}"));
+    ctx.say(format!("RPC          : {}\n", ctx.rpc.url()));
+
+    let mint = load_or_generate_mint(ctx, mint_keypair)?;
+    let kill_mint = mint.pubkey();
+    ctx.say(format!("KILL mint    : {kill_mint}\n"));
+
+    let mut steps = Vec::new();
+    let mut step = |name: &str, exists: bool, run: &dyn Fn() -> Result<Value>| -> Result<()> {
+        if exists {
+            ctx.say(format!("✅ {name} already done — skipping"));
+            steps.push(json!({ "step": name, "skipped": true }));
+        } else {
+            let mut outcome = run()?;
+            outcome["step"] = json!(name);
+            outcome["skipped"] = json!(false);
+            steps.push(outcome);
+        }
+        Ok(())
+    };
+    let exists = |key: Pubkey| -> Result<bool> { Ok(ctx.rpc.account_data(&key)?.is_some()) };
+
+    step("initialize_token", exists(pda::token_config().0)?, &|| {
+        let ix = kill_token::initialize_token(admin, kill_mint);
+        Ok(ctx.send("kill_token initialized", &[ix], &[&mint])?.to_json())
+    })?;
+
+    step("initialize_game", exists(pda::game_config().0)?, &|| {
+        let vault = Keypair::new();
+        let ix = game::initialize_game(admin, kill_mint, vault.pubkey());
+        let mut out = ctx.send("kill_game initialized", &[ix], &[&vault])?.to_json();
+        ctx.say(format!("   game_vault : {}", vault.pubkey()));
+        out["game_vault"] = json!(vault.pubkey().to_string());
+        Ok(out)
+    })?;
+
+    step("initialize_params", exists(pda::game_params().0)?, &|| {
+        Ok(ctx.send("kill_game params initialized", &[game::initialize_params(admin)], &[])?.to_json())
+    })?;
+
+    let season_started = match ctx.rpc.account_data(&pda::game_config().0)? {
+        Some(data) => accounts::game_config(&data)?.current_season > 0,
+        None => false,
+    };
+    step("start_season", season_started, &|| {
+        let slot = ctx.rpc.slot()?;
+        let end_slot = slot.saturating_add(season_slots);
+        let ix = game::start_season(admin, 1, slot, end_slot);
+        let mut out = ctx.send(&format!("season 1 started (ends at slot {end_slot})"), &[ix], &[])?.to_json();
+        out["end_slot"] = json!(end_slot);
+        Ok(out)
+    })?;
+
+    step("initialize_leaderboard", exists(pda::leaderboard().0)?, &|| {
+        Ok(ctx.send("kill_game leaderboard initialized", &[game::initialize_leaderboard(admin)], &[])?.to_json())
+    })?;
+
+    step("initialize_faucet", exists(pda::faucet_config().0)?, &|| {
+        let vault = Keypair::new();
+        let ix = faucet::initialize_faucet(admin, kill_mint, vault.pubkey());
+        let mut out = ctx.send("kill_faucet initialized", &[ix], &[&vault])?.to_json();
+        ctx.say(format!("   faucet_vault : {}", vault.pubkey()));
+        out["faucet_vault"] = json!(vault.pubkey().to_string());
+        Ok(out)
+    })?;
+
+    Ok(json!({ "admin": admin.to_string(), "kill_mint": kill_mint.to_string(), "steps": steps }))
+}
+
+/// Mint KILL to `destination` (default: the wallet).  Admin only.
+pub fn mint(ctx: &Ctx, amount: u64, destination: Option<Pubkey>) -> Result<Value> {
+    let admin = ctx.wallet()?;
+    let data = ctx
+        .rpc
+        .account_data(&pda::token_config().0)?
+        .ok_or_else(|| anyhow!("kill_token is not initialized — run `kill-cli init` first"))?;
+    let kill_mint = accounts::token_config(&data)?.kill_mint;
+    let destination = destination.unwrap_or(admin);
+    let (ata, create) = ctx.ensure_ata(&destination, &kill_mint)?;
+
+    ctx.say(format!("\nMinting {} KILL", fmt_kill(amount)));
+    ctx.say(format!("  Mint    : {kill_mint}"));
+    ctx.say(format!("  To      : {destination}"));
+    ctx.say(format!("  ATA     : {ata}\n"));
+
+    let mut ixs: Vec<_> = create.into_iter().collect();
+    ixs.push(kill_token::mint_to(admin, kill_mint, ata, amount));
+    let outcome = ctx.send(&format!("Minted {} KILL", fmt_kill(amount)), &ixs, &[])?;
+    Ok(json!({
+        "kill_mint": kill_mint.to_string(),
+        "destination": destination.to_string(),
+        "token_account": ata.to_string(),
+        "amount": amount,
+        "tx": outcome.to_json(),
+    }))
+}
+
+/// Transfer KILL from the wallet's token account into a program vault.
+fn fund(ctx: &Ctx, label: &str, kill_mint: Pubkey, vault: Pubkey, amount: u64) -> Result<Value> {
+    let wallet = ctx.wallet()?;
+    let source = get_associated_token_address(&wallet, &kill_mint);
+    ctx.say(format!("\nWallet : {wallet}"));
+    ctx.say(format!("Vault  : {vault}"));
+    ctx.say(format!("Amount : {} KILL\n", fmt_kill(amount)));
+
+    let ix = spl_token::instruction::transfer(&token::ID, &source, &vault, &wallet, &[], amount)?;
+    let outcome = ctx.send(label, &[ix], &[])?;
+    Ok(json!({ "vault": vault.to_string(), "amount": amount, "tx": outcome.to_json() }))
+}
+
+/// Pre-fund bounty payouts in the game vault.
+pub fn fund_vault(ctx: &Ctx, amount: u64) -> Result<Value> {
+    let gc = ctx.game_config()?;
+    fund(ctx, "Vault funded", gc.kill_mint, gc.game_vault, amount)
+}
+
+/// Top up the faucet vault.
+pub fn fund_faucet(ctx: &Ctx, amount: u64) -> Result<Value> {
+    let data = ctx
+        .rpc
+        .account_data(&pda::faucet_config().0)?
+        .ok_or_else(|| anyhow!("kill_faucet is not initialized — run `kill-cli init` first"))?;
+    let fc = accounts::faucet_config(&data)?;
+    fund(ctx, "Faucet funded", fc.kill_mint, fc.faucet_vault, amount)
+}
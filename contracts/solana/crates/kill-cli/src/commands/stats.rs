@@ -0,0 +1,105 @@
+//! Protocol overview: vault balances, on-chain economy counters and
+//! (optionally) transaction counts per program.
+
+use std::thread::sleep;
+use std::time::Duration;
+
+use anyhow::Result;
+use kill_sdk::{accounts, pda, Pubkey, FAUCET_PROGRAM_ID, GAME_PROGRAM_ID, KILL_TOKEN_PROGRAM_ID};
+use serde_json::{json, Value};
+
+use crate::context::Ctx;
+use crate::display::{fmt_kill, fmt_sol};
+
+const PAGE_SIZE: usize = 1_000;
+/// Pause between signature pages; public RPCs rate-limit bulk pagination
+const PAGE_DELAY: Duration = Duration::from_millis(600);
+/// Lamports per transaction (base fee, one signature, no priority fee)
+const BASE_FEE: u64 = 5_000;
+
+/// Successful transactions that touched `program`.
+fn tx_count(ctx: &Ctx, program: &Pubkey) -> Result<u64> {
+    let mut total = 0;
+    let mut before: Option<String> = None;
+    loop {
+        let page = ctx.rpc.signatures_for_address(program, before.as_deref(), PAGE_SIZE)?;
+        total += page.iter().filter(|(_, ok)| *ok).count() as u64;
+        if page.len() < PAGE_SIZE {
+            return Ok(total);
+        }
+        before = page.last().map(|(sig, _)| sig.clone());
+        sleep(PAGE_DELAY);
+    }
+}
+
+pub fn stats(ctx: &Ctx, count_txs: bool) -> Result<Value> {
+    let mut report = json!({});
+
+    ctx.say("\n── Vault Balances ──────────────────────────────────────────────────");
+    let gc = ctx.game_config().ok();
+    if let Some(gc) = &gc {
+        let amount = ctx.token_amount(&gc.game_vault)?.unwrap_or(0);
+        ctx.say(format!("  Game Vault:   {} KILL", fmt_kill(amount)));
+        report["game_vault"] = json!({ "address": gc.game_vault.to_string(), "amount": amount });
+    } else {
+        ctx.say("  Game Vault:   (kill_game not initialized)");
+    }
+    match ctx.rpc.account_data(&pda::faucet_config().0)? {
+        Some(data) => {
+            let fc = accounts::faucet_config(&data)?;
+            let amount = ctx.token_amount(&fc.faucet_vault)?.unwrap_or(0);
+            ctx.say(format!("  Faucet Vault: {} KILL", fmt_kill(amount)));
+            report["faucet_vault"] = json!({ "address": fc.faucet_vault.to_string(), "amount": amount });
+        }
+        None => ctx.say("  Faucet Vault: (kill_faucet not initialized)"),
+    }
+
+    if let Some(gc) = &gc {
+        let net = gc.total_kill_extracted as i128 - gc.total_kill_added as i128;
+        ctx.say("\n── Economy (on-chain) ──────────────────────────────────────────────");
+        ctx.say(format!("  Season:         {}", gc.current_season));
+        ctx.say(format!("  Kills:          {}", gc.total_kills));
+        ctx.say(format!("  Units killed:   {}", gc.total_units_killed));
+        ctx.say(format!("  Reapers killed: {}", gc.total_reapers_killed));
+        ctx.say(format!("  KILL added:     {}", fmt_kill(gc.total_kill_added)));
+        ctx.say(format!("  KILL extracted: {}", fmt_kill(gc.total_kill_extracted)));
+        ctx.say(format!("  KILL burned:    {}", fmt_kill(gc.total_kill_burned)));
+        ctx.say(format!(
+            "  Net P&L:        {}{} ({})",
+            if net >= 0 { "+" } else { "-" },
+            fmt_kill(net.unsigned_abs() as u64),
+            if net >= 0 { "net gain" } else { "net loss" }
+        ));
+        report["economy"] = json!({
+            "current_season": gc.current_season,
+            "total_kills": gc.total_kills,
+            "total_units_killed": gc.total_units_killed,
+            "total_reapers_killed": gc.total_reapers_killed,
+            "total_kill_added": gc.total_kill_added,
+            "total_kill_extracted": gc.total_kill_extracted,
+            "total_kill_burned": gc.total_kill_burned,
+        });
+    }
+
+    if count_txs {
+        ctx.say("\n── Contract Stats ──────────────────────────────────────────────────");
+        ctx.say(format!("  {:<12}  {:>8}  {:>16}", "CONTRACT", "TX", "SOL FEES (est)"));
+        let mut rows = Vec::new();
+        let mut grand = 0;
+        for (name, program) in [
+            ("kill-game", GAME_PROGRAM_ID),
+            ("kill-token", KILL_TOKEN_PROGRAM_ID),
+            ("kill-faucet", FAUCET_PROGRAM_ID),
+        ] {
+            let count = tx_count(ctx, &program)?;
+            grand += count;
+            ctx.say(format!("  {name:<12}  {count:>8}  {:>16} SOL", fmt_sol(count * BASE_FEE)));
+            rows.push(json!({ "program": name, "transactions": count, "est_fee_lamports": count * BASE_FEE }));
+        }
+        ctx.say(format!("  {:<12}  {grand:>8}  {:>16} SOL", "TOTAL", fmt_sol(grand * BASE_FEE)));
+        ctx.say(format!("  (fees estimated at {BASE_FEE} lamports/tx base fee × tx count)"));
+        report["transactions"] = json!(rows);
+    }
+    ctx.say("");
+    Ok(report)
+}
@@ -0,0 +1,129 @@
+//! Wallet helpers: SOL airdrops, creating named wallets and balances.
+
+use std::path::Path;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anyhow::{anyhow, Result};
+use kill_sdk::Pubkey;
+use serde_json::{json, Value};
+use solana_keypair::{read_keypair_file, write_keypair_file, Keypair};
+use solana_signer::Signer;
+
+use crate::context::Ctx;
+use crate::display::{fmt_kill, fmt_sol, LAMPORTS_PER_SOL};
+
+fn request_airdrop(ctx: &Ctx, to: &Pubkey, sol: f64) -> Result<Option<String>> {
+    let lamports = (sol * LAMPORTS_PER_SOL as f64).round() as u64;
+    if ctx.simulate {
+        ctx.say(format!("🧪 Would request {sol} SOL for {to} (skipped with --simulate)"));
+        return Ok(None);
+    }
+    ctx.say(format!("Requesting {sol} SOL airdrop..."));
+    let signature = ctx.rpc.request_airdrop(to, lamports)?;
+    ctx.rpc.confirm(&signature)?;
+    Ok(Some(signature))
+}
+
+/// Airdrop SOL to the wallet (devnet/localnet only).
+pub fn airdrop(ctx: &Ctx, sol: f64) -> Result<Value> {
+    let wallet = ctx.wallet()?;
+    let before = ctx.rpc.balance(&wallet)?;
+    ctx.say(format!("\nWallet : {wallet}"));
+    ctx.say(format!("Before : {} SOL", fmt_sol(before)));
+    let signature = request_airdrop(ctx, &wallet, sol)?;
+    let after = ctx.rpc.balance(&wallet)?;
+    ctx.say(format!("After  : {} SOL", fmt_sol(after)));
+    if let Some(sig) = &signature {
+        ctx.say(format!("Sig    : {sig}\n"));
+    }
+    Ok(json!({ "wallet": wallet.to_string(), "before": before, "after": after, "signature": signature }))
+}
+
+/// Create (or load) the keypair at `path` and fund it with an airdrop.  A
+/// failed airdrop is reported but does not fail the command.
+pub fn new_wallet(ctx: &Ctx, name: &str, path: &Path, sol: f64) -> Result<Value> {
+    let (kp, created) = if path.exists() {
+        ctx.say(format!("  Wallet already exists at {} — loading it", path.display()));
+        let kp = read_keypair_file(path).map_err(|e| anyhow!("reading {}: {e}", path.display()))?;
+        (kp, false)
+    } else {
+        let kp = Keypair::new();
+        if !ctx.simulate {
+            write_keypair_file(&kp, path).map_err(|e| anyhow!("writing {}: {e}", path.display()))?;
+            ctx.say(format!("  Created new keypair → {}", path.display()));
+        }
+        (kp, true)
+    };
+    let pubkey = kp.pubkey();
+    ctx.say(format!("\n  Name   : {name}"));
+    ctx.say(format!("  Pubkey : {pubkey}"));
+    ctx.say(format!("  Path   : {}\n", path.display()));
+
+    let (signature, airdrop_error) = match request_airdrop(ctx, &pubkey, sol) {
+        Ok(sig) => (sig, None),
+        Err(e) => {
+            ctx.say(format!("  ⚠️  Airdrop failed (rate limited?): {e}"));
+            ctx.say(format!("     Fund manually: kill-cli airdrop {sol} --keypair {name}"));
+            (None, Some(e.to_string()))
+        }
+    };
+    let balance = ctx.rpc.balance(&pubkey)?;
+    ctx.say(format!("  Balance: {} SOL", fmt_sol(balance)));
+    ctx.say(format!("\nUse it with any command via --keypair {name}, e.g.\n  kill-cli --keypair {name} spawn 0 666\n"));
+
+    Ok(json!({
+        "name": name,
+        "pubkey": pubkey.to_string(),
+        "path": path.display().to_string(),
+        "created": created,
+        "balance": balance,
+        "airdrop_signature": signature,
+        "airdrop_error": airdrop_error,
+    }))
+}
+
+/// SOL and KILL balances of the wallet, plus the GameConfig summary.
+pub fn balance(ctx: &Ctx) -> Result<Value> {
+    let wallet = ctx.wallet()?;
+    let lamports = ctx.rpc.balance(&wallet)?;
+    ctx.say(format!("\nWallet  : {wallet}"));
+    ctx.say(format!("RPC     : {}\n", ctx.rpc.url()));
+    ctx.say(format!("SOL     : {} SOL", fmt_sol(lamports)));
+
+    let gc = match ctx.game_config() {
+        Ok(gc) => gc,
+        Err(e) => {
+            ctx.say(format!("\n{e}\n"));
+            return Ok(json!({ "wallet": wallet.to_string(), "lamports": lamports }));
+        }
+    };
+    let ata = get_associated_token_address(&wallet, &gc.kill_mint);
+    let kill = ctx.token_amount(&ata)?;
+    match kill {
+        Some(raw) => ctx.say(format!("KILL    : {} KILL  (ATA: {ata})", fmt_kill(raw))),
+        None => ctx.say(format!("KILL    : 0  (no token account yet — ATA: {ata})")),
+    }
+
+    ctx.say("\n── GameConfig ───────────────────────────────────────────────");
+    ctx.say(format!("  kill_mint      : {}", gc.kill_mint));
+    ctx.say(format!("  game_vault     : {}", gc.game_vault));
+    ctx.say(format!("  admin          : {}", gc.admin));
+    ctx.say(format!("  current_season : {}", gc.current_season));
+    ctx.say(format!("  total_kills    : {}", gc.total_kills));
+    ctx.say(format!("  paused         : {}\n", gc.paused));
+
+    Ok(json!({
+        "wallet": wallet.to_string(),
+        "lamports": lamports,
+        "kill_token_account": ata.to_string(),
+        "kill": kill.unwrap_or(0),
+        "game_config": {
+            "kill_mint": gc.kill_mint.to_string(),
+            "game_vault": gc.game_vault.to_string(),
+            "admin": gc.admin.to_string(),
+            "current_season": gc.current_season,
+            "total_kills": gc.total_kills,
+            "paused": gc.paused,
+        },
+    }))
+}
@@ -0,0 +1,205 @@
+//! State shared by every subcommand: the RPC client, the signing keypair and
+//! the output mode, plus the send-or-simulate path all transactions go through.
+
+use std::cell::{Cell, OnceCell};
+use std::path::PathBuf;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use anchor_spl::token::{self, TokenAccount};
+use anchor_lang::AccountDeserialize;
+use anyhow::{anyhow, Context as _, Result};
+use kill_sdk::events::{parse_logs, GameEvent};
+use kill_sdk::kill_game::state::GameConfig;
+use kill_sdk::{accounts, pda, Instruction};
+use serde_json::{json, Value};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+use crate::display::{event_json, event_line};
+use crate::rpc::Rpc;
+
+/// A sent or simulated transaction.
+pub struct TxOutcome {
+    /// `None` when simulated
+    pub signature: Option<String>,
+    pub err: Option<Value>,
+    pub units_consumed: Option<u64>,
+    pub logs: Vec<String>,
+    pub events: Vec<GameEvent>,
+}
+
+impl TxOutcome {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "signature": self.signature,
+            "simulated": self.signature.is_none(),
+            "err": self.err,
+            "units_consumed": self.units_consumed,
+            "logs": self.logs,
+            "events": self.events.iter().map(event_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+pub struct Ctx {
+    pub rpc: Rpc,
+    /// Explorer `?cluster=` value, `None` for a custom RPC URL
+    pub explorer_cluster: Option<&'static str>,
+    pub json: bool,
+    pub simulate: bool,
+    keypair_path: PathBuf,
+    payer: OnceCell<Keypair>,
+    /// Set when a simulation fails so the process exits non-zero
+    failed: Cell<bool>,
+}
+
+impl Ctx {
+    pub fn new(
+        rpc: Rpc,
+        explorer_cluster: Option<&'static str>,
+        keypair_path: PathBuf,
+        json: bool,
+        simulate: bool,
+    ) -> Self {
+        Self {
+            rpc,
+            explorer_cluster,
+            json,
+            simulate,
+            keypair_path,
+            payer: OnceCell::new(),
+            failed: Cell::new(false),
+        }
+    }
+
+    /// The signing wallet, loaded on first use so commands that never sign
+    /// (e.g. `new-wallet`) work without one.
+    pub fn payer(&self) -> Result<&Keypair> {
+        if let Some(kp) = self.payer.get() {
+            return Ok(kp);
+        }
+        let kp = read_keypair_file(&self.keypair_path)
+            .map_err(|e| anyhow!("reading keypair {}: {e}", self.keypair_path.display()))?;
+        Ok(self.payer.get_or_init(|| kp))
+    }
+
+    pub fn wallet(&self) -> Result<Pubkey> {
+        Ok(self.payer()?.pubkey())
+    }
+
+    pub fn failed(&self) -> bool {
+        self.failed.get()
+    }
+
+    /// Print a line of human output (suppressed with `--json`).
+    pub fn say(&self, line: impl AsRef<str>) {
+        if !self.json {
+            println!("{}", line.as_ref());
+        }
+    }
+
+    pub fn tx_link(&self, signature: &str) -> String {
+        match self.explorer_cluster {
+            Some(cluster) => format!("https://explorer.solana.com/tx/{signature}?cluster={cluster}"),
+            None => format!(
+                "https://explorer.solana.com/tx/{signature}?cluster=custom&customUrl={}",
+                self.rpc.url()
+            ),
+        }
+    }
+
+    pub fn game_config(&self) -> Result<GameConfig> {
+        let data = self
+            .rpc
+            .account_data(&pda::game_config().0)?
+            .ok_or_else(|| anyhow!("kill_game is not initialized — run `kill-cli init` first"))?;
+        Ok(accounts::game_config(&data)?)
+    }
+
+    /// Balance of an SPL token account, `None` if it does not exist.
+    pub fn token_amount(&self, account: &Pubkey) -> Result<Option<u64>> {
+        match self.rpc.account_data(account)? {
+            Some(data) => Ok(Some(TokenAccount::try_deserialize(&mut &data[..])?.amount)),
+            None => Ok(None),
+        }
+    }
+
+    /// `owner`'s KILL associated token account, and the instruction creating
+    /// it (paid by the wallet) if it does not exist yet.
+    pub fn ensure_ata(&self, owner: &Pubkey, mint: &Pubkey) -> Result<(Pubkey, Option<Instruction>)> {
+        let ata = get_associated_token_address(owner, mint);
+        let create = match self.rpc.account_data(&ata)? {
+            Some(_) => None,
+            None => Some(create_associated_token_account_idempotent(
+                &self.wallet()?,
+                owner,
+                mint,
+                &token::ID,
+            )),
+        };
+        Ok((ata, create))
+    }
+
+    /// Sign and send `ixs` — or simulate them with `--simulate` — and report
+    /// the result with the decoded `kill_game` events.  `signers` are needed
+    /// in addition to the wallet (e.g. fresh vault keypairs).
+    pub fn send(&self, label: &str, ixs: &[Instruction], signers: &[&Keypair]) -> Result<TxOutcome> {
+        let payer = self.payer()?;
+        let mut all: Vec<&Keypair> = vec![payer];
+        all.extend_from_slice(signers);
+        let blockhash = self.rpc.latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &all, blockhash);
+
+        let outcome = if self.simulate {
+            let sim = self.rpc.simulate_transaction(&tx)?;
+            TxOutcome {
+                signature: None,
+                events: parse_logs(sim.logs.iter().map(String::as_str)),
+                err: sim.err,
+                units_consumed: sim.units_consumed,
+                logs: sim.logs,
+            }
+        } else {
+            let signature = self.rpc.send_transaction(&tx).with_context(|| label.to_owned())?;
+            self.rpc.confirm(&signature).with_context(|| label.to_owned())?;
+            let logs = self.rpc.transaction_logs(&signature)?;
+            TxOutcome {
+                events: parse_logs(logs.iter().map(String::as_str)),
+                signature: Some(signature),
+                err: None,
+                units_consumed: None,
+                logs,
+            }
+        };
+
+        match (&outcome.signature, &outcome.err) {
+            (Some(sig), _) => {
+                self.say(format!("✅ {label}"));
+                self.say(format!("   Tx : {}", self.tx_link(sig)));
+            }
+            (None, None) => {
+                self.say(format!(
+                    "🧪 {label} — simulation succeeded ({} CU)",
+                    outcome.units_consumed.unwrap_or_default()
+                ));
+                for line in &outcome.logs {
+                    self.say(format!("   | {line}"));
+                }
+            }
+            (None, Some(err)) => {
+                self.failed.set(true);
+                self.say(format!("❌ {label} — simulation failed: {err}"));
+                for line in &outcome.logs {
+                    self.say(format!("   | {line}"));
+                }
+            }
+        }
+        for event in &outcome.events {
+            self.say(format!("   {}", event_line(event)));
+        }
+        Ok(outcome)
+    }
+}
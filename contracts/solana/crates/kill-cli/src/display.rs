@@ -0,0 +1,150 @@
+//! Amount parsing/formatting and event rendering (text and JSON).
+
+use anyhow::{bail, Result};
+use kill_sdk::events::GameEvent;
+use serde_json::{json, Value};
+
+/// KILL has 6 decimals.
+pub const KILL_DECIMALS: u32 = 6;
+
+const KILL_ONE: u64 = 10u64.pow(KILL_DECIMALS);
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Parse a whole or fractional KILL amount ("1000", "0.5") into raw units
+/// without going through floating point.
+pub fn parse_kill(s: &str) -> Result<u64> {
+    let (whole, frac) = s.split_once('.').unwrap_or((s, ""));
+    if frac.len() > KILL_DECIMALS as usize
+        || !whole.chars().chain(frac.chars()).all(|c| c.is_ascii_digit())
+        || (whole.is_empty() && frac.is_empty())
+    {
+        bail!("invalid KILL amount {s:?} (up to {KILL_DECIMALS} decimals)");
+    }
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse()? };
+    let frac: u64 = if frac.is_empty() {
+        0
+    } else {
+        format!("{frac:0<width$}", width = KILL_DECIMALS as usize).parse()?
+    };
+    match whole.checked_mul(KILL_ONE).and_then(|w| w.checked_add(frac)) {
+        Some(raw) if raw > 0 => Ok(raw),
+        Some(_) => bail!("amount must be greater than zero"),
+        None => bail!("amount {s} overflows u64"),
+    }
+}
+
+/// Raw KILL as a decimal string with trailing zeros trimmed ("1234.5").
+pub fn fmt_kill(raw: u64) -> String {
+    let frac = raw % KILL_ONE;
+    if frac == 0 {
+        return (raw / KILL_ONE).to_string();
+    }
+    let frac = format!("{frac:0width$}", width = KILL_DECIMALS as usize);
+    format!("{}.{}", raw / KILL_ONE, frac.trim_end_matches('0'))
+}
+
+pub fn fmt_sol(lamports: u64) -> String {
+    format!("{:.6}", lamports as f64 / LAMPORTS_PER_SOL as f64)
+}
+
+/// Grid coordinates of a stack id: x = id % 6, y = (id / 6) % 6, z = id / 36.
+pub fn coord(stack_id: u16) -> String {
+    format!("({},{},{})", stack_id % 6, stack_id / 6 % 6, stack_id / 36)
+}
+
+pub fn event_json(event: &GameEvent) -> Value {
+    match event {
+        GameEvent::Kill(e) => json!({
+            "event": "KillEvent",
+            "attacker": e.attacker.to_string(),
+            "defender": e.defender.to_string(),
+            "attacker_stack": e.attacker_stack,
+            "defender_stack": e.defender_stack,
+            "attacker_bounty": e.attacker_bounty,
+            "defender_bounty": e.defender_bounty,
+            "total_burned": e.total_burned,
+            "remaining_units": e.remaining_units,
+            "remaining_reapers": e.remaining_reapers,
+            "slot": e.slot,
+            "attacker_units_sent": e.attacker_units_sent,
+            "attacker_reapers_sent": e.attacker_reapers_sent,
+            "attacker_units_lost": e.attacker_units_lost,
+            "attacker_reapers_lost": e.attacker_reapers_lost,
+            "defender_units": e.defender_units,
+            "defender_reapers": e.defender_reapers,
+            "defender_units_lost": e.defender_units_lost,
+            "defender_reapers_lost": e.defender_reapers_lost,
+        }),
+        GameEvent::Spawned(e) => json!({
+            "event": "StackSpawned",
+            "agent": e.agent.to_string(),
+            "stack_id": e.stack_id,
+            "units": e.units,
+            "reapers": e.reapers,
+            "slot": e.slot,
+            "deposited": e.deposited,
+            "burned": e.burned,
+            "spawned_units": e.spawned_units,
+            "spawned_reapers": e.spawned_reapers,
+        }),
+        GameEvent::Moved(e) => json!({
+            "event": "StackMoved",
+            "agent": e.agent.to_string(),
+            "from_stack": e.from_stack,
+            "to_stack": e.to_stack,
+            "units": e.units,
+            "reapers": e.reapers,
+            "slot": e.slot,
+            "deposited": e.deposited,
+            "burned": e.burned,
+        }),
+    }
+}
+
+/// One-line human summary of an event.
+pub fn event_line(event: &GameEvent) -> String {
+    match event {
+        GameEvent::Kill(e) => {
+            let won = e.remaining_units > 0 || e.remaining_reapers > 0;
+            format!(
+                "KillEvent    stack {} {} → {}: {} | sent {}u/{}r, lost {}u/{}r | defender lost {}u/{}r of {}u/{}r | \
+                 bounty {} / {} KILL, burned {}",
+                e.attacker_stack,
+                e.attacker,
+                e.defender,
+                if won { "WON" } else { "LOST" },
+                e.attacker_units_sent,
+                e.attacker_reapers_sent,
+                e.attacker_units_lost,
+                e.attacker_reapers_lost,
+                e.defender_units_lost,
+                e.defender_reapers_lost,
+                e.defender_units,
+                e.defender_reapers,
+                fmt_kill(e.attacker_bounty),
+                fmt_kill(e.defender_bounty),
+                fmt_kill(e.total_burned),
+            )
+        }
+        GameEvent::Spawned(e) => format!(
+            "StackSpawned stack {}: +{} units, +{} reapers (now {}/{}) | {} KILL to vault, {} burned",
+            e.stack_id,
+            e.spawned_units,
+            e.spawned_reapers,
+            e.units,
+            e.reapers,
+            fmt_kill(e.deposited),
+            fmt_kill(e.burned),
+        ),
+        GameEvent::Moved(e) => format!(
+            "StackMoved   stack {} → {}: {} units, {} reapers | {} KILL to vault, {} burned",
+            e.from_stack,
+            e.to_stack,
+            e.units,
+            e.reapers,
+            fmt_kill(e.deposited),
+            fmt_kill(e.burned),
+        ),
+    }
+}
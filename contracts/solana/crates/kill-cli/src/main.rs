@@ -0,0 +1,254 @@
+//! `kill-cli` — command-line client for the KILL programs.
+//!
+//! One binary for the operations previously spread over the Node scripts in
+//! `scripts/solana`: setup (`init`, `mint`, `fund-vault`, `fund-faucet`),
+//! gameplay (`spawn`, `move`, `kill`, `stacks`, `migrate-stacks`), wallets
+//! (`airdrop`, `new-wallet`, `balance`) and `stats`.
+//!
+//! Global flags select the keypair (`--keypair`), the cluster (`--url`),
+//! machine-readable output (`--json`) and dry runs (`--simulate`: every
+//! transaction goes through `simulateTransaction` and its logs and decoded
+//! `kill_game` events are printed; nothing is sent).
+
+mod commands;
+mod context;
+mod display;
+mod rpc;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use kill_sdk::kill_game::instructions::kill::KillGuards;
+use kill_sdk::Pubkey;
+use serde_json::Value;
+
+use commands::{play, setup, stats, wallet};
+use context::Ctx;
+use display::parse_kill;
+use rpc::Rpc;
+
+#[derive(Parser)]
+#[command(name = "kill-cli", version, about = "Command-line client for the KILL programs")]
+struct Cli {
+    /// RPC URL or cluster moniker: localnet, devnet, testnet, mainnet-beta
+    #[arg(short = 'u', long, global = true, env = "SOLANA_RPC_URL", default_value = "devnet")]
+    url: String,
+
+    /// Signing keypair: a wallet name under ~/.config/solana (`player2` →
+    /// ~/.config/solana/player2.json) or a path to a keypair file
+    #[arg(
+        short = 'k',
+        long,
+        visible_alias = "wallet",
+        global = true,
+        env = "SOLANA_KEYPAIR_PATH",
+        default_value = "id"
+    )]
+    keypair: String,
+
+    /// Print a JSON report instead of human-readable output
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Simulate transactions instead of sending them and print the decoded
+    /// program logs
+    #[arg(long, global = true)]
+    simulate: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// One-time setup of kill_token, kill_game (config, params, season 1,
+    /// leaderboard) and kill_faucet; steps already done are skipped
+    Init {
+        /// Season length in slots (~400 ms each)
+        #[arg(long, env = "SEASON_SLOTS", default_value_t = 6_480_000)]
+        season_slots: u64,
+        /// KILL mint keypair, generated if missing
+        #[arg(long, default_value = "kill-mint")]
+        mint_keypair: String,
+    },
+    /// Mint KILL (admin only)
+    Mint {
+        /// Whole or fractional KILL, e.g. 1000000 or 0.5
+        #[arg(value_parser = parse_kill)]
+        amount: u64,
+        /// Recipient wallet (default: the signing wallet)
+        destination: Option<Pubkey>,
+    },
+    /// Spawn or reinforce a stack at a grid position (0–215)
+    Spawn { stack_id: u16, units: u64 },
+    /// Move units and reapers between two adjacent stacks
+    Move {
+        from: u16,
+        to: u16,
+        /// Units to move (default: the whole stack, reapers included)
+        units: Option<u64>,
+        /// Reapers to move (default 0 when units are given)
+        reapers: Option<u64>,
+        /// Close the source stack if it ends up empty
+        #[arg(long)]
+        close_empty: bool,
+    },
+    /// Attack a defender on the same stack
+    Kill {
+        stack_id: u16,
+        defender: Pubkey,
+        units: u64,
+        #[arg(default_value_t = 0)]
+        reapers: u64,
+        /// Close the attacker stack if it ends up empty
+        #[arg(long)]
+        close_empty: bool,
+        /// Abort if the defender's raw power exceeds this
+        #[arg(long)]
+        max_defender_power: Option<u64>,
+        /// Abort if the attacker's payout (after burn) would be below this many KILL
+        #[arg(long, value_parser = parse_kill)]
+        min_bounty: Option<u64>,
+        /// Abort unless the attack wins
+        #[arg(long)]
+        require_win: bool,
+    },
+    /// List the current season's stacks of a wallet
+    Stacks {
+        /// Wallet to list (default: the signing wallet)
+        agent: Option<Pubkey>,
+    },
+    /// Move a wallet's stacks created before seasons into the current season
+    /// (anyone may pay; legacy stacks are unusable until migrated)
+    MigrateStacks {
+        /// Wallet whose stacks to migrate (default: the signing wallet)
+        agent: Option<Pubkey>,
+    },
+    /// Vault balances and on-chain economy counters
+    Stats {
+        /// Also count each program's transactions (slow on public RPCs)
+        #[arg(long)]
+        tx_counts: bool,
+    },
+    /// Transfer KILL from the wallet into the game vault
+    FundVault {
+        #[arg(value_parser = parse_kill)]
+        amount: u64,
+    },
+    /// Transfer KILL from the wallet into the faucet vault
+    FundFaucet {
+        #[arg(value_parser = parse_kill)]
+        amount: u64,
+    },
+    /// Request a SOL airdrop for the wallet (devnet/localnet)
+    Airdrop {
+        #[arg(default_value_t = 2.0)]
+        sol: f64,
+    },
+    /// Create a named wallet under ~/.config/solana and airdrop SOL to it
+    NewWallet {
+        name: String,
+        #[arg(default_value_t = 2.0)]
+        sol: f64,
+    },
+    /// SOL and KILL balances of the wallet and the GameConfig summary
+    Balance,
+}
+
+/// RPC URL and explorer cluster for a moniker, or a custom URL as given.
+fn resolve_cluster(url: &str) -> (String, Option<&'static str>) {
+    match url {
+        "localnet" | "localhost" | "l" => ("http://127.0.0.1:8899".into(), None),
+        "devnet" | "d" => ("https://api.devnet.solana.com".into(), Some("devnet")),
+        "testnet" | "t" => ("https://api.testnet.solana.com".into(), Some("testnet")),
+        "mainnet-beta" | "mainnet" | "m" => ("https://api.mainnet-beta.solana.com".into(), Some("mainnet-beta")),
+        custom => (custom.into(), None),
+    }
+}
+
+fn solana_config_dir() -> PathBuf {
+    let home = std::env::var_os("HOME").unwrap_or_else(|| ".".into());
+    PathBuf::from(home).join(".config/solana")
+}
+
+/// A wallet name resolves to ~/.config/solana/<name>.json; anything that
+/// looks like a path is used as one (with `~` expanded).
+fn resolve_keypair(value: &str) -> PathBuf {
+    if let Some(rest) = value.strip_prefix("~/") {
+        let home = std::env::var_os("HOME").unwrap_or_else(|| ".".into());
+        return PathBuf::from(home).join(rest);
+    }
+    if value.contains('/') || value.ends_with(".json") {
+        return PathBuf::from(value);
+    }
+    solana_config_dir().join(format!("{value}.json"))
+}
+
+fn run(ctx: &Ctx, command: Command) -> Result<Value> {
+    match command {
+        Command::Init { season_slots, mint_keypair } => {
+            setup::init(ctx, &resolve_keypair(&mint_keypair), season_slots)
+        }
+        Command::Mint { amount, destination } => setup::mint(ctx, amount, destination),
+        Command::Spawn { stack_id, units } => play::spawn(ctx, stack_id, units),
+        Command::Move { from, to, units, reapers, close_empty } => {
+            play::move_units(ctx, from, to, units, reapers, close_empty)
+        }
+        Command::Kill {
+            stack_id,
+            defender,
+            units,
+            reapers,
+            close_empty,
+            max_defender_power,
+            min_bounty,
+            require_win,
+        } => {
+            let guards = KillGuards {
+                max_defender_power,
+                min_attacker_bounty: min_bounty,
+                require_win,
+            };
+            play::kill(ctx, stack_id, defender, units, reapers, close_empty, guards)
+        }
+        Command::Stacks { agent } => play::stacks(ctx, agent),
+        Command::MigrateStacks { agent } => play::migrate_stacks(ctx, agent),
+        Command::Stats { tx_counts } => stats::stats(ctx, tx_counts),
+        Command::FundVault { amount } => setup::fund_vault(ctx, amount),
+        Command::FundFaucet { amount } => setup::fund_faucet(ctx, amount),
+        Command::Airdrop { sol } => wallet::airdrop(ctx, sol),
+        Command::NewWallet { name, sol } => wallet::new_wallet(ctx, &name, &resolve_keypair(&name), sol),
+        Command::Balance => wallet::balance(ctx),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let (url, explorer_cluster) = resolve_cluster(&cli.url);
+    let ctx = Ctx::new(
+        Rpc::new(url),
+        explorer_cluster,
+        resolve_keypair(&cli.keypair),
+        cli.json,
+        cli.simulate,
+    );
+
+    match run(&ctx, cli.command) {
+        Ok(report) => {
+            if ctx.json {
+                println!("{}", serde_json::to_string_pretty(&report).expect("JSON values serialize"));
+            }
+            if ctx.failed() {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Err(e) => {
+            eprintln!("error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Minimal blocking JSON-RPC client covering the methods the CLI calls.
+//!
+//! Every request uses `confirmed` commitment.  RPC errors surface as
+//! `anyhow` errors carrying the node's message and, for failed preflight
+//! simulations, the program logs.
+
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, Context, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_hash::Hash;
+use solana_transaction::Transaction;
+
+const COMMITMENT: &str = "confirmed";
+
+/// How long `confirm` polls before giving up.
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Result of `simulateTransaction`.
+pub struct Simulation {
+    /// Transaction error as returned by the node, `None` on success
+    pub err: Option<Value>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+pub struct Rpc {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl Rpc {
+    pub fn new(url: String) -> Self {
+        let agent = ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build();
+        Self { url, agent }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let mut reply: Value = match self.agent.post(&self.url).send_json(body) {
+            Ok(resp) => resp.into_json()?,
+            // Some nodes answer JSON-RPC errors with a non-200 status
+            Err(ureq::Error::Status(code, resp)) => resp
+                .into_json()
+                .map_err(|_| anyhow!("{method}: HTTP {code} from {}", self.url))?,
+            Err(e) => return Err(e).context(method.to_owned()),
+        };
+        if let Some(err) = reply.get("error") {
+            let mut msg = format!("{method}: {}", err["message"].as_str().unwrap_or("RPC error"));
+            if let Some(logs) = err["data"]["logs"].as_array() {
+                for line in logs.iter().filter_map(Value::as_str) {
+                    msg.push_str("\n  ");
+                    msg.push_str(line);
+                }
+            }
+            bail!(msg);
+        }
+        Ok(reply["result"].take())
+    }
+
+    /// Raw account data, `None` if the account does not exist.
+    pub fn account_data(&self, key: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let res = self.call(
+            "getAccountInfo",
+            json!([key.to_string(), { "encoding": "base64", "commitment": COMMITMENT }]),
+        )?;
+        match res["value"]["data"][0].as_str() {
+            Some(data) => Ok(Some(STANDARD.decode(data)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// `(address, data)` of every account owned by `program` matching `filters`.
+    pub fn program_accounts(&self, program: &Pubkey, filters: Value) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+        let res = self.call(
+            "getProgramAccounts",
+            json!([program.to_string(), {
+                "encoding": "base64",
+                "commitment": COMMITMENT,
+                "filters": filters,
+            }]),
+        )?;
+        let mut out = Vec::new();
+        for entry in res.as_array().into_iter().flatten() {
+            let key = Pubkey::from_str(entry["pubkey"].as_str().unwrap_or_default())?;
+            let data = STANDARD.decode(entry["account"]["data"][0].as_str().unwrap_or_default())?;
+            out.push((key, data));
+        }
+        Ok(out)
+    }
+
+    pub fn balance(&self, key: &Pubkey) -> Result<u64> {
+        let res = self.call("getBalance", json!([key.to_string(), { "commitment": COMMITMENT }]))?;
+        res["value"].as_u64().ok_or_else(|| anyhow!("getBalance: malformed reply"))
+    }
+
+    pub fn slot(&self) -> Result<u64> {
+        let res = self.call("getSlot", json!([{ "commitment": COMMITMENT }]))?;
+        res.as_u64().ok_or_else(|| anyhow!("getSlot: malformed reply"))
+    }
+
+    pub fn latest_blockhash(&self) -> Result<Hash> {
+        let res = self.call("getLatestBlockhash", json!([{ "commitment": COMMITMENT }]))?;
+        let hash = res["value"]["blockhash"].as_str().unwrap_or_default();
+        Hash::from_str(hash).map_err(|e| anyhow!("getLatestBlockhash: {e}"))
+    }
+
+    /// Submit a signed transaction (with preflight) and return its signature.
+    pub fn send_transaction(&self, tx: &Transaction) -> Result<String> {
+        let res = self.call(
+            "sendTransaction",
+            json!([encode(tx)?, { "encoding": "base64", "preflightCommitment": COMMITMENT }]),
+        )?;
+        res.as_str()
+            .map(str::to_owned)
+            .ok_or_else(|| anyhow!("sendTransaction: malformed reply"))
+    }
+
+    /// Simulate `tx` without signature checks, against a fresh blockhash.
+    pub fn simulate_transaction(&self, tx: &Transaction) -> Result<Simulation> {
+        let mut res = self.call(
+            "simulateTransaction",
+            json!([encode(tx)?, {
+                "encoding": "base64",
+                "commitment": COMMITMENT,
+                "sigVerify": false,
+                "replaceRecentBlockhash": true,
+            }]),
+        )?;
+        let value = res["value"].take();
+        Ok(Simulation {
+            err: Some(value["err"].clone()).filter(|e| !e.is_null()),
+            logs: string_list(&value["logs"]),
+            units_consumed: value["unitsConsumed"].as_u64(),
+        })
+    }
+
+    /// Wait until `signature` reaches `confirmed`; fails if the transaction failed.
+    pub fn confirm(&self, signature: &str) -> Result<()> {
+        let started = Instant::now();
+        loop {
+            let res = self.call("getSignatureStatuses", json!([[signature]]))?;
+            let status = &res["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    bail!("transaction {signature} failed: {}", status["err"]);
+                }
+                if matches!(status["confirmationStatus"].as_str(), Some("confirmed" | "finalized")) {
+                    return Ok(());
+                }
+            }
+            if started.elapsed() > CONFIRM_TIMEOUT {
+                bail!("transaction {signature} not confirmed after {}s", CONFIRM_TIMEOUT.as_secs());
+            }
+            sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Log messages of a confirmed transaction (empty if the node no longer has it).
+    pub fn transaction_logs(&self, signature: &str) -> Result<Vec<String>> {
+        let res = self.call(
+            "getTransaction",
+            json!([signature, {
+                "encoding": "json",
+                "commitment": COMMITMENT,
+                "maxSupportedTransactionVersion": 0,
+            }]),
+        )?;
+        Ok(string_list(&res["meta"]["logMessages"]))
+    }
+
+    pub fn request_airdrop(&self, key: &Pubkey, lamports: u64) -> Result<String> {
+        let res = self.call(
+            "requestAirdrop",
+            json!([key.to_string(), lamports, { "commitment": COMMITMENT }]),
+        )?;
+        res.as_str()
+            .map(str::to_owned)
+            .ok_or_else(|| anyhow!("requestAirdrop: malformed reply"))
+    }
+
+    /// One page of signatures for `address`, newest first: `(signature, succeeded)`.
+    pub fn signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<(String, bool)>> {
+        let mut opts = json!({ "limit": limit, "commitment": COMMITMENT });
+        if let Some(before) = before {
+            opts["before"] = json!(before);
+        }
+        let res = self.call("getSignaturesForAddress", json!([address.to_string(), opts]))?;
+        Ok(res
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|s| Some((s["signature"].as_str()?.to_owned(), s["err"].is_null())))
+            .collect())
+    }
+}
+
+fn encode(tx: &Transaction) -> Result<String> {
+    Ok(STANDARD.encode(bincode::serialize(tx)?))
+}
+
+fn string_list(v: &Value) -> Vec<String> {
+    v.as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|s| s.as_str().map(str::to_owned))
+        .collect()
+}
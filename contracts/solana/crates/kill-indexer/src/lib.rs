@@ -0,0 +1,24 @@
+//! Self-contained indexer for `kill_game` events.
+//!
+//! [`source`] follows the program's confirmed transactions over JSON-RPC and
+//! decodes the Anchor events in their logs; [`store`] writes them to SQLite:
+//!
+//! - `battles`, `spawns`, `moves` — one row per `KillEvent`, `StackSpawned`
+//!   and `StackMoved`, keyed by `(signature, event_index)`.
+//! - `agents` — per-wallet aggregates, updated only when an event row is
+//!   new, so replaying any range of transactions is idempotent.
+//! - `cursor` — the last indexed transaction, where a restart resumes.
+
+pub mod source;
+pub mod store;
+
+pub use kill_sdk::events::GameEvent;
+
+/// A confirmed `kill_game` transaction and the events it emitted, in log order.
+pub struct IndexedTx {
+    pub signature: String,
+    pub slot: u64,
+    /// Unix timestamp, if the node reports one
+    pub block_time: Option<i64>,
+    pub events: Vec<GameEvent>,
+}
@@ -0,0 +1,102 @@
+//! `kill-indexer` — follows `kill_game` transactions on a validator and
+//! stores their events in SQLite.
+//!
+//! Without `--from-slot` it resumes after the last indexed transaction (or
+//! from the start of the node's history on first run).  `--from-slot N`
+//! re-reads everything from slot N; rows already present are skipped, so
+//! this is always safe.  Add `--rewind` to first delete indexed events at
+//! or after N and rebuild the aggregates (e.g. after a validator reset).
+
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+use kill_indexer::source::RpcSource;
+use kill_indexer::store::Store;
+
+#[derive(Parser)]
+#[command(name = "kill-indexer", version, about = "Index kill_game events into SQLite")]
+struct Args {
+    /// Validator JSON-RPC URL
+    #[arg(short = 'u', long, env = "SOLANA_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// SQLite database file (created if missing)
+    #[arg(long, default_value = "kill-index.sqlite")]
+    db: PathBuf,
+
+    /// Re-read transactions from this slot instead of resuming
+    #[arg(long)]
+    from_slot: Option<u64>,
+
+    /// Delete indexed events at or after --from-slot before re-reading
+    #[arg(long, requires = "from_slot")]
+    rewind: bool,
+
+    /// Index what is available and exit instead of following
+    #[arg(long)]
+    once: bool,
+
+    /// Delay between polls when following, in milliseconds
+    #[arg(long, default_value_t = 1_000)]
+    poll_ms: u64,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let source = RpcSource::new(args.url.clone());
+    let mut store = Store::open(&args.db)?;
+
+    if args.rewind {
+        let slot = args.from_slot.unwrap_or_default();
+        store.rewind(slot)?;
+        println!("rewound {} to slot {slot}", args.db.display());
+    }
+
+    let min_slot = args.from_slot.unwrap_or(0);
+    let mut until = match args.from_slot {
+        Some(_) => None,
+        None => store.cursor()?.map(|c| c.signature),
+    };
+
+    loop {
+        match poll(&source, &mut store, until.as_deref(), min_slot) {
+            Ok(Some(last)) => until = Some(last),
+            Ok(None) => {}
+            // Keep following through transient RPC errors; the next poll
+            // re-reads the batch and already-indexed events are skipped.
+            Err(e) if !args.once => eprintln!("poll failed: {e:#}"),
+            Err(e) => return Err(e),
+        }
+        if args.once {
+            break;
+        }
+        sleep(Duration::from_millis(args.poll_ms));
+    }
+
+    let counts = store.counts()?;
+    println!(
+        "{}: {} battles, {} spawns, {} moves, {} agents",
+        args.db.display(),
+        counts.battles,
+        counts.spawns,
+        counts.moves,
+        counts.agents
+    );
+    Ok(())
+}
+
+/// Index every new transaction; returns the newest signature seen.
+fn poll(source: &RpcSource, store: &mut Store, until: Option<&str>, min_slot: u64) -> Result<Option<String>> {
+    let signatures = source.new_signatures(until, min_slot)?;
+    for info in signatures.iter().filter(|s| s.succeeded) {
+        let tx = source.transaction(&info.signature)?;
+        let new = store.apply(&tx)?;
+        if new > 0 {
+            println!("slot {:>10}  {}  {new} event(s)", tx.slot, tx.signature);
+        }
+    }
+    Ok(signatures.last().map(|s| s.signature.clone()))
+}
@@ -0,0 +1,118 @@
+//! Transaction source: `kill_game` signatures and logs over JSON-RPC.
+//!
+//! `getSignaturesForAddress` lists the program's transactions newest first;
+//! `new_signatures` pages back to a known signature or slot and returns them
+//! oldest first, and `transaction` fetches one and decodes its events.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use kill_sdk::events::parse_logs;
+use kill_sdk::GAME_PROGRAM_ID;
+use serde_json::{json, Value};
+
+use crate::IndexedTx;
+
+const COMMITMENT: &str = "confirmed";
+const PAGE_SIZE: usize = 1_000;
+
+/// One entry of `getSignaturesForAddress`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureInfo {
+    pub signature: String,
+    pub slot: u64,
+    /// False if the transaction failed (it emitted no events)
+    pub succeeded: bool,
+}
+
+pub struct RpcSource {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl RpcSource {
+    pub fn new(url: String) -> Self {
+        let agent = ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build();
+        Self { url, agent }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let mut reply: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(body)
+            .with_context(|| format!("{method}: {}", self.url))?
+            .into_json()?;
+        if let Some(err) = reply.get("error") {
+            bail!("{method}: {}", err["message"].as_str().unwrap_or("RPC error"));
+        }
+        Ok(reply["result"].take())
+    }
+
+    /// `kill_game` transactions newer than `until` (exclusive) and at or
+    /// after `min_slot`, oldest first.  With `until = None` pages back
+    /// through the node's whole history down to `min_slot`.
+    pub fn new_signatures(&self, until: Option<&str>, min_slot: u64) -> Result<Vec<SignatureInfo>> {
+        let mut out = Vec::new();
+        let mut before: Option<String> = None;
+        loop {
+            let mut opts = json!({ "limit": PAGE_SIZE, "commitment": COMMITMENT });
+            if let Some(before) = &before {
+                opts["before"] = json!(before);
+            }
+            if let Some(until) = until {
+                opts["until"] = json!(until);
+            }
+            let page = self.call("getSignaturesForAddress", json!([GAME_PROGRAM_ID.to_string(), opts]))?;
+            let page: Vec<SignatureInfo> = page
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|s| {
+                    Some(SignatureInfo {
+                        signature: s["signature"].as_str()?.to_owned(),
+                        slot: s["slot"].as_u64()?,
+                        succeeded: s["err"].is_null(),
+                    })
+                })
+                .collect();
+            let full = page.len() == PAGE_SIZE;
+            let reached_floor = page.last().is_some_and(|s| s.slot < min_slot);
+            before = page.last().map(|s| s.signature.clone());
+            out.extend(page.into_iter().filter(|s| s.slot >= min_slot));
+            if !full || reached_floor {
+                break;
+            }
+        }
+        out.reverse();
+        Ok(out)
+    }
+
+    /// Fetch a confirmed transaction and decode its `kill_game` events.
+    pub fn transaction(&self, signature: &str) -> Result<IndexedTx> {
+        let res = self.call(
+            "getTransaction",
+            json!([signature, {
+                "encoding": "json",
+                "commitment": COMMITMENT,
+                "maxSupportedTransactionVersion": 0,
+            }]),
+        )?;
+        if res.is_null() {
+            return Err(anyhow!("getTransaction: {signature} not found"));
+        }
+        let logs: Vec<&str> = res["meta"]["logMessages"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        Ok(IndexedTx {
+            signature: signature.to_owned(),
+            slot: res["slot"].as_u64().unwrap_or_default(),
+            block_time: res["blockTime"].as_i64(),
+            events: parse_logs(logs),
+        })
+    }
+}
@@ -0,0 +1,533 @@
+//! SQLite schema and writes.
+//!
+//! Event rows are keyed by `(signature, event_index)` and inserted with
+//! `INSERT OR IGNORE`; an event's contribution to `agents` is applied only
+//! when its row was actually inserted.  Every transaction is written in one
+//! SQLite transaction together with the cursor, so a crash never leaves an
+//! event counted twice or half-applied.
+
+use std::path::Path;
+
+use anyhow::Result;
+use kill_sdk::kill_game::state::{KillEvent, StackMoved, StackSpawned};
+use kill_sdk::Pubkey;
+use rusqlite::{params, Connection, OptionalExtension, Row, Transaction};
+
+use crate::{GameEvent, IndexedTx};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS battles (
+    signature             TEXT    NOT NULL,
+    event_index           INTEGER NOT NULL,
+    slot                  INTEGER NOT NULL,
+    block_time            INTEGER,
+    attacker              TEXT    NOT NULL,
+    defender              TEXT    NOT NULL,
+    attacker_stack        INTEGER NOT NULL,
+    defender_stack        INTEGER NOT NULL,
+    attacker_won          INTEGER NOT NULL,
+    attacker_units_sent   INTEGER NOT NULL,
+    attacker_reapers_sent INTEGER NOT NULL,
+    attacker_units_lost   INTEGER NOT NULL,
+    attacker_reapers_lost INTEGER NOT NULL,
+    remaining_units       INTEGER NOT NULL,
+    remaining_reapers     INTEGER NOT NULL,
+    defender_units        INTEGER NOT NULL,
+    defender_reapers      INTEGER NOT NULL,
+    defender_units_lost   INTEGER NOT NULL,
+    defender_reapers_lost INTEGER NOT NULL,
+    attacker_bounty       INTEGER NOT NULL,
+    defender_bounty       INTEGER NOT NULL,
+    total_burned          INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS battles_slot ON battles (slot);
+CREATE INDEX IF NOT EXISTS battles_attacker ON battles (attacker);
+CREATE INDEX IF NOT EXISTS battles_defender ON battles (defender);
+
+CREATE TABLE IF NOT EXISTS spawns (
+    signature   TEXT    NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot        INTEGER NOT NULL,
+    block_time  INTEGER,
+    agent       TEXT    NOT NULL,
+    stack_id        INTEGER NOT NULL,
+    -- Stack totals after the spawn
+    units           INTEGER NOT NULL,
+    reapers         INTEGER NOT NULL,
+    deposited       INTEGER NOT NULL,
+    burned          INTEGER NOT NULL,
+    -- Forces this spawn added
+    spawned_units   INTEGER NOT NULL,
+    spawned_reapers INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS spawns_slot ON spawns (slot);
+CREATE INDEX IF NOT EXISTS spawns_agent ON spawns (agent);
+
+CREATE TABLE IF NOT EXISTS moves (
+    signature   TEXT    NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot        INTEGER NOT NULL,
+    block_time  INTEGER,
+    agent       TEXT    NOT NULL,
+    from_stack  INTEGER NOT NULL,
+    to_stack    INTEGER NOT NULL,
+    units       INTEGER NOT NULL,
+    reapers     INTEGER NOT NULL,
+    deposited   INTEGER NOT NULL,
+    burned      INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS moves_slot ON moves (slot);
+CREATE INDEX IF NOT EXISTS moves_agent ON moves (agent);
+
+CREATE TABLE IF NOT EXISTS agents (
+    agent           TEXT    PRIMARY KEY,
+    spawns          INTEGER NOT NULL DEFAULT 0,
+    moves           INTEGER NOT NULL DEFAULT 0,
+    attacks         INTEGER NOT NULL DEFAULT 0,
+    kills_won       INTEGER NOT NULL DEFAULT 0,
+    defenses        INTEGER NOT NULL DEFAULT 0,
+    defenses_lost   INTEGER NOT NULL DEFAULT 0,
+    units_spawned   INTEGER NOT NULL DEFAULT 0,
+    reapers_spawned INTEGER NOT NULL DEFAULT 0,
+    units_lost      INTEGER NOT NULL DEFAULT 0,
+    reapers_lost    INTEGER NOT NULL DEFAULT 0,
+    units_killed    INTEGER NOT NULL DEFAULT 0,
+    reapers_killed  INTEGER NOT NULL DEFAULT 0,
+    -- KILL paid for spawns and moves, burned share included
+    total_spent     INTEGER NOT NULL DEFAULT 0,
+    -- Bounties received (after burn), as attacker or defender
+    total_earned    INTEGER NOT NULL DEFAULT 0,
+    net_pnl         INTEGER GENERATED ALWAYS AS (total_earned - total_spent) VIRTUAL,
+    first_slot      INTEGER NOT NULL,
+    last_slot       INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS cursor (
+    id        INTEGER PRIMARY KEY CHECK (id = 1),
+    signature TEXT    NOT NULL,
+    slot      INTEGER NOT NULL
+);
+";
+
+/// The last indexed transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cursor {
+    pub signature: String,
+    pub slot: u64,
+}
+
+/// One row of `agents`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AgentStats {
+    pub spawns: u64,
+    pub moves: u64,
+    pub attacks: u64,
+    pub kills_won: u64,
+    pub defenses: u64,
+    pub defenses_lost: u64,
+    pub units_spawned: u64,
+    pub reapers_spawned: u64,
+    pub units_lost: u64,
+    pub reapers_lost: u64,
+    pub units_killed: u64,
+    pub reapers_killed: u64,
+    pub total_spent: u64,
+    pub total_earned: u64,
+    pub net_pnl: i64,
+    pub first_slot: u64,
+    pub last_slot: u64,
+}
+
+/// Row counts of the event tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub battles: u64,
+    pub spawns: u64,
+    pub moves: u64,
+    pub agents: u64,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    pub fn cursor(&self) -> Result<Option<Cursor>> {
+        Ok(self
+            .conn
+            .query_row("SELECT signature, slot FROM cursor WHERE id = 1", [], |r| {
+                Ok(Cursor { signature: r.get(0)?, slot: r.get(1)? })
+            })
+            .optional()?)
+    }
+
+    /// Record `tx`'s events and advance the cursor to it.  Returns the number
+    /// of events that were new; replaying a transaction returns 0 and changes
+    /// nothing but the cursor.
+    pub fn apply(&mut self, tx: &IndexedTx) -> Result<usize> {
+        let db = self.conn.transaction()?;
+        let mut inserted = 0;
+        for (index, event) in tx.events.iter().enumerate() {
+            if insert_event(&db, tx, index, event)? {
+                inserted += 1;
+                for (agent, delta) in agent_deltas(event) {
+                    bump_agent(&db, &agent, &delta, event_slot(event))?;
+                }
+            }
+        }
+        db.execute(
+            "INSERT INTO cursor (id, signature, slot) VALUES (1, ?1, ?2)
+             ON CONFLICT (id) DO UPDATE SET signature = excluded.signature, slot = excluded.slot",
+            params![tx.signature, tx.slot],
+        )?;
+        db.commit()?;
+        Ok(inserted)
+    }
+
+    /// Drop every event at or after `slot` and rebuild `agents` from what is
+    /// left, so that range can be indexed again from scratch.  Clears the
+    /// cursor.
+    pub fn rewind(&mut self, slot: u64) -> Result<()> {
+        let db = self.conn.transaction()?;
+        for table in ["battles", "spawns", "moves"] {
+            db.execute(&format!("DELETE FROM {table} WHERE slot >= ?1"), [slot])?;
+        }
+        db.execute("DELETE FROM agents", [])?;
+        db.execute("DELETE FROM cursor", [])?;
+
+        let mut events = Vec::new();
+        for (query, decode) in [
+            ("SELECT * FROM battles", battle_from_row as fn(&Row) -> rusqlite::Result<GameEvent>),
+            ("SELECT * FROM spawns", spawn_from_row),
+            ("SELECT * FROM moves", move_from_row),
+        ] {
+            let mut stmt = db.prepare(query)?;
+            let rows = stmt.query_map([], decode)?;
+            for event in rows {
+                events.push(event?);
+            }
+        }
+        for event in &events {
+            for (agent, delta) in agent_deltas(event) {
+                bump_agent(&db, &agent, &delta, event_slot(event))?;
+            }
+        }
+        db.commit()?;
+        Ok(())
+    }
+
+    pub fn agent(&self, agent: &Pubkey) -> Result<Option<AgentStats>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT spawns, moves, attacks, kills_won, defenses, defenses_lost,
+                        units_spawned, reapers_spawned, units_lost, reapers_lost,
+                        units_killed, reapers_killed, total_spent, total_earned, net_pnl,
+                        first_slot, last_slot
+                 FROM agents WHERE agent = ?1",
+                [agent.to_string()],
+                |r| {
+                    Ok(AgentStats {
+                        spawns: r.get(0)?,
+                        moves: r.get(1)?,
+                        attacks: r.get(2)?,
+                        kills_won: r.get(3)?,
+                        defenses: r.get(4)?,
+                        defenses_lost: r.get(5)?,
+                        units_spawned: r.get(6)?,
+                        reapers_spawned: r.get(7)?,
+                        units_lost: r.get(8)?,
+                        reapers_lost: r.get(9)?,
+                        units_killed: r.get(10)?,
+                        reapers_killed: r.get(11)?,
+                        total_spent: r.get(12)?,
+                        total_earned: r.get(13)?,
+                        net_pnl: r.get(14)?,
+                        first_slot: r.get(15)?,
+                        last_slot: r.get(16)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+
+    pub fn counts(&self) -> Result<Counts> {
+        let count = |table: &str| -> Result<u64> {
+            Ok(self
+                .conn
+                .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |r| r.get(0))?)
+        };
+        Ok(Counts {
+            battles: count("battles")?,
+            spawns: count("spawns")?,
+            moves: count("moves")?,
+            agents: count("agents")?,
+        })
+    }
+}
+
+fn event_slot(event: &GameEvent) -> u64 {
+    match event {
+        GameEvent::Kill(e) => e.slot,
+        GameEvent::Spawned(e) => e.slot,
+        GameEvent::Moved(e) => e.slot,
+    }
+}
+
+/// The attacker keeps every unit it sent on a win and none on a loss.
+fn attacker_won(e: &KillEvent) -> bool {
+    e.remaining_units > 0 || e.remaining_reapers > 0
+}
+
+/// Insert one event row; `false` if it was already indexed.
+fn insert_event(db: &Transaction, tx: &IndexedTx, index: usize, event: &GameEvent) -> Result<bool> {
+    let changed = match event {
+        GameEvent::Kill(e) => db.execute(
+            "INSERT OR IGNORE INTO battles (
+                signature, event_index, slot, block_time, attacker, defender,
+                attacker_stack, defender_stack, attacker_won,
+                attacker_units_sent, attacker_reapers_sent, attacker_units_lost, attacker_reapers_lost,
+                remaining_units, remaining_reapers,
+                defender_units, defender_reapers, defender_units_lost, defender_reapers_lost,
+                attacker_bounty, defender_bounty, total_burned
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22)",
+            params![
+                tx.signature,
+                index,
+                e.slot,
+                tx.block_time,
+                e.attacker.to_string(),
+                e.defender.to_string(),
+                e.attacker_stack,
+                e.defender_stack,
+                attacker_won(e),
+                e.attacker_units_sent,
+                e.attacker_reapers_sent,
+                e.attacker_units_lost,
+                e.attacker_reapers_lost,
+                e.remaining_units,
+                e.remaining_reapers,
+                e.defender_units,
+                e.defender_reapers,
+                e.defender_units_lost,
+                e.defender_reapers_lost,
+                e.attacker_bounty,
+                e.defender_bounty,
+                e.total_burned,
+            ],
+        )?,
+        GameEvent::Spawned(e) => db.execute(
+            "INSERT OR IGNORE INTO spawns (
+                signature, event_index, slot, block_time, agent, stack_id, units, reapers, deposited, burned,
+                spawned_units, spawned_reapers
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            params![
+                tx.signature,
+                index,
+                e.slot,
+                tx.block_time,
+                e.agent.to_string(),
+                e.stack_id,
+                e.units,
+                e.reapers,
+                e.deposited,
+                e.burned,
+                e.spawned_units,
+                e.spawned_reapers,
+            ],
+        )?,
+        GameEvent::Moved(e) => db.execute(
+            "INSERT OR IGNORE INTO moves (
+                signature, event_index, slot, block_time, agent, from_stack, to_stack, units, reapers, deposited, burned
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                tx.signature,
+                index,
+                e.slot,
+                tx.block_time,
+                e.agent.to_string(),
+                e.from_stack,
+                e.to_stack,
+                e.units,
+                e.reapers,
+                e.deposited,
+                e.burned,
+            ],
+        )?,
+    };
+    Ok(changed == 1)
+}
+
+/// Each affected agent's change in aggregates from one event.
+fn agent_deltas(event: &GameEvent) -> Vec<(Pubkey, AgentStats)> {
+    match event {
+        GameEvent::Spawned(e) => vec![(
+            e.agent,
+            AgentStats {
+                spawns: 1,
+                units_spawned: e.spawned_units,
+                reapers_spawned: e.spawned_reapers,
+                total_spent: e.deposited.saturating_add(e.burned),
+                ..Default::default()
+            },
+        )],
+        GameEvent::Moved(e) => vec![(
+            e.agent,
+            AgentStats {
+                moves: 1,
+                total_spent: e.deposited.saturating_add(e.burned),
+                ..Default::default()
+            },
+        )],
+        GameEvent::Kill(e) => {
+            let won = attacker_won(e) as u64;
+            vec![
+                (
+                    e.attacker,
+                    AgentStats {
+                        attacks: 1,
+                        kills_won: won,
+                        units_lost: e.attacker_units_lost,
+                        reapers_lost: e.attacker_reapers_lost,
+                        units_killed: e.defender_units_lost,
+                        reapers_killed: e.defender_reapers_lost,
+                        total_earned: e.attacker_bounty,
+                        ..Default::default()
+                    },
+                ),
+                (
+                    e.defender,
+                    AgentStats {
+                        defenses: 1,
+                        defenses_lost: won,
+                        units_lost: e.defender_units_lost,
+                        reapers_lost: e.defender_reapers_lost,
+                        units_killed: e.attacker_units_lost,
+                        reapers_killed: e.attacker_reapers_lost,
+                        total_earned: e.defender_bounty,
+                        ..Default::default()
+                    },
+                ),
+            ]
+        }
+    }
+}
+
+fn bump_agent(db: &Transaction, agent: &Pubkey, d: &AgentStats, slot: u64) -> Result<()> {
+    db.execute(
+        "INSERT INTO agents (
+            agent, spawns, moves, attacks, kills_won, defenses, defenses_lost,
+            units_spawned, reapers_spawned, units_lost, reapers_lost, units_killed, reapers_killed,
+            total_spent, total_earned, first_slot, last_slot
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?16)
+         ON CONFLICT (agent) DO UPDATE SET
+            spawns          = spawns + excluded.spawns,
+            moves           = moves + excluded.moves,
+            attacks         = attacks + excluded.attacks,
+            kills_won       = kills_won + excluded.kills_won,
+            defenses        = defenses + excluded.defenses,
+            defenses_lost   = defenses_lost + excluded.defenses_lost,
+            units_spawned   = units_spawned + excluded.units_spawned,
+            reapers_spawned = reapers_spawned + excluded.reapers_spawned,
+            units_lost      = units_lost + excluded.units_lost,
+            reapers_lost    = reapers_lost + excluded.reapers_lost,
+            units_killed    = units_killed + excluded.units_killed,
+            reapers_killed  = reapers_killed + excluded.reapers_killed,
+            total_spent     = total_spent + excluded.total_spent,
+            total_earned    = total_earned + excluded.total_earned,
+            first_slot      = MIN(first_slot, excluded.first_slot),
+            last_slot       = MAX(last_slot, excluded.last_slot)",
+        params![
+            agent.to_string(),
+            d.spawns,
+            d.moves,
+            d.attacks,
+            d.kills_won,
+            d.defenses,
+            d.defenses_lost,
+            d.units_spawned,
+            d.reapers_spawned,
+            d.units_lost,
+            d.reapers_lost,
+            d.units_killed,
+            d.reapers_killed,
+            d.total_spent,
+            d.total_earned,
+            slot,
+        ],
+    )?;
+    Ok(())
+}
+
+fn pubkey(row: &Row, column: &str) -> rusqlite::Result<Pubkey> {
+    let text: String = row.get(column)?;
+    text.parse().map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+    })
+}
+
+fn battle_from_row(r: &Row) -> rusqlite::Result<GameEvent> {
+    Ok(GameEvent::Kill(KillEvent {
+        attacker: pubkey(r, "attacker")?,
+        defender: pubkey(r, "defender")?,
+        attacker_stack: r.get("attacker_stack")?,
+        defender_stack: r.get("defender_stack")?,
+        attacker_bounty: r.get("attacker_bounty")?,
+        defender_bounty: r.get("defender_bounty")?,
+        total_burned: r.get("total_burned")?,
+        remaining_units: r.get("remaining_units")?,
+        remaining_reapers: r.get("remaining_reapers")?,
+        slot: r.get("slot")?,
+        attacker_units_sent: r.get("attacker_units_sent")?,
+        attacker_reapers_sent: r.get("attacker_reapers_sent")?,
+        attacker_units_lost: r.get("attacker_units_lost")?,
+        attacker_reapers_lost: r.get("attacker_reapers_lost")?,
+        defender_units: r.get("defender_units")?,
+        defender_reapers: r.get("defender_reapers")?,
+        defender_units_lost: r.get("defender_units_lost")?,
+        defender_reapers_lost: r.get("defender_reapers_lost")?,
+    }))
+}
+
+fn spawn_from_row(r: &Row) -> rusqlite::Result<GameEvent> {
+    Ok(GameEvent::Spawned(StackSpawned {
+        agent: pubkey(r, "agent")?,
+        stack_id: r.get("stack_id")?,
+        units: r.get("units")?,
+        reapers: r.get("reapers")?,
+        slot: r.get("slot")?,
+        deposited: r.get("deposited")?,
+        burned: r.get("burned")?,
+        spawned_units: r.get("spawned_units")?,
+        spawned_reapers: r.get("spawned_reapers")?,
+    }))
+}
+
+fn move_from_row(r: &Row) -> rusqlite::Result<GameEvent> {
+    Ok(GameEvent::Moved(StackMoved {
+        agent: pubkey(r, "agent")?,
+        from_stack: r.get("from_stack")?,
+        to_stack: r.get("to_stack")?,
+        units: r.get("units")?,
+        reapers: r.get("reapers")?,
+        slot: r.get("slot")?,
+        deposited: r.get("deposited")?,
+        burned: r.get("burned")?,
+    }))
+}
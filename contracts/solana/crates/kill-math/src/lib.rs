@@ -0,0 +1,317 @@
+//! Combat and bounty math for the KILL game.
+//!
+//! Plain-integer, `no_std`, dependency-free versions of the formulas the
+//! `kill_game` program settles with, so the program, off-chain clients and
+//! the simulator all link the same code.
+//!
+//! Arithmetic rules, applied uniformly:
+//! - Every division rounds toward zero (floor — all operands are unsigned).
+//! - Products are formed in `u128`; intermediates saturate at `u128::MAX`.
+//! - Results saturate at `u64::MAX` when narrowed back to `u64`.
+//! - Divisors that could be zero are guarded explicitly; no function panics.
+//!
+//! `tests/vectors.rs` locks the outputs — any change that moves a vector is a
+//! change to game economics.
+#![no_std]
+
+/// Units one reaper is worth in power and bounty (matches EVM THERMAL_PARITY)
+pub const THERMAL_PARITY: u64 = 666;
+
+/// Basis-points denominator (10,000 = 100%)
+pub const BPS_DENOM: u64 = 10_000;
+
+/// Minimum combat power a stack retains after full age-decay (5%)
+pub const MIN_DECAY_PCT: u64 = 5;
+
+/// The economy parameters the formulas read (a subset of `GameParams`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// KILL per unit spawned (raw, 6 decimals)
+    pub spawn_cost: u64,
+    /// Bounty multiplier cap
+    pub max_multiplier: u64,
+    /// Slots per +1 multiplier step; treated as 1 if zero
+    pub slots_per_multiplier: u64,
+    /// Cap on one bounty as basis points of the vault balance (0 = uncapped)
+    pub global_cap_bps: u64,
+    /// Share of each fee and bounty burned, in basis points
+    pub burn_bps: u64,
+}
+
+/// The parts of a stack the formulas read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stack {
+    pub units: u64,
+    pub reapers: u64,
+    pub spawn_slot: u64,
+}
+
+/// Result of `resolve_combat`, in actual (not decayed) unit counts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Combat {
+    pub attacker_won: bool,
+    /// Sent attacker units that survive (all of them on a win, none on a loss)
+    pub remaining_units: u64,
+    pub remaining_reapers: u64,
+    pub attacker_units_lost: u64,
+    pub attacker_reapers_lost: u64,
+    pub defender_units_lost: u64,
+    pub defender_reapers_lost: u64,
+}
+
+/// How a pending bounty is released and split by `split_bounty`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BountySplit {
+    /// Share of the pending bounty released by the power destroyed
+    pub battle_pool: u64,
+    /// Attacker's payout after burn
+    pub attacker_payout: u64,
+    /// Defender's payout after burn
+    pub defender_payout: u64,
+    pub attacker_burn: u64,
+    pub defender_burn: u64,
+}
+
+impl BountySplit {
+    pub fn total_burn(&self) -> u64 {
+        self.attacker_burn.saturating_add(self.defender_burn)
+    }
+}
+
+/// Everything `kill` settles, as computed by `strike`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Strike {
+    pub attacker_decay_pct: u64,
+    pub defender_decay_pct: u64,
+    pub defender_power: u64,
+    pub pending_bounty: u64,
+    pub combat: Combat,
+    pub bounty: BountySplit,
+}
+
+fn narrow(v: u128) -> u64 {
+    v.min(u64::MAX as u128) as u64
+}
+
+/// `a × b / d`, floored, with a `u128` product.  Returns 0 when `d` is 0.
+pub fn mul_div(a: u64, b: u64, d: u64) -> u64 {
+    if d == 0 {
+        return 0;
+    }
+    narrow((a as u128 * b as u128) / d as u128)
+}
+
+/// Raw power: units + reapers × THERMAL_PARITY.
+pub fn power(units: u64, reapers: u64) -> u64 {
+    narrow(units as u128 + reapers as u128 * THERMAL_PARITY as u128)
+}
+
+/// Portion of `fee` burned at `burn_bps`, floored.  `burn_bps` above
+/// BPS_DENOM burns the whole fee, so the burn never exceeds it.
+pub fn fee_burn(fee: u64, burn_bps: u64) -> u64 {
+    mul_div(fee, burn_bps.min(BPS_DENOM), BPS_DENOM)
+}
+
+/// Reapers completed by spawning `units` onto a stack that already has
+/// `progress` units banked toward its next reaper.
+///
+///   reapers      = (progress + units) / reaper_threshold
+///   new_progress = (progress + units) % reaper_threshold
+///
+/// Returns `(reapers, new_progress)`; a zero threshold grants none and banks
+/// nothing.
+pub fn accrue_reapers(progress: u64, units: u64, reaper_threshold: u64) -> (u64, u64) {
+    if reaper_threshold == 0 {
+        return (0, 0);
+    }
+    let total = progress as u128 + units as u128;
+    let threshold = reaper_threshold as u128;
+    (narrow(total / threshold), (total % threshold) as u64)
+}
+
+/// Spawn slot of `stack` after `added_power` raw power that dates from
+/// `added_slot` joins it, weighted by raw power:
+///
+///   spawn_slot = (power(stack) × stack.spawn_slot + added_power × added_slot)
+///              / (power(stack) + added_power)
+///
+/// Rounded toward the stack's slot.  An empty stack takes `added_slot`;
+/// adding no power keeps the stack's slot.
+pub fn blend_spawn_slot(stack: &Stack, added_power: u64, added_slot: u64) -> u64 {
+    let total = power(stack.units, stack.reapers) as u128 + added_power as u128;
+    if total == 0 {
+        return added_slot;
+    }
+    // Shift by the added share of the gap, which keeps the product in u128.
+    let shift = |gap: u64| narrow(gap as u128 * added_power as u128 / total);
+    if added_slot >= stack.spawn_slot {
+        stack.spawn_slot + shift(added_slot - stack.spawn_slot)
+    } else {
+        stack.spawn_slot - shift(stack.spawn_slot - added_slot)
+    }
+}
+
+/// Bounty multiplier for a stack based on its age in slots.
+///
+///   mult = clamp(1 + age_slots / slots_per_multiplier, 1, max_multiplier)
+///
+/// A spawn slot in the future counts as age 0.
+pub fn age_multiplier(params: &Params, spawn_slot: u64, current_slot: u64) -> u64 {
+    let age = current_slot.saturating_sub(spawn_slot);
+    let steps = age / params.slots_per_multiplier.max(1);
+    steps.saturating_add(1).min(params.max_multiplier).max(1)
+}
+
+/// Power decay percentage for a stack based on its age in slots.
+///
+///   decay_pct = max(MIN_DECAY_PCT, 100 - (mult - 1) × 95 / (max_multiplier - 1))
+///
+/// Returns a value in [5, 100] inclusive (always 100 when max_multiplier ≤ 1).
+pub fn power_decay_pct(params: &Params, spawn_slot: u64, current_slot: u64) -> u64 {
+    if params.max_multiplier <= 1 {
+        return 100;
+    }
+    let mult = age_multiplier(params, spawn_slot, current_slot);
+    let drop = mul_div(mult - 1, 100 - MIN_DECAY_PCT, params.max_multiplier - 1);
+    100u64.saturating_sub(drop).max(MIN_DECAY_PCT)
+}
+
+/// Combat resolution matching EVM KillGame.sol `_resolveCombat`, extended with
+/// per-stack power decay.
+///
+/// Effective power is raw power × decay_pct.  The defender gets a 10% bonus:
+/// the attacker wins iff `atkEff × 10 > defEff × 11` (ties go to the defender).
+/// - Win:  attacker keeps all sent forces; all defender forces are destroyed.
+/// - Loss: attacker loses all sent forces; the defender loses, per kind,
+///   `floor(count × (atkEff×10)² / (defEff×11)²)`, capped at `count`.
+pub fn resolve_combat(
+    def_units: u64,
+    atk_units: u64,
+    def_reapers: u64,
+    atk_reapers: u64,
+    atk_decay: u64,
+    def_decay: u64,
+) -> Combat {
+    let atk_eff = (power(atk_units, atk_reapers) as u128).saturating_mul(atk_decay as u128);
+    let def_eff = (power(def_units, def_reapers) as u128).saturating_mul(def_decay as u128);
+    let atk_p = atk_eff.saturating_mul(10);
+    let def_p = def_eff.saturating_mul(11);
+
+    if atk_p > def_p {
+        return Combat {
+            attacker_won: true,
+            remaining_units: atk_units,
+            remaining_reapers: atk_reapers,
+            attacker_units_lost: 0,
+            attacker_reapers_lost: 0,
+            defender_units_lost: def_units,
+            defender_reapers_lost: def_reapers,
+        };
+    }
+
+    let p_sq = atk_p.saturating_mul(atk_p);
+    let d_sq = def_p.saturating_mul(def_p);
+    let lanchester = |count: u64| -> u64 {
+        if d_sq == 0 {
+            0
+        } else {
+            ((count as u128).saturating_mul(p_sq) / d_sq).min(count as u128) as u64
+        }
+    };
+
+    Combat {
+        attacker_won: false,
+        remaining_units: 0,
+        remaining_reapers: 0,
+        attacker_units_lost: atk_units,
+        attacker_reapers_lost: atk_reapers,
+        defender_units_lost: lanchester(def_units),
+        defender_reapers_lost: lanchester(def_reapers),
+    }
+}
+
+/// Bounty owed for destroying `stack` outright at `current_slot`.
+///
+/// Matches EVM KillGame.sol getPendingBounty():
+///   raw_bounty = power × spawn_cost × multiplier
+///   cap        = vault_amount × global_cap_bps / BPS_DENOM
+///   bounty     = min(raw_bounty, cap)      (raw_bounty when cap is 0)
+///
+/// An empty stack is worth nothing.
+pub fn get_pending_bounty(stack: &Stack, params: &Params, current_slot: u64, vault_amount: u64) -> u64 {
+    if stack.units == 0 && stack.reapers == 0 {
+        return 0;
+    }
+    let mult = age_multiplier(params, stack.spawn_slot, current_slot);
+    let raw = (power(stack.units, stack.reapers) as u128)
+        .saturating_mul(params.spawn_cost as u128)
+        .saturating_mul(mult as u128);
+    let cap = mul_div(vault_amount, params.global_cap_bps, BPS_DENOM);
+    if cap == 0 {
+        narrow(raw)
+    } else {
+        narrow(raw.min(cap as u128))
+    }
+}
+
+/// Release and split `pending` after `combat` (EVM `_applyRewards` parity).
+///
+///   battle_pool  = pending × min(total_power_lost, THERMAL_PARITY) / THERMAL_PARITY
+///   atk_bounty   = battle_pool × def_power_lost / total_power_lost
+///   def_bounty   = battle_pool × atk_power_lost / total_power_lost
+///   burn         = bounty × burn_bps / BPS_DENOM, taken from each bounty
+pub fn split_bounty(pending: u64, combat: &Combat, burn_bps: u64) -> BountySplit {
+    let def_lost = power(combat.defender_units_lost, combat.defender_reapers_lost);
+    let atk_lost = power(combat.attacker_units_lost, combat.attacker_reapers_lost);
+    let total_lost = def_lost.saturating_add(atk_lost);
+    if total_lost == 0 {
+        return BountySplit::default();
+    }
+
+    let battle_pool = mul_div(pending, total_lost.min(THERMAL_PARITY), THERMAL_PARITY);
+    let atk_bounty = mul_div(battle_pool, def_lost, total_lost);
+    let def_bounty = mul_div(battle_pool, atk_lost, total_lost);
+    let attacker_burn = fee_burn(atk_bounty, burn_bps);
+    let defender_burn = fee_burn(def_bounty, burn_bps);
+
+    BountySplit {
+        battle_pool,
+        attacker_payout: atk_bounty - attacker_burn,
+        defender_payout: def_bounty - defender_burn,
+        attacker_burn,
+        defender_burn,
+    }
+}
+
+/// Full outcome of `attacker` sending `sent_units`/`sent_reapers` against
+/// `defender` at `current_slot`: decay, combat, and the bounty split.
+pub fn strike(
+    params: &Params,
+    attacker: &Stack,
+    defender: &Stack,
+    sent_units: u64,
+    sent_reapers: u64,
+    vault_amount: u64,
+    current_slot: u64,
+) -> Strike {
+    let attacker_decay_pct = power_decay_pct(params, attacker.spawn_slot, current_slot);
+    let defender_decay_pct = power_decay_pct(params, defender.spawn_slot, current_slot);
+    let combat = resolve_combat(
+        defender.units,
+        sent_units,
+        defender.reapers,
+        sent_reapers,
+        attacker_decay_pct,
+        defender_decay_pct,
+    );
+    let pending_bounty = get_pending_bounty(defender, params, current_slot, vault_amount);
+
+    Strike {
+        attacker_decay_pct,
+        defender_decay_pct,
+        defender_power: power(defender.units, defender.reapers),
+        pending_bounty,
+        combat,
+        bounty: split_bounty(pending_bounty, &combat, params.burn_bps),
+    }
+}
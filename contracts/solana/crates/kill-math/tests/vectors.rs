@@ -0,0 +1,231 @@
+//! Test vectors locking the game formulas.  Values were derived independently from
+//! the EVM-parity formulas with the default GameParams; a failing vector
+//! means game economics changed.
+
+use kill_math::*;
+
+const S: u64 = 13_224;
+
+const DEFAULT: Params = Params {
+    spawn_cost: 20_000_000,
+    max_multiplier: 50,
+    slots_per_multiplier: S,
+    global_cap_bps: 2_500,
+    burn_bps: 666,
+};
+
+/// `resolve_combat` arguments: (def_units, atk_units, def_reapers, atk_reapers, atk_decay, def_decay)
+type CombatArgs = (u64, u64, u64, u64, u64, u64);
+
+fn stack(units: u64, reapers: u64, spawn_slot: u64) -> Stack {
+    Stack { units, reapers, spawn_slot }
+}
+
+#[test]
+fn age_multiplier_and_decay() {
+    // (spawn_slot, current_slot, multiplier, decay_pct)
+    let vectors = [
+        (0, 0, 1, 100),
+        (0, S - 1, 1, 100),
+        (0, S, 2, 99),
+        (0, S * 25, 26, 52),
+        (0, S * 49, 50, 5),
+        (0, S * 1_000, 50, 5),
+        (500, 100, 1, 100), // spawn slot in the future counts as age 0
+    ];
+    for (spawn, now, mult, decay) in vectors {
+        assert_eq!(age_multiplier(&DEFAULT, spawn, now), mult, "mult {spawn}→{now}");
+        assert_eq!(power_decay_pct(&DEFAULT, spawn, now), decay, "decay {spawn}→{now}");
+    }
+}
+
+#[test]
+fn degenerate_params_do_not_panic() {
+    let flat = Params { max_multiplier: 1, slots_per_multiplier: 0, ..DEFAULT };
+    assert_eq!(age_multiplier(&flat, 0, u64::MAX), 1);
+    assert_eq!(power_decay_pct(&flat, 0, u64::MAX), 100);
+}
+
+#[test]
+fn combat_vectors() {
+    let vectors: [(CombatArgs, Combat); 10] = [
+        // 300×10 > 100×11 — attacker wins, keeps everything
+        ((100, 300, 0, 0, 100, 100), won(300, 0, 100, 0)),
+        // 100×10 < 300×11 — Lanchester: 300 × 1000² / 3300² = 27.5 → 27
+        ((300, 100, 0, 0, 100, 100), lost(100, 0, 27, 0)),
+        // 110×10 == 100×11 — ties go to the defender
+        ((100, 110, 0, 0, 100, 100), lost(110, 0, 100, 0)),
+        ((100, 111, 0, 0, 100, 100), won(111, 0, 100, 0)),
+        ((666, 1998, 1, 1, 100, 100), won(1998, 1, 666, 1)),
+        // A fully decayed defender falls to an equal fresh force
+        ((1000, 1000, 0, 0, 100, 5), won(1000, 0, 1000, 0)),
+        // …and a fully decayed attacker barely scratches it: 1000 × 50² / 1100² = 2.06 → 2
+        ((1000, 1000, 0, 0, 5, 100), lost(1000, 0, 2, 0)),
+        ((0, 0, 0, 0, 100, 100), lost(0, 0, 0, 0)),
+        // One reaper (666) vs 10 + 2×666: ratio² = 6660² / 14762² ≈ 0.2035
+        ((10, 0, 2, 1, 100, 100), lost(0, 1, 2, 0)),
+        // Saturated u128 squares compare equal: ratio floors to 1 per kind
+        ((u64::MAX, u64::MAX, u64::MAX, u64::MAX, 100, 100), lost(u64::MAX, u64::MAX, 1, 1)),
+    ];
+    for ((du, au, dr, ar, ad, dd), expected) in vectors {
+        assert_eq!(
+            resolve_combat(du, au, dr, ar, ad, dd),
+            expected,
+            "combat {du}/{dr} vs {au}/{ar} @ {ad}%/{dd}%"
+        );
+    }
+}
+
+fn won(units: u64, reapers: u64, def_u: u64, def_r: u64) -> Combat {
+    Combat {
+        attacker_won: true,
+        remaining_units: units,
+        remaining_reapers: reapers,
+        attacker_units_lost: 0,
+        attacker_reapers_lost: 0,
+        defender_units_lost: def_u,
+        defender_reapers_lost: def_r,
+    }
+}
+
+fn lost(units: u64, reapers: u64, def_u: u64, def_r: u64) -> Combat {
+    Combat {
+        attacker_won: false,
+        remaining_units: 0,
+        remaining_reapers: 0,
+        attacker_units_lost: units,
+        attacker_reapers_lost: reapers,
+        defender_units_lost: def_u,
+        defender_reapers_lost: def_r,
+    }
+}
+
+#[test]
+fn pending_bounty_vectors() {
+    // (stack, current_slot, vault_amount, bounty)
+    let vectors = [
+        (stack(0, 0, 0), 0, 1_000_000_000_000_000, 0),
+        // Zero vault means no cap
+        (stack(100, 0, 0), 0, 0, 2_000_000_000),
+        (stack(100, 0, 0), 0, 1_000_000_000_000_000, 2_000_000_000),
+        // 1332 power × 20 KILL × 11
+        (stack(666, 1, 0), S * 10, 1_000_000_000_000_000, 293_040_000_000),
+        // Capped at 25% of the vault
+        (stack(100, 0, 0), 0, 1_000_000, 250_000),
+        (stack(1_000_000, 1_000, 0), S * 60, 4_000_000_000_000, 1_000_000_000_000),
+        // Uncapped overflow saturates instead of wrapping
+        (stack(u64::MAX, 0, 0), 0, 0, u64::MAX),
+    ];
+    for (s, now, vault, bounty) in vectors {
+        assert_eq!(get_pending_bounty(&s, &DEFAULT, now, vault), bounty, "{s:?} @ {now}");
+    }
+}
+
+#[test]
+fn bounty_split_vectors() {
+    // (pending, combat, (battle_pool, atk_payout, def_payout, atk_burn, def_burn))
+    let vectors = [
+        // 100 power destroyed → pool = 2e9 × 100 / 666
+        (2_000_000_000, won(300, 0, 100, 0), (300_300_300, 280_300_301, 0, 19_999_999, 0)),
+        // 127 destroyed: 27 by the attacker, 100 by the defender
+        (2_000_000_000, lost(100, 0, 27, 0), (381_381_381, 75_681_082, 280_300_301, 5_399_999, 19_999_999)),
+        // ≥ THERMAL_PARITY destroyed releases the whole pending bounty
+        (26_640_000_000, won(1998, 1, 666, 1), (26_640_000_000, 24_865_776_000, 0, 1_774_224_000, 0)),
+        (5_000_000, lost(0, 1, 2, 0), (5_000_000, 13_973, 4_653_027, 997, 332_002)),
+        (0, won(300, 0, 100, 0), (0, 0, 0, 0, 0)),
+        (2_000_000_000, lost(0, 0, 0, 0), (0, 0, 0, 0, 0)),
+    ];
+    for (pending, combat, (pool, atk, def, atk_burn, def_burn)) in vectors {
+        let split = split_bounty(pending, &combat, DEFAULT.burn_bps);
+        assert_eq!(
+            split,
+            BountySplit {
+                battle_pool: pool,
+                attacker_payout: atk,
+                defender_payout: def,
+                attacker_burn: atk_burn,
+                defender_burn: def_burn,
+            },
+            "split {pending} {combat:?}"
+        );
+        assert_eq!(split.total_burn(), atk_burn + def_burn);
+    }
+
+    // burn_bps past 100% burns the whole bounty instead of underflowing
+    let split = split_bounty(2_000_000_000, &lost(100, 0, 27, 0), 20_000);
+    assert_eq!(
+        split,
+        BountySplit {
+            battle_pool: 381_381_381,
+            attacker_payout: 0,
+            defender_payout: 0,
+            attacker_burn: 81_081_081,
+            defender_burn: 300_300_300,
+        }
+    );
+}
+
+#[test]
+fn strike_composes_the_parts() {
+    let attacker = stack(300, 0, 0);
+    let defender = stack(100, 0, 0);
+    let strike = strike(&DEFAULT, &attacker, &defender, 300, 0, 1_000_000_000_000_000, 10);
+    assert_eq!(
+        strike,
+        Strike {
+            attacker_decay_pct: 100,
+            defender_decay_pct: 100,
+            defender_power: 100,
+            pending_bounty: 2_000_000_000,
+            combat: won(300, 0, 100, 0),
+            bounty: BountySplit {
+                battle_pool: 300_300_300,
+                attacker_payout: 280_300_301,
+                defender_payout: 0,
+                attacker_burn: 19_999_999,
+                defender_burn: 0,
+            },
+        }
+    );
+}
+
+#[test]
+fn reaper_accrual_spans_calls() {
+    assert_eq!(accrue_reapers(0, 665, 666), (0, 665));
+    assert_eq!(accrue_reapers(665, 665, 666), (1, 664));
+    assert_eq!(accrue_reapers(0, 1_332, 666), (2, 0));
+    assert_eq!(accrue_reapers(664, 2, 666), (1, 0));
+    // Progress banked under a larger threshold pays out at once when it drops
+    assert_eq!(accrue_reapers(600, 1, 100), (6, 1));
+    assert_eq!(accrue_reapers(u64::MAX, u64::MAX, 1), (u64::MAX, 0));
+    assert_eq!(accrue_reapers(5, 1_000, 0), (0, 0));
+}
+
+#[test]
+fn spawn_slot_blends_by_power() {
+    // 1 unit topping up a 3-day-old stack barely moves it; 1,000 units swamp it
+    assert_eq!(blend_spawn_slot(&stack(999, 0, 0), 1, 648_000), 648);
+    assert_eq!(blend_spawn_slot(&stack(1, 0, 0), 999, 648_000), 647_352);
+    // Reapers weigh THERMAL_PARITY units each
+    assert_eq!(blend_spawn_slot(&stack(0, 1, 100), 666, 200), 150);
+    assert_eq!(blend_spawn_slot(&stack(0, 0, 100), 10, 200), 200);
+    assert_eq!(blend_spawn_slot(&stack(10, 0, 100), 0, 200), 100);
+    assert_eq!(blend_spawn_slot(&stack(0, 0, 100), 0, 200), 200);
+    // Rounds toward the stack's own slot either way
+    assert_eq!(blend_spawn_slot(&stack(2, 0, 0), 1, 100), 33);
+    assert_eq!(blend_spawn_slot(&stack(2, 0, 100), 1, 0), 67);
+    assert_eq!(blend_spawn_slot(&stack(u64::MAX, 0, u64::MAX), u64::MAX, u64::MAX), u64::MAX);
+    assert_eq!(blend_spawn_slot(&stack(u64::MAX, 0, 0), u64::MAX, u64::MAX), u64::MAX / 2);
+}
+
+#[test]
+fn rounding_helpers() {
+    assert_eq!(power(10, 2), 1_342);
+    assert_eq!(power(u64::MAX, 1), u64::MAX);
+    assert_eq!(fee_burn(20_000_000, 666), 1_332_000);
+    assert_eq!(fee_burn(14, 666), 0); // 0.9324 floors to 0
+    assert_eq!(fee_burn(14, 20_000), 14); // clamped to 100%
+    assert_eq!(mul_div(u64::MAX, u64::MAX, u64::MAX), u64::MAX);
+    assert_eq!(mul_div(7, 3, 2), 10);
+    assert_eq!(mul_div(1, 1, 0), 0);
+}
@@ -0,0 +1,40 @@
+//! Account deserializers.  Each checks the 8-byte Anchor discriminator before
+//! decoding, so passing the wrong account's data is an error, not garbage.
+
+use anchor_lang::{AccountDeserialize, Result};
+
+use kill_faucet::state::FaucetConfig;
+use kill_game::state::{AgentStack, GameConfig, GameParams};
+use kill_token::TokenConfig;
+
+/// Decode any program account type from raw account data.
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn game_config(data: &[u8]) -> Result<GameConfig> {
+    decode(data)
+}
+
+pub fn game_params(data: &[u8]) -> Result<GameParams> {
+    decode(data)
+}
+
+/// Pre-season stacks (the original layout at the unseasoned seeds, see
+/// `migrate_stack`) decode with `season` and `reaper_progress` zeroed.
+pub fn agent_stack(data: &[u8]) -> Result<AgentStack> {
+    if data.len() == AgentStack::LEGACY_SPACE {
+        let mut padded = data.to_vec();
+        padded.resize(AgentStack::SPACE, 0);
+        return decode(&padded);
+    }
+    decode(data)
+}
+
+pub fn faucet_config(data: &[u8]) -> Result<FaucetConfig> {
+    decode(data)
+}
+
+pub fn token_config(data: &[u8]) -> Result<TokenConfig> {
+    decode(data)
+}
@@ -0,0 +1,97 @@
+//! Decoding of `kill_game` events from transaction logs.
+//!
+//! Anchor's `emit!` writes each event as a `Program data: <base64>` log line
+//! holding the event's 8-byte discriminator followed by its Borsh encoding.
+//! `parse_logs` follows the `invoke`/`success` lines so only data logged by
+//! `kill_game` itself is decoded, also when it runs as an inner instruction.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use kill_game::state::{KillEvent, StackMoved, StackSpawned};
+
+use crate::GAME_PROGRAM_ID;
+
+/// A decoded gameplay event.
+pub enum GameEvent {
+    Kill(KillEvent),
+    Spawned(StackSpawned),
+    Moved(StackMoved),
+}
+
+fn decode_as<T: AnchorDeserialize + Discriminator>(data: &[u8]) -> Option<T> {
+    let body = data.strip_prefix(T::DISCRIMINATOR)?;
+    T::deserialize(&mut &body[..]).ok()
+}
+
+/// Body length of StackSpawned, and of its older layouts: before `deposited`
+/// and `burned` were appended, and before `spawned_units` and `spawned_reapers`.
+const SPAWNED_LEN: usize = 32 + 2 + 8 * 7;
+const LEGACY_SPAWNED_LENS: [usize; 2] = [SPAWNED_LEN - 32, SPAWNED_LEN - 16];
+
+/// Body length of StackMoved, and of its layout before `deposited` and
+/// `burned` were appended.
+const MOVED_LEN: usize = 32 + 2 + 2 + 8 * 5;
+const LEGACY_MOVED_LENS: [usize; 1] = [MOVED_LEN - 16];
+
+/// Like `decode_as`, but a body of one of the `legacy` lengths predates the
+/// fields appended since; it is zero-padded to `len`, so they decode as 0.
+fn decode_padded<T: AnchorDeserialize + Discriminator>(data: &[u8], len: usize, legacy: &[usize]) -> Option<T> {
+    let body = data.strip_prefix(T::DISCRIMINATOR)?;
+    if legacy.contains(&body.len()) {
+        let mut padded = body.to_vec();
+        padded.resize(len, 0);
+        return T::deserialize(&mut &padded[..]).ok();
+    }
+    T::deserialize(&mut &body[..]).ok()
+}
+
+/// Decode one event from its raw bytes (discriminator + Borsh).  Returns
+/// `None` for other event types or malformed data.  Spawns and moves logged
+/// by older program versions decode with the fields they lack set to 0.
+pub fn decode_event(data: &[u8]) -> Option<GameEvent> {
+    if let Some(e) = decode_as::<KillEvent>(data) {
+        return Some(GameEvent::Kill(e));
+    }
+    if let Some(e) = decode_padded::<StackSpawned>(data, SPAWNED_LEN, &LEGACY_SPAWNED_LENS) {
+        return Some(GameEvent::Spawned(e));
+    }
+    decode_padded::<StackMoved>(data, MOVED_LEN, &LEGACY_MOVED_LENS).map(GameEvent::Moved)
+}
+
+/// Every `Program data:` payload logged by `program_id`, base64-decoded, in
+/// log order.
+pub fn program_data<'a>(logs: impl IntoIterator<Item = &'a str>, program_id: &Pubkey) -> Vec<Vec<u8>> {
+    let id = program_id.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut out = Vec::new();
+    for line in logs {
+        let Some(rest) = line.strip_prefix("Program ") else {
+            continue;
+        };
+        if let Some(data) = rest.strip_prefix("data: ") {
+            if stack.last() == Some(&id.as_str()) {
+                if let Ok(bytes) = STANDARD.decode(data.trim()) {
+                    out.push(bytes);
+                }
+            }
+        } else if let Some((program, tail)) = rest.split_once(' ') {
+            if tail.starts_with("invoke [") {
+                stack.push(program);
+            } else if tail == "success" || tail.starts_with("failed") {
+                stack.pop();
+            }
+        }
+    }
+    out
+}
+
+/// Decode the gameplay events `kill_game` logged in one transaction.
+pub fn parse_logs<'a>(logs: impl IntoIterator<Item = &'a str>) -> Vec<GameEvent> {
+    program_data(logs, &GAME_PROGRAM_ID)
+        .iter()
+        .filter_map(|data| decode_event(data))
+        .collect()
+}
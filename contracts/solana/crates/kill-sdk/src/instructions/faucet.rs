@@ -0,0 +1,80 @@
+//! `kill_faucet` instruction builders.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_spl::token;
+
+use kill_faucet::{accounts, instruction};
+
+use super::build;
+use crate::{pda, FAUCET_PROGRAM_ID};
+
+/// `faucet_vault` is a fresh keypair that must also sign.
+pub fn initialize_faucet(admin: Pubkey, kill_mint: Pubkey, faucet_vault: Pubkey) -> Instruction {
+    build(
+        FAUCET_PROGRAM_ID,
+        accounts::InitializeFaucet {
+            faucet_config: pda::faucet_config().0,
+            kill_mint,
+            faucet_vault,
+            admin,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeFaucet {},
+    )
+}
+
+pub fn claim(claimer: Pubkey, claimer_token_account: Pubkey, kill_mint: Pubkey, faucet_vault: Pubkey) -> Instruction {
+    build(
+        FAUCET_PROGRAM_ID,
+        accounts::Claim {
+            faucet_config: pda::faucet_config().0,
+            claim_record: pda::claim_record(&claimer).0,
+            faucet_vault,
+            claimer_token_account,
+            kill_mint,
+            claimer,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::Claim {},
+    )
+}
+
+pub fn propose_admin(admin: Pubkey, new_admin: Pubkey) -> Instruction {
+    build(
+        FAUCET_PROGRAM_ID,
+        accounts::ProposeAdmin {
+            faucet_config: pda::faucet_config().0,
+            admin,
+        },
+        instruction::ProposeAdmin { new_admin },
+    )
+}
+
+pub fn accept_admin(new_admin: Pubkey) -> Instruction {
+    build(
+        FAUCET_PROGRAM_ID,
+        accounts::AcceptAdmin {
+            faucet_config: pda::faucet_config().0,
+            new_admin,
+        },
+        instruction::AcceptAdmin {},
+    )
+}
+
+pub fn migrate_config(admin: Pubkey) -> Instruction {
+    build(
+        FAUCET_PROGRAM_ID,
+        accounts::MigrateConfig {
+            faucet_config: pda::faucet_config().0,
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateConfig {},
+    )
+}
@@ -0,0 +1,637 @@
+//! `kill_game` instruction builders.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_spl::token;
+
+use kill_game::instructions::admin::GameParamsArgs;
+use kill_game::instructions::kill::KillGuards;
+use kill_game::instructions::spawn_batch::SpawnEntry;
+use kill_game::{accounts, instruction};
+
+use super::{build, GameKeys};
+use crate::{pda, GAME_PROGRAM_ID};
+
+/// The Delegation PDA when `authority` acts for `agent`, `None` when the agent
+/// signs for itself.
+fn delegation_for(agent: &Pubkey, authority: &Pubkey) -> Option<Pubkey> {
+    (agent != authority).then(|| pda::delegation(agent).0)
+}
+
+/// The parties to a `kill` or `reveal_kill`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Strike {
+    pub attacker: Pubkey,
+    /// The attacker or its delegate
+    pub authority: Pubkey,
+    pub attacker_token_account: Pubkey,
+    pub defender: Pubkey,
+    pub defender_token_account: Pubkey,
+}
+
+// ── Setup ─────────────────────────────────────────────────────────────────────
+
+/// `game_vault` is a fresh keypair that must also sign.
+pub fn initialize_game(admin: Pubkey, kill_mint: Pubkey, game_vault: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::InitializeGame {
+            game_config: pda::game_config().0,
+            kill_mint,
+            game_vault,
+            admin,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeGame {},
+    )
+}
+
+pub fn initialize_params(admin: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::InitializeParams {
+            game_config: pda::game_config().0,
+            game_params: pda::game_params().0,
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeParams {},
+    )
+}
+
+pub fn initialize_leaderboard(admin: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::InitializeLeaderboard {
+            game_config: pda::game_config().0,
+            leaderboard: pda::leaderboard().0,
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeLeaderboard {},
+    )
+}
+
+pub fn migrate_config(admin: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::MigrateConfig {
+            game_config: pda::game_config().0,
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateConfig {},
+    )
+}
+
+// ── Gameplay ──────────────────────────────────────────────────────────────────
+
+pub fn spawn(
+    keys: &GameKeys,
+    agent: Pubkey,
+    authority: Pubkey,
+    agent_token_account: Pubkey,
+    stack_id: u16,
+    units: u64,
+) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::Spawn {
+            game_config: pda::game_config().0,
+            game_params: pda::game_params().0,
+            season: pda::season(keys.season).0,
+            agent_stack: pda::agent_stack(keys.season, &agent, stack_id).0,
+            cell_index: pda::cell_index(keys.season, stack_id).0,
+            agent_profile: pda::agent_profile(&agent).0,
+            season_standing: pda::season_standing(keys.season, &agent).0,
+            agent_token_account,
+            game_vault: keys.game_vault,
+            kill_mint: keys.kill_mint,
+            agent,
+            delegation: delegation_for(&agent, &authority),
+            authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::Spawn { stack_id, units },
+    )
+}
+
+/// Appends the (agent_stack, cell_index) pair for every entry as remaining accounts.
+/// The program accepts at most `MAX_SPAWN_BATCH` entries.
+pub fn spawn_batch(
+    keys: &GameKeys,
+    agent: Pubkey,
+    authority: Pubkey,
+    agent_token_account: Pubkey,
+    entries: Vec<SpawnEntry>,
+) -> Instruction {
+    let mut ix = build(
+        GAME_PROGRAM_ID,
+        accounts::SpawnBatch {
+            game_config: pda::game_config().0,
+            game_params: pda::game_params().0,
+            season: pda::season(keys.season).0,
+            agent_profile: pda::agent_profile(&agent).0,
+            season_standing: pda::season_standing(keys.season, &agent).0,
+            agent_token_account,
+            game_vault: keys.game_vault,
+            kill_mint: keys.kill_mint,
+            agent,
+            delegation: delegation_for(&agent, &authority),
+            authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::SpawnBatch { entries: entries.clone() },
+    );
+    for entry in &entries {
+        ix.accounts.push(AccountMeta::new(
+            pda::agent_stack(keys.season, &agent, entry.stack_id).0,
+            false,
+        ));
+        ix.accounts
+            .push(AccountMeta::new(pda::cell_index(keys.season, entry.stack_id).0, false));
+    }
+    ix
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn move_units(
+    keys: &GameKeys,
+    agent: Pubkey,
+    authority: Pubkey,
+    agent_token_account: Pubkey,
+    from_stack_id: u16,
+    to_stack_id: u16,
+    units: u64,
+    reapers: u64,
+    close_empty: bool,
+) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::MoveUnits {
+            game_config: pda::game_config().0,
+            game_params: pda::game_params().0,
+            season: pda::season(keys.season).0,
+            from_stack: pda::agent_stack(keys.season, &agent, from_stack_id).0,
+            to_stack: pda::agent_stack(keys.season, &agent, to_stack_id).0,
+            from_cell: pda::cell_index(keys.season, from_stack_id).0,
+            to_cell: pda::cell_index(keys.season, to_stack_id).0,
+            agent_profile: pda::agent_profile(&agent).0,
+            season_standing: pda::season_standing(keys.season, &agent).0,
+            agent_token_account,
+            game_vault: keys.game_vault,
+            kill_mint: keys.kill_mint,
+            agent,
+            delegation: delegation_for(&agent, &authority),
+            authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::MoveUnits {
+            from_stack_id,
+            to_stack_id,
+            units,
+            reapers,
+            close_empty,
+        },
+    )
+}
+
+fn kill_accounts(keys: &GameKeys, strike: &Strike, stack_id: u16, commitment: bool) -> accounts::Kill {
+    let attacker_stack = pda::agent_stack(keys.season, &strike.attacker, stack_id).0;
+    accounts::Kill {
+        game_config: pda::game_config().0,
+        game_params: pda::game_params().0,
+        season: pda::season(keys.season).0,
+        attacker_stack,
+        commitment: commitment.then(|| pda::kill_commitment(&attacker_stack).0),
+        defender_stack: pda::agent_stack(keys.season, &strike.defender, stack_id).0,
+        cell_index: pda::cell_index(keys.season, stack_id).0,
+        attacker_profile: pda::agent_profile(&strike.attacker).0,
+        defender_profile: pda::agent_profile(&strike.defender).0,
+        attacker_standing: pda::season_standing(keys.season, &strike.attacker).0,
+        defender_standing: pda::season_standing(keys.season, &strike.defender).0,
+        leaderboard: pda::leaderboard().0,
+        attacker_token_account: strike.attacker_token_account,
+        defender_token_account: strike.defender_token_account,
+        game_vault: keys.game_vault,
+        kill_mint: keys.kill_mint,
+        attacker: strike.attacker,
+        delegation: delegation_for(&strike.attacker, &strike.authority),
+        authority: strike.authority,
+        defender: strike.defender,
+        token_program: token::ID,
+        system_program: system_program::ID,
+    }
+}
+
+/// Attacker and defender share `stack_id`.
+pub fn kill(
+    keys: &GameKeys,
+    strike: &Strike,
+    stack_id: u16,
+    sent_units: u64,
+    sent_reapers: u64,
+    close_empty: bool,
+    guards: KillGuards,
+) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        kill_accounts(keys, strike, stack_id, false),
+        instruction::Kill {
+            attacker_stack_id: stack_id,
+            defender_stack_id: stack_id,
+            sent_units,
+            sent_reapers,
+            close_empty,
+            guards,
+        },
+    )
+}
+
+/// `hash` is `kill_commitment_hash(stack_id, defender, sent_units, sent_reapers, salt)`.
+pub fn commit_kill(
+    keys: &GameKeys,
+    attacker: Pubkey,
+    authority: Pubkey,
+    stack_id: u16,
+    units: u64,
+    reapers: u64,
+    hash: [u8; 32],
+) -> Instruction {
+    let attacker_stack = pda::agent_stack(keys.season, &attacker, stack_id).0;
+    build(
+        GAME_PROGRAM_ID,
+        accounts::CommitKill {
+            game_config: pda::game_config().0,
+            season: pda::season(keys.season).0,
+            attacker_stack,
+            commitment: pda::kill_commitment(&attacker_stack).0,
+            attacker,
+            delegation: delegation_for(&attacker, &authority),
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::CommitKill {
+            attacker_stack_id: stack_id,
+            units,
+            reapers,
+            hash,
+        },
+    )
+}
+
+pub fn reveal_kill(
+    keys: &GameKeys,
+    strike: &Strike,
+    stack_id: u16,
+    sent_units: u64,
+    sent_reapers: u64,
+    salt: [u8; 32],
+    guards: KillGuards,
+) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        kill_accounts(keys, strike, stack_id, true),
+        instruction::RevealKill {
+            attacker_stack_id: stack_id,
+            defender: strike.defender,
+            sent_units,
+            sent_reapers,
+            salt,
+            guards,
+        },
+    )
+}
+
+/// `season`, `attacker` and `stack_id` are the commitment's own fields.
+pub fn refund_commit(season: u32, attacker: Pubkey, stack_id: u16) -> Instruction {
+    let attacker_stack = pda::agent_stack(season, &attacker, stack_id).0;
+    build(
+        GAME_PROGRAM_ID,
+        accounts::RefundCommit {
+            commitment: pda::kill_commitment(&attacker_stack).0,
+            attacker_stack,
+            attacker,
+        },
+        instruction::RefundCommit {},
+    )
+}
+
+pub fn claim_units(keys: &GameKeys, claimer: Pubkey, proof: Vec<[u8; 32]>, stack_id: u16) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::ClaimUnits {
+            game_config: pda::game_config().0,
+            game_params: pda::game_params().0,
+            airdrop: pda::airdrop().0,
+            season: pda::season(keys.season).0,
+            claim_record: pda::airdrop_claim(&claimer).0,
+            agent_stack: pda::agent_stack(keys.season, &claimer, stack_id).0,
+            cell_index: pda::cell_index(keys.season, stack_id).0,
+            agent_profile: pda::agent_profile(&claimer).0,
+            claimer,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimUnits { proof, stack_id },
+    )
+}
+
+pub fn close_stack(season_id: u32, agent: Pubkey, stack_id: u16) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::CloseStack {
+            season: pda::season(season_id).0,
+            agent_stack: pda::agent_stack(season_id, &agent, stack_id).0,
+            cell_index: pda::cell_index(season_id, stack_id).0,
+            agent,
+            system_program: system_program::ID,
+        },
+        instruction::CloseStack { season_id, stack_id },
+    )
+}
+
+pub fn migrate_stack(season: u32, agent: Pubkey, stack_id: u16, payer: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::MigrateStack {
+            game_config: pda::game_config().0,
+            game_params: pda::game_params().0,
+            legacy_stack: pda::legacy_agent_stack(&agent, stack_id).0,
+            agent_stack: pda::agent_stack(season, &agent, stack_id).0,
+            cell_index: pda::cell_index(season, stack_id).0,
+            agent,
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateStack { stack_id },
+    )
+}
+
+// ── Delegation ────────────────────────────────────────────────────────────────
+
+pub fn set_delegation(
+    owner: Pubkey,
+    owner_token_account: Pubkey,
+    delegate: Pubkey,
+    expiry_slot: u64,
+    allowed: u8,
+    spend_cap: u64,
+) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::SetDelegation {
+            game_config: pda::game_config().0,
+            delegation: pda::delegation(&owner).0,
+            owner_token_account,
+            owner,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::SetDelegation {
+            delegate,
+            expiry_slot,
+            allowed,
+            spend_cap,
+        },
+    )
+}
+
+pub fn revoke_delegation(owner: Pubkey, owner_token_account: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::RevokeDelegation {
+            game_config: pda::game_config().0,
+            delegation: pda::delegation(&owner).0,
+            owner_token_account,
+            owner,
+            token_program: token::ID,
+        },
+        instruction::RevokeDelegation {},
+    )
+}
+
+// ── Views ─────────────────────────────────────────────────────────────────────
+
+/// Simulate to read the `KillQuote` return data.
+pub fn quote_kill(
+    keys: &GameKeys,
+    attacker_stack: Pubkey,
+    defender_stack: Pubkey,
+    sent_units: u64,
+    sent_reapers: u64,
+) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::QuoteKill {
+            game_config: pda::game_config().0,
+            game_params: pda::game_params().0,
+            attacker_stack,
+            defender_stack,
+            game_vault: keys.game_vault,
+        },
+        instruction::QuoteKill { sent_units, sent_reapers },
+    )
+}
+
+/// Simulate to read the `BountyQuote` return data.
+pub fn quote_bounty(keys: &GameKeys, stack: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::QuoteBounty {
+            game_config: pda::game_config().0,
+            game_params: pda::game_params().0,
+            stack,
+            game_vault: keys.game_vault,
+        },
+        instruction::QuoteBounty {},
+    )
+}
+
+// ── Roles ─────────────────────────────────────────────────────────────────────
+
+fn admin_config(pauser: Pubkey) -> accounts::AdminConfig {
+    accounts::AdminConfig {
+        game_config: pda::game_config().0,
+        pauser,
+    }
+}
+
+fn admin_authority(admin: Pubkey) -> accounts::AdminAuthority {
+    accounts::AdminAuthority {
+        game_config: pda::game_config().0,
+        admin,
+    }
+}
+
+pub fn set_paused(pauser: Pubkey, paused: bool) -> Instruction {
+    build(GAME_PROGRAM_ID, admin_config(pauser), instruction::SetPaused { paused })
+}
+
+pub fn set_pause_mask(pauser: Pubkey, pause_mask: u8) -> Instruction {
+    build(GAME_PROGRAM_ID, admin_config(pauser), instruction::SetPauseMask { pause_mask })
+}
+
+pub fn propose_admin(admin: Pubkey, new_admin: Pubkey) -> Instruction {
+    build(GAME_PROGRAM_ID, admin_authority(admin), instruction::ProposeAdmin { new_admin })
+}
+
+pub fn accept_admin(new_admin: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::AcceptAdmin {
+            game_config: pda::game_config().0,
+            new_admin,
+        },
+        instruction::AcceptAdmin {},
+    )
+}
+
+pub fn set_pauser(admin: Pubkey, pauser: Pubkey) -> Instruction {
+    build(GAME_PROGRAM_ID, admin_authority(admin), instruction::SetPauser { pauser })
+}
+
+pub fn set_treasurer(admin: Pubkey, treasurer: Pubkey) -> Instruction {
+    build(GAME_PROGRAM_ID, admin_authority(admin), instruction::SetTreasurer { treasurer })
+}
+
+pub fn set_params_authority(admin: Pubkey, params_authority: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        admin_authority(admin),
+        instruction::SetParamsAuthority { params_authority },
+    )
+}
+
+pub fn set_withdraw_limits(admin: Pubkey, delay_slots: u64, epoch_cap: u64) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        admin_authority(admin),
+        instruction::SetWithdrawLimits { delay_slots, epoch_cap },
+    )
+}
+
+// ── Parameters ────────────────────────────────────────────────────────────────
+
+pub fn set_config(params_authority: Pubkey, args: GameParamsArgs) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::AdminParams {
+            game_config: pda::game_config().0,
+            game_params: pda::game_params().0,
+            params_authority,
+        },
+        instruction::SetConfig { args },
+    )
+}
+
+pub fn set_merkle_root(params_authority: Pubkey, merkle_root: [u8; 32], airdrop_amount: u64) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::AdminAirdrop {
+            game_config: pda::game_config().0,
+            airdrop: pda::airdrop().0,
+            params_authority,
+            system_program: system_program::ID,
+        },
+        instruction::SetMerkleRoot {
+            merkle_root,
+            airdrop_amount,
+        },
+    )
+}
+
+// ── Vault withdrawals ─────────────────────────────────────────────────────────
+
+/// `id` is the current `GameConfig.withdrawal_nonce`.
+pub fn queue_withdraw(treasurer: Pubkey, id: u64, destination: Pubkey, amount: u64) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::QueueWithdraw {
+            game_config: pda::game_config().0,
+            pending_withdrawal: pda::withdrawal(id).0,
+            destination,
+            treasurer,
+            system_program: system_program::ID,
+        },
+        instruction::QueueWithdraw { amount },
+    )
+}
+
+pub fn execute_withdraw(keys: &GameKeys, treasurer: Pubkey, id: u64, destination: Pubkey) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::AdminWithdraw {
+            game_config: pda::game_config().0,
+            pending_withdrawal: pda::withdrawal(id).0,
+            game_vault: keys.game_vault,
+            destination,
+            treasurer,
+            token_program: token::ID,
+        },
+        instruction::ExecuteWithdraw { id },
+    )
+}
+
+pub fn cancel_withdraw(treasurer: Pubkey, id: u64) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::CancelWithdraw {
+            game_config: pda::game_config().0,
+            pending_withdrawal: pda::withdrawal(id).0,
+            treasurer,
+        },
+        instruction::CancelWithdraw { id },
+    )
+}
+
+// ── Seasons ───────────────────────────────────────────────────────────────────
+
+/// `new_season` is `GameConfig.current_season + 1`.
+pub fn start_season(params_authority: Pubkey, new_season: u32, start_slot: u64, end_slot: u64) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::StartSeason {
+            game_config: pda::game_config().0,
+            season: pda::season(new_season).0,
+            params_authority,
+            system_program: system_program::ID,
+        },
+        instruction::StartSeason { start_slot, end_slot },
+    )
+}
+
+pub fn set_season_end(params_authority: Pubkey, season: u32, end_slot: u64) -> Instruction {
+    build(
+        GAME_PROGRAM_ID,
+        accounts::SetSeasonEnd {
+            game_config: pda::game_config().0,
+            season: pda::season(season).0,
+            params_authority,
+        },
+        instruction::SetSeasonEnd { end_slot },
+    )
+}
+
+/// Appends the SeasonStanding PDA of every agent in `agents` as remaining accounts.
+pub fn end_season(season_id: u32, agents: &[Pubkey]) -> Instruction {
+    let mut ix = build(
+        GAME_PROGRAM_ID,
+        accounts::EndSeason {
+            season: pda::season(season_id).0,
+        },
+        instruction::EndSeason { season_id },
+    );
+    ix.accounts.extend(
+        agents
+            .iter()
+            .map(|agent| AccountMeta::new(pda::season_standing(season_id, agent).0, false)),
+    );
+    ix
+}
@@ -0,0 +1,44 @@
+//! Typed instruction builders, one function per program instruction.
+//!
+//! Builders derive every PDA themselves and take only the keys that cannot be
+//! derived (signers, token accounts, the mint and vault).  Gameplay builders
+//! take an `authority`: pass the agent itself, or its delegate — the
+//! Delegation PDA is then included automatically.
+
+pub mod faucet;
+pub mod game;
+pub mod token;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+
+use kill_game::state::GameConfig;
+
+/// The `GameConfig` keys most gameplay instructions need.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameKeys {
+    pub kill_mint: Pubkey,
+    pub game_vault: Pubkey,
+    /// Season the gameplay accounts are derived for
+    pub season: u32,
+}
+
+impl GameKeys {
+    /// Keys for live play, read from a fetched `GameConfig`.
+    pub fn from_config(config: &GameConfig) -> Self {
+        Self {
+            kill_mint: config.kill_mint,
+            game_vault: config.game_vault,
+            season: config.current_season,
+        }
+    }
+}
+
+pub(crate) fn build(program_id: Pubkey, accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
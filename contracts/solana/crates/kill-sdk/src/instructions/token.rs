@@ -0,0 +1,76 @@
+//! `kill_token` instruction builders.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_spl::token;
+
+use kill_token::{accounts, instruction};
+
+use super::build;
+use crate::{pda, KILL_TOKEN_PROGRAM_ID};
+
+/// `kill_mint` is a fresh keypair that must also sign.
+pub fn initialize_token(admin: Pubkey, kill_mint: Pubkey) -> Instruction {
+    build(
+        KILL_TOKEN_PROGRAM_ID,
+        accounts::InitializeToken {
+            token_config: pda::token_config().0,
+            kill_mint,
+            admin,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeToken {},
+    )
+}
+
+pub fn mint_to(admin: Pubkey, kill_mint: Pubkey, destination: Pubkey, amount: u64) -> Instruction {
+    build(
+        KILL_TOKEN_PROGRAM_ID,
+        accounts::MintTokens {
+            token_config: pda::token_config().0,
+            kill_mint,
+            destination,
+            admin,
+            token_program: token::ID,
+        },
+        instruction::MintTo { amount },
+    )
+}
+
+pub fn propose_admin(admin: Pubkey, new_admin: Pubkey) -> Instruction {
+    build(
+        KILL_TOKEN_PROGRAM_ID,
+        accounts::ProposeAdmin {
+            token_config: pda::token_config().0,
+            admin,
+        },
+        instruction::ProposeAdmin { new_admin },
+    )
+}
+
+pub fn accept_admin(new_admin: Pubkey) -> Instruction {
+    build(
+        KILL_TOKEN_PROGRAM_ID,
+        accounts::AcceptAdmin {
+            token_config: pda::token_config().0,
+            new_admin,
+        },
+        instruction::AcceptAdmin {},
+    )
+}
+
+pub fn migrate_config(admin: Pubkey) -> Instruction {
+    build(
+        KILL_TOKEN_PROGRAM_ID,
+        accounts::MigrateConfig {
+            token_config: pda::token_config().0,
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateConfig {},
+    )
+}
@@ -0,0 +1,30 @@
+//! Rust client SDK for the KILL programs (`kill_game`, `kill_token`,
+//! `kill_faucet`).
+//!
+//! - [`pda`] — every program-derived address the programs use.
+//! - [`instructions`] — typed builders returning ready-to-sign `Instruction`s.
+//! - [`accounts`] — deserializers for on-chain account data.
+//! - [`events`] — decoding of Anchor events from transaction logs.
+//!
+//! The program crates are re-exported so callers share their types
+//! (`GameConfig`, `KillGuards`, `SpawnEntry`, …) without a separate dependency.
+
+pub mod accounts;
+pub mod events;
+pub mod instructions;
+pub mod pda;
+
+pub use anchor_lang::prelude::Pubkey;
+pub use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+pub use kill_faucet;
+pub use kill_game;
+pub use kill_token;
+
+/// `kill_game` program ID
+pub const GAME_PROGRAM_ID: Pubkey = kill_game::ID;
+
+/// `kill_token` program ID (not the SPL Token program)
+pub const KILL_TOKEN_PROGRAM_ID: Pubkey = kill_token::ID;
+
+/// `kill_faucet` program ID
+pub const FAUCET_PROGRAM_ID: Pubkey = kill_faucet::ID;
@@ -0,0 +1,110 @@
+//! Program-derived addresses.  Each helper returns `(address, bump)` and
+//! mirrors the `seeds = [...]` constraint of the account it derives.
+//!
+//! Gameplay accounts are scoped to a season — pass `GameConfig.current_season`
+//! for live play.
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::{FAUCET_PROGRAM_ID, GAME_PROGRAM_ID, KILL_TOKEN_PROGRAM_ID};
+
+fn game(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &GAME_PROGRAM_ID)
+}
+
+// ── kill_game ─────────────────────────────────────────────────────────────────
+
+/// Seeds: [b"game_config"]
+pub fn game_config() -> (Pubkey, u8) {
+    game(&[b"game_config"])
+}
+
+/// Seeds: [b"game_params"]
+pub fn game_params() -> (Pubkey, u8) {
+    game(&[b"game_params"])
+}
+
+/// Seeds: [b"leaderboard"]
+pub fn leaderboard() -> (Pubkey, u8) {
+    game(&[b"leaderboard"])
+}
+
+/// Seeds: [b"airdrop"]
+pub fn airdrop() -> (Pubkey, u8) {
+    game(&[b"airdrop"])
+}
+
+/// Seeds: [b"airdrop_claim", claimer]
+pub fn airdrop_claim(claimer: &Pubkey) -> (Pubkey, u8) {
+    game(&[b"airdrop_claim", claimer.as_ref()])
+}
+
+/// Seeds: [b"season", season as [u8;4] LE]
+pub fn season(season: u32) -> (Pubkey, u8) {
+    game(&[b"season", &season.to_le_bytes()])
+}
+
+/// Seeds: [b"agent_stack", season LE, agent, stack_id as [u8;2] LE]
+pub fn agent_stack(season: u32, agent: &Pubkey, stack_id: u16) -> (Pubkey, u8) {
+    game(&[
+        b"agent_stack",
+        &season.to_le_bytes(),
+        agent.as_ref(),
+        &stack_id.to_le_bytes(),
+    ])
+}
+
+/// Pre-season stack, moved into a season by `migrate_stack`.
+/// Seeds: [b"agent_stack", agent, stack_id as [u8;2] LE]
+pub fn legacy_agent_stack(agent: &Pubkey, stack_id: u16) -> (Pubkey, u8) {
+    game(&[b"agent_stack", agent.as_ref(), &stack_id.to_le_bytes()])
+}
+
+/// Seeds: [b"cell_index", season LE, stack_id LE]
+pub fn cell_index(season: u32, stack_id: u16) -> (Pubkey, u8) {
+    game(&[b"cell_index", &season.to_le_bytes(), &stack_id.to_le_bytes()])
+}
+
+/// Seeds: [b"agent_profile", agent]
+pub fn agent_profile(agent: &Pubkey) -> (Pubkey, u8) {
+    game(&[b"agent_profile", agent.as_ref()])
+}
+
+/// Seeds: [b"season_standing", season LE, agent]
+pub fn season_standing(season: u32, agent: &Pubkey) -> (Pubkey, u8) {
+    game(&[b"season_standing", &season.to_le_bytes(), agent.as_ref()])
+}
+
+/// Seeds: [b"kill_commit", attacker_stack]
+pub fn kill_commitment(attacker_stack: &Pubkey) -> (Pubkey, u8) {
+    game(&[b"kill_commit", attacker_stack.as_ref()])
+}
+
+/// Seeds: [b"delegation", owner]
+pub fn delegation(owner: &Pubkey) -> (Pubkey, u8) {
+    game(&[b"delegation", owner.as_ref()])
+}
+
+/// Seeds: [b"withdrawal", id as [u8;8] LE]
+pub fn withdrawal(id: u64) -> (Pubkey, u8) {
+    game(&[b"withdrawal", &id.to_le_bytes()])
+}
+
+// ── kill_token ────────────────────────────────────────────────────────────────
+
+/// Seeds: [b"token_config"] under `kill_token`
+pub fn token_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"token_config"], &KILL_TOKEN_PROGRAM_ID)
+}
+
+// ── kill_faucet ───────────────────────────────────────────────────────────────
+
+/// Seeds: [b"faucet_config"] under `kill_faucet`
+pub fn faucet_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"faucet_config"], &FAUCET_PROGRAM_ID)
+}
+
+/// Seeds: [b"claim_record", claimer] under `kill_faucet`
+pub fn claim_record(claimer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"claim_record", claimer.as_ref()], &FAUCET_PROGRAM_ID)
+}
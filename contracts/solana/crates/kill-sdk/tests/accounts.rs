@@ -0,0 +1,38 @@
+use anchor_lang::AccountSerialize;
+use kill_sdk::accounts;
+use kill_sdk::kill_game::state::AgentStack;
+use kill_sdk::Pubkey;
+
+fn stack(reaper_progress: u64) -> AgentStack {
+    AgentStack {
+        agent: Pubkey::new_unique(),
+        stack_id: 42,
+        units: 1_330,
+        reapers: 1,
+        spawn_slot: 100,
+        kill_slot: 0,
+        bump: 254,
+        season: 1,
+        reaper_progress,
+        locked_units: 0,
+        locked_reapers: 0,
+    }
+}
+
+#[test]
+fn agent_stack_decodes_current_and_legacy_layouts() {
+    let current = stack(664);
+    let mut data = Vec::new();
+    current.try_serialize(&mut data).unwrap();
+    assert_eq!(data.len(), AgentStack::SPACE);
+    let decoded = accounts::agent_stack(&data).unwrap();
+    assert_eq!((decoded.agent, decoded.units, decoded.reaper_progress), (current.agent, 1_330, 664));
+
+    // A stack created before seasons: the layout ends at `bump`
+    data.truncate(AgentStack::LEGACY_SPACE);
+    let legacy = accounts::agent_stack(&data).unwrap();
+    assert_eq!((legacy.agent, legacy.units, legacy.bump), (current.agent, 1_330, 254));
+    assert_eq!((legacy.season, legacy.reaper_progress), (0, 0));
+
+    assert!(accounts::agent_stack(&data[..AgentStack::LEGACY_SPACE - 1]).is_err());
+}
@@ -0,0 +1,91 @@
+use anchor_lang::{prelude::Pubkey, AnchorSerialize, Discriminator};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use kill_sdk::events::{decode_event, parse_logs, GameEvent};
+use kill_sdk::kill_game::state::{StackMoved, StackSpawned};
+use kill_sdk::GAME_PROGRAM_ID;
+
+fn data_line(event: &StackSpawned) -> String {
+    let mut bytes = StackSpawned::DISCRIMINATOR.to_vec();
+    event.serialize(&mut bytes).unwrap();
+    format!("Program data: {}", STANDARD.encode(bytes))
+}
+
+#[test]
+fn decodes_only_events_logged_by_kill_game() {
+    let agent = Pubkey::new_unique();
+    let spawned = StackSpawned {
+        agent,
+        stack_id: 7,
+        units: 666,
+        reapers: 1,
+        slot: 42,
+        deposited: 100,
+        burned: 5,
+        spawned_units: 666,
+        spawned_reapers: 1,
+    };
+    let other = Pubkey::new_unique();
+    let game = GAME_PROGRAM_ID.to_string();
+    let logs = [
+        format!("Program {other} invoke [1]"),
+        data_line(&spawned), // logged by another program — ignored
+        format!("Program {game} invoke [2]"),
+        "Program log: Instruction: Spawn".to_string(),
+        data_line(&spawned),
+        "Program data: not-base64!".to_string(),
+        format!("Program {game} consumed 1000 of 200000 compute units"),
+        format!("Program {game} success"),
+        format!("Program {other} success"),
+    ];
+
+    let events = parse_logs(logs.iter().map(String::as_str));
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        GameEvent::Spawned(e) => {
+            assert_eq!(e.agent, agent);
+            assert_eq!((e.stack_id, e.units, e.reapers, e.slot), (7, 666, 1, 42));
+        }
+        _ => panic!("expected StackSpawned"),
+    }
+}
+
+#[test]
+fn decodes_spawns_and_moves_in_older_layouts() {
+    let agent = Pubkey::new_unique();
+
+    // Pre-fee-split layouts end at `slot`
+    let mut spawned = StackSpawned::DISCRIMINATOR.to_vec();
+    (agent, 7u16, 666u64, 1u64, 42u64).serialize(&mut spawned).unwrap();
+    let mut moved = StackMoved::DISCRIMINATOR.to_vec();
+    (agent, 7u16, 8u16, 10u64, 0u64, 43u64).serialize(&mut moved).unwrap();
+
+    match decode_event(&spawned) {
+        Some(GameEvent::Spawned(e)) => {
+            assert_eq!(e.agent, agent);
+            assert_eq!((e.stack_id, e.units, e.reapers, e.slot), (7, 666, 1, 42));
+            assert_eq!((e.deposited, e.burned, e.spawned_units, e.spawned_reapers), (0, 0, 0, 0));
+        }
+        _ => panic!("expected StackSpawned"),
+    }
+    // …and those logged before spawned_units/spawned_reapers
+    let mut with_fees = spawned.clone();
+    (100u64, 5u64).serialize(&mut with_fees).unwrap();
+    match decode_event(&with_fees) {
+        Some(GameEvent::Spawned(e)) => {
+            assert_eq!((e.deposited, e.burned, e.spawned_units, e.spawned_reapers), (100, 5, 0, 0));
+        }
+        _ => panic!("expected StackSpawned"),
+    }
+    match decode_event(&moved) {
+        Some(GameEvent::Moved(e)) => {
+            assert_eq!((e.from_stack, e.to_stack, e.units, e.reapers, e.slot), (7, 8, 10, 0, 43));
+            assert_eq!((e.deposited, e.burned), (0, 0));
+        }
+        _ => panic!("expected StackMoved"),
+    }
+
+    // Any other truncation is still rejected
+    assert!(decode_event(&spawned[..spawned.len() - 1]).is_none());
+}
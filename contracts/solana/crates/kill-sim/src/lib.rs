@@ -0,0 +1,20 @@
+//! Monte Carlo simulator of the KILL economy.
+//!
+//! Combat and bounties are settled with the same `kill-math` functions the
+//! program uses (`power_decay_pct`, `resolve_combat`, `get_pending_bounty`,
+//! `split_bounty`); only the ledger is simplified.
+//!
+//! - [`world`] — the grid, the vault, and spawn / move / kill settlement.
+//! - [`strategy`] — the [`Strategy`] trait and the built-in agent profiles.
+//! - [`sim`] — the event loop that gives agents turns and samples the world.
+//! - [`report`] — vault sawtooth cycles, ROI percentiles and CSV output.
+
+pub mod report;
+pub mod rng;
+pub mod sim;
+pub mod strategy;
+pub mod world;
+
+pub use sim::{Config, Report, Simulation};
+pub use strategy::{Action, Strategy};
+pub use world::{Economy, World};
@@ -0,0 +1,190 @@
+//! `kill-sim` — run a seeded simulation and write CSVs to `--out`:
+//!
+//! - `timeseries.csv` — vault depth, burn and flow totals every `--sample-every` slots
+//! - `agents.csv` — per-agent spend, earnings and realised ROI
+//! - `roi.csv` — ROI percentiles per strategy (also printed)
+//! - `cycles.csv` — vault sawtooth cycles (trough → peak → trough)
+//!
+//! Amounts are raw KILL (6 decimals) in the CSVs; flags take whole KILL.
+
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use kill_sim::report::{self, cycles, roi_summary};
+use kill_sim::strategy::{builtin, BUILTINS};
+use kill_math::BPS_DENOM;
+use kill_sim::{Config, Economy, Simulation};
+
+const KILL: u64 = 1_000_000;
+
+#[derive(Parser)]
+#[command(name = "kill-sim", version, about = "Monte Carlo simulation of the KILL economy")]
+struct Args {
+    /// Agents per strategy, e.g. farmer=1600,fortress=200,hunter=150,sniper=50
+    #[arg(long, default_value = "farmer=1600,fortress=200,hunter=150,sniper=50")]
+    agents: String,
+
+    /// Starting balance per agent (KILL)
+    #[arg(long, default_value_t = 2_000_000)]
+    balance: u64,
+
+    /// Slots to simulate
+    #[arg(long, default_value_t = 5_000_000)]
+    slots: u64,
+
+    /// Mean slots between an agent's turns
+    #[arg(long, default_value_t = 9_000)]
+    turn_slots: u64,
+
+    /// Slots between time-series samples
+    #[arg(long, default_value_t = 9_000)]
+    sample_every: u64,
+
+    /// Vault balance at slot 0 (KILL)
+    #[arg(long, default_value_t = 0)]
+    vault_seed: u64,
+
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    /// Minimum swing, in bps, for a vault move to count as a cycle leg
+    #[arg(long, default_value_t = 1_000)]
+    cycle_threshold_bps: u64,
+
+    /// Output directory (created if missing)
+    #[arg(long, default_value = "sim-out")]
+    out: PathBuf,
+
+    /// KILL per unit spawned
+    #[arg(long, default_value_t = 20)]
+    spawn_cost: u64,
+
+    /// KILL per move
+    #[arg(long, default_value_t = 100)]
+    move_cost: u64,
+
+    #[arg(long, default_value_t = 50)]
+    max_multiplier: u64,
+
+    #[arg(long, default_value_t = 13_224)]
+    slots_per_multiplier: u64,
+
+    /// Per-kill bounty cap, bps of the vault
+    #[arg(long, default_value_t = 2_500)]
+    global_cap_bps: u64,
+
+    #[arg(long, default_value_t = 666)]
+    burn_bps: u64,
+
+    #[arg(long, default_value_t = 666)]
+    reaper_threshold: u64,
+
+    /// Blend spawn_slot by power on reinforcement and merge
+    #[arg(long)]
+    blend_spawn_slot: bool,
+}
+
+fn parse_mix(spec: &str) -> Result<Vec<(String, usize)>> {
+    spec.split(',')
+        .filter(|s| !s.is_empty())
+        .map(|entry| {
+            let (name, count) = entry.split_once('=').context(format!("expected strategy=count, got {entry:?}"))?;
+            if !BUILTINS.contains(&name) {
+                bail!("unknown strategy {name:?} (expected one of {})", BUILTINS.join(", "));
+            }
+            Ok((name.to_owned(), count.parse().context(format!("bad count for {name}"))?))
+        })
+        .collect()
+}
+
+/// Whole KILL from `--{flag}` in raw units.
+fn raw_kill(amount: u64, flag: &str) -> Result<u64> {
+    match amount.checked_mul(KILL) {
+        Some(raw) => Ok(raw),
+        None => bail!("--{flag} {amount} overflows u64 once scaled to raw KILL"),
+    }
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let mix = parse_mix(&args.agents)?;
+    // Same bounds as the program's set_config
+    if args.burn_bps > BPS_DENOM {
+        bail!("--burn-bps must be at most {BPS_DENOM}, got {}", args.burn_bps);
+    }
+    if args.global_cap_bps == 0 || args.global_cap_bps > BPS_DENOM {
+        bail!("--global-cap-bps must be in 1..={BPS_DENOM}, got {}", args.global_cap_bps);
+    }
+    let balance = raw_kill(args.balance, "balance")?;
+
+    let mut economy = Economy::default();
+    economy.math.spawn_cost = raw_kill(args.spawn_cost, "spawn-cost")?;
+    economy.math.max_multiplier = args.max_multiplier;
+    economy.math.slots_per_multiplier = args.slots_per_multiplier;
+    economy.math.global_cap_bps = args.global_cap_bps;
+    economy.math.burn_bps = args.burn_bps;
+    economy.move_cost = raw_kill(args.move_cost, "move-cost")?;
+    economy.reaper_threshold = args.reaper_threshold;
+    economy.blend_spawn_slot = args.blend_spawn_slot;
+
+    let mut sim = Simulation::new(Config {
+        economy,
+        slots: args.slots,
+        turn_slots: args.turn_slots,
+        sample_every: args.sample_every,
+        vault_seed: raw_kill(args.vault_seed, "vault-seed")?,
+        seed: args.seed,
+    });
+    for (name, count) in &mix {
+        for _ in 0..*count {
+            let strategy = builtin(name, sim.rng()).expect("validated by parse_mix");
+            sim.add_agent(strategy, balance);
+        }
+    }
+
+    let report = sim.run();
+    let cycles = cycles(&report.samples, args.cycle_threshold_bps);
+    let roi = roi_summary(&report.agents);
+
+    fs::create_dir_all(&args.out).with_context(|| format!("creating {}", args.out.display()))?;
+    let create = |name: &str| -> Result<BufWriter<File>> {
+        let path = args.out.join(name);
+        Ok(BufWriter::new(File::create(&path).with_context(|| format!("creating {}", path.display()))?))
+    };
+    report::write_timeseries(create("timeseries.csv")?, &report)?;
+    report::write_agents(create("agents.csv")?, &report)?;
+    report::write_roi(create("roi.csv")?, &roi)?;
+    report::write_cycles(create("cycles.csv")?, &cycles)?;
+
+    if let Some(last) = report.samples.last() {
+        println!(
+            "slot {}: vault {} KILL, burned {} KILL, {} spawns, {} attacks ({} won), {} rejected",
+            last.slot,
+            last.vault / KILL,
+            last.burned / KILL,
+            last.spawns,
+            last.attacks,
+            last.kills_won,
+            report.rejected
+        );
+    }
+    println!("{} vault cycles (≥ {} bps swings)", cycles.len(), args.cycle_threshold_bps);
+    println!("{:<10} {:>6} {:>6} {:>9} {:>9} {:>9} {:>9}", "strategy", "agents", "active", "roi p10", "p50", "p90", "mean");
+    for r in &roi {
+        println!(
+            "{:<10} {:>6} {:>6} {:>8.1}% {:>8.1}% {:>8.1}% {:>8.1}%",
+            r.strategy,
+            r.agents,
+            r.active,
+            r.p10 * 100.0,
+            r.p50 * 100.0,
+            r.p90 * 100.0,
+            r.mean * 100.0
+        );
+    }
+    println!("wrote {}", args.out.display());
+    Ok(())
+}
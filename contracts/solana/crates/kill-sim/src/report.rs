@@ -0,0 +1,258 @@
+//! Post-processing: sawtooth cycles in vault depth, ROI percentiles per
+//! strategy, and the CSV files `kill-sim` writes.
+
+use std::io::{self, Write};
+
+use crate::sim::{AgentResult, Report, Sample};
+
+/// One accumulate → liquidate swing of the vault: trough, peak, trough.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start_slot: u64,
+    pub start_vault: u64,
+    pub peak_slot: u64,
+    pub peak_vault: u64,
+    pub end_slot: u64,
+    pub end_vault: u64,
+}
+
+impl Cycle {
+    pub fn accumulation_slots(&self) -> u64 {
+        self.peak_slot - self.start_slot
+    }
+
+    pub fn distribution_slots(&self) -> u64 {
+        self.end_slot - self.peak_slot
+    }
+
+    /// Peak-to-trough fall, in basis points of the peak
+    pub fn drawdown_bps(&self) -> u64 {
+        kill_math::mul_div(self.peak_vault - self.end_vault, kill_math::BPS_DENOM, self.peak_vault)
+    }
+}
+
+/// Zigzag over `(slot, value)` points: a swing counts once the value moves
+/// `threshold_bps` away from the last extreme.  Returns alternating pivots,
+/// `true` for peaks; the final extreme is not one until a reversal follows.
+pub fn pivots(points: &[(u64, u64)], threshold_bps: u64) -> Vec<(u64, u64, bool)> {
+    let t = threshold_bps as f64 / kill_math::BPS_DENOM as f64;
+    let rose = |from: u64, to: u64| to > from && to as f64 >= from as f64 * (1.0 + t);
+    let fell = |from: u64, to: u64| to < from && to as f64 <= from as f64 * (1.0 - t);
+
+    let mut out = Vec::new();
+    let Some(&first) = points.first() else {
+        return out;
+    };
+    // `rising` is None until the first swing picks a direction.
+    let mut rising: Option<bool> = None;
+    let (mut low, mut high) = (first, first);
+    for &p in &points[1..] {
+        match rising {
+            None => {
+                if rose(low.1, p.1) {
+                    out.push((low.0, low.1, false));
+                    rising = Some(true);
+                    high = p;
+                } else if fell(high.1, p.1) {
+                    out.push((high.0, high.1, true));
+                    rising = Some(false);
+                    low = p;
+                } else {
+                    if p.1 < low.1 {
+                        low = p;
+                    }
+                    if p.1 > high.1 {
+                        high = p;
+                    }
+                }
+            }
+            Some(true) => {
+                if p.1 > high.1 {
+                    high = p;
+                } else if fell(high.1, p.1) {
+                    out.push((high.0, high.1, true));
+                    rising = Some(false);
+                    low = p;
+                }
+            }
+            Some(false) => {
+                if p.1 < low.1 {
+                    low = p;
+                } else if rose(low.1, p.1) {
+                    out.push((low.0, low.1, false));
+                    rising = Some(true);
+                    high = p;
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Completed vault-depth sawtooth cycles.
+pub fn cycles(samples: &[Sample], threshold_bps: u64) -> Vec<Cycle> {
+    let points: Vec<(u64, u64)> = samples.iter().map(|s| (s.slot, s.vault)).collect();
+    pivots(&points, threshold_bps)
+        .windows(3)
+        .filter(|w| !w[0].2 && w[1].2)
+        .map(|w| Cycle {
+            start_slot: w[0].0,
+            start_vault: w[0].1,
+            peak_slot: w[1].0,
+            peak_vault: w[1].1,
+            end_slot: w[2].0,
+            end_vault: w[2].1,
+        })
+        .collect()
+}
+
+/// Realised-ROI distribution of one strategy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoiSummary {
+    pub strategy: String,
+    pub agents: usize,
+    /// Agents that spent something (the ones with an ROI)
+    pub active: usize,
+    pub spent: u64,
+    pub earned: u64,
+    pub mean: f64,
+    pub p10: f64,
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p90: f64,
+}
+
+/// Nearest-rank percentile of sorted `values`.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// One summary per strategy, in order of first appearance.
+pub fn roi_summary(agents: &[AgentResult]) -> Vec<RoiSummary> {
+    let mut names: Vec<&str> = Vec::new();
+    for a in agents {
+        if !names.contains(&a.strategy.as_str()) {
+            names.push(&a.strategy);
+        }
+    }
+    names
+        .into_iter()
+        .map(|name| {
+            let group: Vec<&AgentResult> = agents.iter().filter(|a| a.strategy == name).collect();
+            let mut rois: Vec<f64> = group.iter().filter_map(|a| a.roi()).collect();
+            rois.sort_by(f64::total_cmp);
+            let mean = if rois.is_empty() { 0.0 } else { rois.iter().sum::<f64>() / rois.len() as f64 };
+            RoiSummary {
+                strategy: name.to_owned(),
+                agents: group.len(),
+                active: rois.len(),
+                spent: group.iter().map(|a| a.ledger.spent).sum(),
+                earned: group.iter().map(|a| a.ledger.earned).sum(),
+                mean,
+                p10: percentile(&rois, 10.0),
+                p25: percentile(&rois, 25.0),
+                p50: percentile(&rois, 50.0),
+                p75: percentile(&rois, 75.0),
+                p90: percentile(&rois, 90.0),
+            }
+        })
+        .collect()
+}
+
+pub fn write_timeseries(mut w: impl Write, report: &Report) -> io::Result<()> {
+    writeln!(
+        w,
+        "slot,vault,burned,added,extracted,spawns,moves,attacks,kills_won,stacks,grid_power,liability,max_bounty"
+    )?;
+    for s in &report.samples {
+        writeln!(
+            w,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            s.slot,
+            s.vault,
+            s.burned,
+            s.added,
+            s.extracted,
+            s.spawns,
+            s.moves,
+            s.attacks,
+            s.kills_won,
+            s.stacks,
+            s.grid_power,
+            s.liability,
+            s.max_bounty
+        )?;
+    }
+    Ok(())
+}
+
+pub fn write_agents(mut w: impl Write, report: &Report) -> io::Result<()> {
+    writeln!(
+        w,
+        "agent,strategy,initial_balance,balance,spent,earned,net,roi,spawns,moves,attacks,kills_won,defenses,defenses_lost,grid_power"
+    )?;
+    for a in &report.agents {
+        let l = &a.ledger;
+        writeln!(
+            w,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            a.agent,
+            a.strategy,
+            a.initial_balance,
+            l.balance,
+            l.spent,
+            l.earned,
+            l.earned as i128 - l.spent as i128,
+            a.roi().map(|r| format!("{r:.6}")).unwrap_or_default(),
+            l.spawns,
+            l.moves,
+            l.attacks,
+            l.kills_won,
+            l.defenses,
+            l.defenses_lost,
+            a.grid_power
+        )?;
+    }
+    Ok(())
+}
+
+pub fn write_cycles(mut w: impl Write, cycles: &[Cycle]) -> io::Result<()> {
+    writeln!(
+        w,
+        "cycle,start_slot,start_vault,peak_slot,peak_vault,end_slot,end_vault,accumulation_slots,distribution_slots,drawdown_bps"
+    )?;
+    for (i, c) in cycles.iter().enumerate() {
+        writeln!(
+            w,
+            "{},{},{},{},{},{},{},{},{},{}",
+            i,
+            c.start_slot,
+            c.start_vault,
+            c.peak_slot,
+            c.peak_vault,
+            c.end_slot,
+            c.end_vault,
+            c.accumulation_slots(),
+            c.distribution_slots(),
+            c.drawdown_bps()
+        )?;
+    }
+    Ok(())
+}
+
+pub fn write_roi(mut w: impl Write, summary: &[RoiSummary]) -> io::Result<()> {
+    writeln!(w, "strategy,agents,active,spent,earned,mean,p10,p25,p50,p75,p90")?;
+    for r in summary {
+        writeln!(
+            w,
+            "{},{},{},{},{},{:.6},{:.6},{:.6},{:.6},{:.6},{:.6}",
+            r.strategy, r.agents, r.active, r.spent, r.earned, r.mean, r.p10, r.p25, r.p50, r.p75, r.p90
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,204 @@
+//! The event loop: agents take turns at exponentially distributed
+//! intervals, and the world is sampled at the end of every
+//! `sample_every`-th slot and of the last one.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::rng::Rng;
+use crate::strategy::{Action, Strategy};
+use crate::world::{AgentId, Economy, Ledger, World};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    pub economy: Economy,
+    /// Slots to simulate
+    pub slots: u64,
+    /// Mean slots between an agent's turns
+    pub turn_slots: u64,
+    /// Slots between time-series samples
+    pub sample_every: u64,
+    /// KILL in the vault at slot 0
+    pub vault_seed: u64,
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            economy: Economy::default(),
+            slots: 5_000_000,
+            turn_slots: 9_000,
+            sample_every: 9_000,
+            vault_seed: 0,
+            seed: 1,
+        }
+    }
+}
+
+/// World state after one sampled slot.  Counters are cumulative.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Sample {
+    pub slot: u64,
+    pub vault: u64,
+    pub burned: u64,
+    pub added: u64,
+    pub extracted: u64,
+    pub spawns: u64,
+    pub moves: u64,
+    pub attacks: u64,
+    pub kills_won: u64,
+    pub stacks: u64,
+    /// Σ raw power on the grid
+    pub grid_power: u64,
+    /// Σ pending bounty over all stacks (each capped, so can exceed the vault)
+    pub liability: u64,
+    pub max_bounty: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AgentResult {
+    pub agent: AgentId,
+    pub strategy: String,
+    pub initial_balance: u64,
+    pub ledger: Ledger,
+    /// Raw power still on the grid at the end
+    pub grid_power: u64,
+}
+
+impl AgentResult {
+    /// Realised return on spend, `(earned − spent) / spent`; `None` if the
+    /// agent never spent anything.
+    pub fn roi(&self) -> Option<f64> {
+        (self.ledger.spent > 0).then(|| (self.ledger.earned as f64 - self.ledger.spent as f64) / self.ledger.spent as f64)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub samples: Vec<Sample>,
+    pub agents: Vec<AgentResult>,
+    /// Actions the program would have rejected
+    pub rejected: u64,
+}
+
+pub struct Simulation {
+    pub config: Config,
+    pub world: World,
+    strategies: Vec<Box<dyn Strategy>>,
+    initial: Vec<u64>,
+    rng: Rng,
+}
+
+impl Simulation {
+    pub fn new(config: Config) -> Self {
+        Self {
+            world: World::new(config.economy, config.vault_seed),
+            strategies: Vec::new(),
+            initial: Vec::new(),
+            rng: Rng::new(config.seed),
+            config,
+        }
+    }
+
+    /// Generator for building strategies, seeded from the run seed.
+    pub fn rng(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+
+    pub fn add_agent(&mut self, strategy: Box<dyn Strategy>, balance: u64) -> AgentId {
+        self.strategies.push(strategy);
+        self.initial.push(balance);
+        self.world.add_agent(balance)
+    }
+
+    pub fn run(mut self) -> Report {
+        let Config { slots, turn_slots, sample_every, .. } = self.config;
+        let sample_every = sample_every.max(1);
+        let mut turns: BinaryHeap<Reverse<(u64, AgentId)>> =
+            (0..self.strategies.len()).map(|a| Reverse((self.rng.wait(turn_slots), a))).collect();
+        let mut samples = Vec::new();
+        let mut next_sample = 0;
+        let mut rejected = 0;
+
+        while let Some(Reverse((slot, agent))) = turns.pop() {
+            if slot > slots {
+                break;
+            }
+            while next_sample < slot {
+                self.world.slot = next_sample;
+                samples.push(self.sample());
+                next_sample += sample_every;
+            }
+            self.world.slot = slot;
+            for action in self.strategies[agent].act(agent, &self.world, &mut self.rng) {
+                if self.apply(agent, action).is_err() {
+                    rejected += 1;
+                }
+            }
+            turns.push(Reverse((slot + self.rng.wait(turn_slots), agent)));
+        }
+        while next_sample <= slots {
+            self.world.slot = next_sample;
+            samples.push(self.sample());
+            next_sample += sample_every;
+        }
+        if samples.last().is_some_and(|s| s.slot < slots) {
+            self.world.slot = slots;
+            samples.push(self.sample());
+        }
+
+        let mut power = vec![0u64; self.strategies.len()];
+        for (_, o) in self.world.occupants() {
+            power[o.agent] += kill_math::power(o.stack.units, o.stack.reapers);
+        }
+        let agents = self
+            .strategies
+            .iter()
+            .enumerate()
+            .map(|(agent, s)| AgentResult {
+                agent,
+                strategy: s.name().to_owned(),
+                initial_balance: self.initial[agent],
+                ledger: self.world.ledgers[agent],
+                grid_power: power[agent],
+            })
+            .collect();
+        Report { samples, agents, rejected }
+    }
+
+    fn apply(&mut self, agent: AgentId, action: Action) -> Result<(), crate::world::Rejected> {
+        match action {
+            Action::Spawn { stack_id, units } => self.world.spawn(agent, stack_id, units),
+            Action::Move { from, to, units, reapers } => self.world.move_units(agent, from, to, units, reapers),
+            Action::Kill { stack_id, target, units, reapers } => {
+                self.world.kill(agent, stack_id, target, units, reapers).map(|_| ())
+            }
+        }
+    }
+
+    fn sample(&self) -> Sample {
+        let w = &self.world;
+        let t = w.totals;
+        let mut s = Sample {
+            slot: w.slot,
+            vault: w.vault,
+            burned: t.burned,
+            added: t.added,
+            extracted: t.extracted,
+            spawns: t.spawns,
+            moves: t.moves,
+            attacks: t.attacks,
+            kills_won: t.kills_won,
+            ..Default::default()
+        };
+        for (_, o) in w.occupants() {
+            let bounty = w.pending_bounty(&o.stack);
+            s.stacks += 1;
+            s.grid_power = s.grid_power.saturating_add(kill_math::power(o.stack.units, o.stack.reapers));
+            s.liability = s.liability.saturating_add(bounty);
+            s.max_bounty = s.max_bounty.max(bounty);
+        }
+        s
+    }
+}
@@ -0,0 +1,242 @@
+//! Agent strategies.
+//!
+//! A strategy sees the whole [`World`] when its agent gets a turn and
+//! returns the instructions to send, in order — a sniper's spawn + kill is
+//! two actions in one turn.  Actions the program would reject are skipped
+//! and counted, so a strategy may be optimistic.
+//!
+//! The built-ins follow the profiles in the README:
+//! - [`Farmer`] — prey: spawns small stacks at random and lets them age.
+//! - [`Fortress`] — reinforces one hub and purges anyone who lands on it.
+//! - [`Sniper`] — spawns overwhelming force onto matured stacks, liquidates
+//!   them when the payout beats the spawn cost, and walks what is left
+//!   back to its hub.
+//! - [`Hunter`] — roams with one stack, attacking when it wins for profit.
+
+use kill_math::{strike, Stack, BPS_DENOM};
+
+use crate::rng::Rng;
+use crate::world::{neighbors, step_toward, AgentId, World, CELLS};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Spawn { stack_id: u16, units: u64 },
+    Move { from: u16, to: u16, units: u64, reapers: u64 },
+    Kill { stack_id: u16, target: AgentId, units: u64, reapers: u64 },
+}
+
+pub trait Strategy {
+    fn name(&self) -> &str;
+
+    /// Instructions for `me` this turn (empty to stay dark).
+    fn act(&mut self, me: AgentId, world: &World, rng: &mut Rng) -> Vec<Action>;
+}
+
+/// Names accepted by [`builtin`].
+pub const BUILTINS: &[&str] = &["farmer", "fortress", "sniper", "hunter"];
+
+/// A built-in strategy with its default settings.
+pub fn builtin(name: &str, rng: &mut Rng) -> Option<Box<dyn Strategy>> {
+    let hub = rng.below(CELLS as u64) as u16;
+    Some(match name {
+        "farmer" => Box::new(Farmer::default()),
+        "fortress" => Box::new(Fortress { hub, ..Default::default() }),
+        "sniper" => Box::new(Sniper { hub, ..Default::default() }),
+        "hunter" => Box::new(Hunter::default()),
+        _ => return None,
+    })
+}
+
+/// Units needed for a fresh stack to beat `defender` with `margin_bps` to
+/// spare over the 10% defender bonus (reapers granted by the spawn ignored).
+fn units_to_beat(world: &World, defender: &Stack, margin_bps: u64) -> u64 {
+    let needed = world.effective_power(defender) * 11 * margin_bps as u128 / (10 * 100 * BPS_DENOM as u128);
+    u64::try_from(needed + 1).unwrap_or(u64::MAX)
+}
+
+/// Spawns `min_units..=max_units` on a random cell with probability
+/// `spawn_chance` per turn, and never attacks.
+#[derive(Clone, Debug)]
+pub struct Farmer {
+    pub spawn_chance: f64,
+    pub min_units: u64,
+    pub max_units: u64,
+}
+
+impl Default for Farmer {
+    fn default() -> Self {
+        Self { spawn_chance: 0.25, min_units: 50, max_units: 700 }
+    }
+}
+
+impl Strategy for Farmer {
+    fn name(&self) -> &str {
+        "farmer"
+    }
+
+    fn act(&mut self, _me: AgentId, _world: &World, rng: &mut Rng) -> Vec<Action> {
+        if !rng.chance(self.spawn_chance) {
+            return Vec::new();
+        }
+        let units = rng.range(self.min_units, self.max_units);
+        vec![Action::Spawn { stack_id: rng.below(CELLS as u64) as u16, units }]
+    }
+}
+
+/// Holds `hub`: purges any rival stack on it it can beat with all its
+/// forces, otherwise reinforces with `reinforce_units`.
+#[derive(Clone, Debug)]
+pub struct Fortress {
+    pub hub: u16,
+    pub reinforce_chance: f64,
+    pub reinforce_units: u64,
+}
+
+impl Default for Fortress {
+    fn default() -> Self {
+        Self { hub: 0, reinforce_chance: 0.5, reinforce_units: 1_332 }
+    }
+}
+
+impl Strategy for Fortress {
+    fn name(&self) -> &str {
+        "fortress"
+    }
+
+    fn act(&mut self, me: AgentId, world: &World, rng: &mut Rng) -> Vec<Action> {
+        if let Some(own) = world.stack(me, self.hub) {
+            let rival = world.cell(self.hub).iter().filter(|o| o.agent != me).find(|o| {
+                strike(&world.economy.math, own, &o.stack, own.units, own.reapers, world.vault, world.slot)
+                    .combat
+                    .attacker_won
+            });
+            if let Some(rival) = rival {
+                return vec![Action::Kill {
+                    stack_id: self.hub,
+                    target: rival.agent,
+                    units: own.units,
+                    reapers: own.reapers,
+                }];
+            }
+        }
+        if rng.chance(self.reinforce_chance) {
+            return vec![Action::Spawn { stack_id: self.hub, units: self.reinforce_units }];
+        }
+        Vec::new()
+    }
+}
+
+/// Scans the grid for the most profitable liquidation: spawn
+/// `margin_bps` × the force needed on a cell where it holds nothing, and
+/// kill with all of it, if the attacker payout is at least
+/// `min_return_bps` of the spawn cost.  With nothing worth hitting it
+/// moves one leftover stack a step toward `hub`.
+#[derive(Clone, Debug)]
+pub struct Sniper {
+    pub hub: u16,
+    pub margin_bps: u64,
+    pub min_return_bps: u64,
+}
+
+impl Default for Sniper {
+    fn default() -> Self {
+        Self { hub: 0, margin_bps: 20_000, min_return_bps: 15_000 }
+    }
+}
+
+impl Strategy for Sniper {
+    fn name(&self) -> &str {
+        "sniper"
+    }
+
+    fn act(&mut self, me: AgentId, world: &World, _rng: &mut Rng) -> Vec<Action> {
+        let params = &world.economy.math;
+        let mut held = [false; CELLS as usize];
+        for (id, _) in world.stacks_of(me) {
+            held[id as usize] = true;
+        }
+        let mut best: Option<(i128, u16, AgentId, u64)> = None;
+        for (id, target) in world.occupants() {
+            if target.agent == me || held[id as usize] {
+                continue;
+            }
+            let units = units_to_beat(world, &target.stack, self.margin_bps);
+            let cost = world.spawn_cost(units);
+            // The full pending bounty bounds the payout; skip the exact
+            // settlement for targets that cannot clear the bar.
+            let ceiling = world.pending_bounty(&target.stack) as u128 * BPS_DENOM as u128;
+            if cost > world.balance(me) || ceiling < cost as u128 * self.min_return_bps as u128 {
+                continue;
+            }
+            let attacker = Stack { units, reapers: world.reapers_for(units), spawn_slot: world.slot };
+            let vault = world.vault + cost - kill_math::fee_burn(cost, params.burn_bps);
+            let outcome = strike(params, &attacker, &target.stack, units, attacker.reapers, vault, world.slot);
+            let payout = outcome.bounty.attacker_payout as u128;
+            if !outcome.combat.attacker_won || payout * (BPS_DENOM as u128) < cost as u128 * self.min_return_bps as u128 {
+                continue;
+            }
+            let profit = payout as i128 - cost as i128;
+            if best.is_none_or(|(p, ..)| profit > p) {
+                best = Some((profit, id, target.agent, units));
+            }
+        }
+        let Some((_, stack_id, target, units)) = best else {
+            let stray = world.stacks_of(me).find(|(id, _)| *id != self.hub);
+            return stray
+                .map(|(from, s)| Action::Move {
+                    from,
+                    to: step_toward(from, self.hub),
+                    units: s.units,
+                    reapers: s.reapers,
+                })
+                .into_iter()
+                .collect();
+        };
+        vec![
+            Action::Spawn { stack_id, units },
+            Action::Kill { stack_id, target, units, reapers: world.reapers_for(units) },
+        ]
+    }
+}
+
+/// Keeps one stack of `stack_units` roaming: attacks the richest rival on
+/// its cell it beats with a payout of at least `min_payout`, otherwise
+/// moves everything to a random adjacent cell.
+#[derive(Clone, Debug)]
+pub struct Hunter {
+    pub stack_units: u64,
+    pub min_payout: u64,
+}
+
+impl Default for Hunter {
+    fn default() -> Self {
+        Self { stack_units: 6_660, min_payout: 1_000_000_000 }
+    }
+}
+
+impl Strategy for Hunter {
+    fn name(&self) -> &str {
+        "hunter"
+    }
+
+    fn act(&mut self, me: AgentId, world: &World, rng: &mut Rng) -> Vec<Action> {
+        let Some((at, own)) = world.stacks_of(me).next() else {
+            let stack_id = rng.below(CELLS as u64) as u16;
+            return vec![Action::Spawn { stack_id, units: self.stack_units }];
+        };
+        let params = &world.economy.math;
+        let target = world
+            .cell(at)
+            .iter()
+            .filter(|o| o.agent != me)
+            .map(|o| (o, strike(params, own, &o.stack, own.units, own.reapers, world.vault, world.slot)))
+            .filter(|(_, s)| s.combat.attacker_won && s.bounty.attacker_payout >= self.min_payout)
+            .max_by_key(|(_, s)| s.bounty.attacker_payout);
+        if let Some((target, _)) = target {
+            return vec![Action::Kill { stack_id: at, target: target.agent, units: own.units, reapers: own.reapers }];
+        }
+        let options: Vec<u16> = neighbors(at).collect();
+        let to = options[rng.below(options.len() as u64) as usize];
+        vec![Action::Move { from: at, to, units: own.units, reapers: own.reapers }]
+    }
+}
@@ -0,0 +1,395 @@
+//! The simulated grid and vault.
+//!
+//! `World` settles spawn, move and kill the way the `kill_game` program
+//! does — same costs, burns, adjacency rule, `spawn_slot` handling and
+//! kill-math formulas — but keeps balances as plain integers so millions of
+//! slots run in seconds.  Token conservation holds exactly:
+//! `Σ balances + vault + burned` never changes.
+
+use kill_math::{
+    accrue_reapers, blend_spawn_slot, fee_burn, get_pending_bounty, power, power_decay_pct, resolve_combat, split_bounty, Params,
+    Stack,
+};
+
+/// Cells per axis of the 6×6×6 grid
+pub const GRID_SIZE: u16 = 6;
+
+/// Number of stack ids (cells)
+pub const CELLS: u16 = GRID_SIZE * GRID_SIZE * GRID_SIZE;
+
+pub type AgentId = usize;
+
+/// Economy parameters: everything in `GameParams` the simulation reads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Economy {
+    pub math: Params,
+    /// KILL charged per move (raw, 6 decimals)
+    pub move_cost: u64,
+    /// Units per automatically granted reaper
+    pub reaper_threshold: u64,
+    /// Blend `spawn_slot` by power on reinforcement and merge
+    pub blend_spawn_slot: bool,
+}
+
+impl Default for Economy {
+    /// The program's `initialize_params` defaults.
+    fn default() -> Self {
+        Self {
+            math: Params {
+                spawn_cost: 20_000_000,
+                max_multiplier: 50,
+                slots_per_multiplier: 13_224,
+                global_cap_bps: 2_500,
+                burn_bps: 666,
+            },
+            move_cost: 100_000_000,
+            reaper_threshold: 666,
+            blend_spawn_slot: false,
+        }
+    }
+}
+
+/// One agent's stack on a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Occupant {
+    pub agent: AgentId,
+    pub stack: Stack,
+    /// Units spawned toward the next reaper (`AgentStack::reaper_progress`)
+    pub reaper_progress: u64,
+}
+
+/// Per-agent token flows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub balance: u64,
+    /// Paid into spawns and moves
+    pub spent: u64,
+    /// Bounties received, as attacker or defender
+    pub earned: u64,
+    pub spawns: u64,
+    pub moves: u64,
+    pub attacks: u64,
+    pub kills_won: u64,
+    pub defenses: u64,
+    pub defenses_lost: u64,
+}
+
+/// Grid-wide counters, cumulative from the start of the run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Totals {
+    /// Paid in by spawns and moves (vault share + burn)
+    pub added: u64,
+    /// Bounties paid out of the vault
+    pub extracted: u64,
+    pub burned: u64,
+    pub spawns: u64,
+    pub moves: u64,
+    pub attacks: u64,
+    pub kills_won: u64,
+}
+
+/// Why an action was not settled (the program would have reverted).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rejected {
+    InvalidStack,
+    InvalidTarget,
+    NotAdjacent,
+    InsufficientFunds,
+    InsufficientForces,
+    /// The vault cannot cover the bounty transfers
+    VaultShortfall,
+}
+
+/// Settlement of one kill, as the program's `KillEvent` would report it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KillOutcome {
+    pub attacker_won: bool,
+    pub attacker_payout: u64,
+    pub defender_payout: u64,
+    pub burned: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct World {
+    pub economy: Economy,
+    pub slot: u64,
+    pub vault: u64,
+    pub totals: Totals,
+    pub ledgers: Vec<Ledger>,
+    cells: Vec<Vec<Occupant>>,
+}
+
+/// Grid coordinates of a stack id (same layout as the program).
+pub fn coords(id: u16) -> (u16, u16, u16) {
+    (id % GRID_SIZE, (id / GRID_SIZE) % GRID_SIZE, id / (GRID_SIZE * GRID_SIZE))
+}
+
+/// Manhattan distance 1, as `is_adjacent` in the program.
+pub fn is_adjacent(a: u16, b: u16) -> bool {
+    let (ax, ay, az) = coords(a);
+    let (bx, by, bz) = coords(b);
+    ax.abs_diff(bx) + ay.abs_diff(by) + az.abs_diff(bz) == 1
+}
+
+/// The up-to-six cells adjacent to `id`.
+pub fn neighbors(id: u16) -> impl Iterator<Item = u16> {
+    (0..CELLS).filter(move |&other| is_adjacent(id, other))
+}
+
+/// The neighbor of `from` one step closer to `to` (x, then y, then z).
+pub fn step_toward(from: u16, to: u16) -> u16 {
+    let (fx, fy, fz) = coords(from);
+    let (tx, ty, tz) = coords(to);
+    let step = |f: u16, t: u16| if f < t { f + 1 } else { f - 1 };
+    let (x, y, z) = if fx != tx {
+        (step(fx, tx), fy, fz)
+    } else if fy != ty {
+        (fx, step(fy, ty), fz)
+    } else if fz != tz {
+        (fx, fy, step(fz, tz))
+    } else {
+        return from;
+    };
+    x + y * GRID_SIZE + z * GRID_SIZE * GRID_SIZE
+}
+
+impl World {
+    /// A world with `vault` already in the game vault and no agents.
+    pub fn new(economy: Economy, vault: u64) -> Self {
+        Self {
+            economy,
+            slot: 0,
+            vault,
+            totals: Totals::default(),
+            ledgers: Vec::new(),
+            cells: vec![Vec::new(); CELLS as usize],
+        }
+    }
+
+    pub fn add_agent(&mut self, balance: u64) -> AgentId {
+        self.ledgers.push(Ledger { balance, ..Default::default() });
+        self.ledgers.len() - 1
+    }
+
+    pub fn balance(&self, agent: AgentId) -> u64 {
+        self.ledgers[agent].balance
+    }
+
+    /// Non-empty stacks on cell `id`.
+    pub fn cell(&self, id: u16) -> &[Occupant] {
+        &self.cells[id as usize]
+    }
+
+    pub fn occupants(&self) -> impl Iterator<Item = (u16, &Occupant)> {
+        (0..CELLS).flat_map(move |id| self.cell(id).iter().map(move |o| (id, o)))
+    }
+
+    pub fn stack(&self, agent: AgentId, id: u16) -> Option<&Stack> {
+        self.cells
+            .get(id as usize)?
+            .iter()
+            .find(|o| o.agent == agent)
+            .map(|o| &o.stack)
+    }
+
+    /// Cells where `agent` holds a stack.
+    pub fn stacks_of(&self, agent: AgentId) -> impl Iterator<Item = (u16, &Stack)> {
+        self.occupants().filter(move |(_, o)| o.agent == agent).map(|(id, o)| (id, &o.stack))
+    }
+
+    pub fn decay_pct(&self, stack: &Stack) -> u64 {
+        power_decay_pct(&self.economy.math, stack.spawn_slot, self.slot)
+    }
+
+    /// Power × decay, the quantity `resolve_combat` compares.
+    pub fn effective_power(&self, stack: &Stack) -> u128 {
+        power(stack.units, stack.reapers) as u128 * self.decay_pct(stack) as u128
+    }
+
+    pub fn pending_bounty(&self, stack: &Stack) -> u64 {
+        get_pending_bounty(stack, &self.economy.math, self.slot, self.vault)
+    }
+
+    /// KILL charged for spawning `units`.
+    pub fn spawn_cost(&self, units: u64) -> u64 {
+        units.saturating_mul(self.economy.math.spawn_cost)
+    }
+
+    /// Reapers granted for spawning `units` onto a new stack.
+    pub fn reapers_for(&self, units: u64) -> u64 {
+        accrue_reapers(0, units, self.economy.reaper_threshold).0
+    }
+
+    /// Take `fee` from `agent`: the burn share is destroyed, the rest goes
+    /// to the vault.
+    fn charge(&mut self, agent: AgentId, fee: u64) -> Result<(), Rejected> {
+        let ledger = &mut self.ledgers[agent];
+        if ledger.balance < fee {
+            return Err(Rejected::InsufficientFunds);
+        }
+        let burned = fee_burn(fee, self.economy.math.burn_bps);
+        ledger.balance -= fee;
+        ledger.spent += fee;
+        self.vault += fee - burned;
+        self.totals.burned += burned;
+        self.totals.added += fee;
+        Ok(())
+    }
+
+    /// Find or create `agent`'s stack on `id`.  A new (or emptied) stack
+    /// starts aging now with no reaper progress.
+    fn occupant_mut(&mut self, agent: AgentId, id: u16) -> &mut Occupant {
+        let slot = self.slot;
+        let cell = &mut self.cells[id as usize];
+        let i = match cell.iter().position(|o| o.agent == agent) {
+            Some(i) => i,
+            None => {
+                cell.push(Occupant {
+                    agent,
+                    stack: Stack { units: 0, reapers: 0, spawn_slot: slot },
+                    reaper_progress: 0,
+                });
+                cell.len() - 1
+            }
+        };
+        &mut cell[i]
+    }
+
+    fn stack_mut(&mut self, agent: AgentId, id: u16) -> &mut Stack {
+        &mut self.occupant_mut(agent, id).stack
+    }
+
+    fn prune(&mut self, id: u16) {
+        self.cells[id as usize].retain(|o| o.stack.units > 0 || o.stack.reapers > 0);
+    }
+
+    pub fn spawn(&mut self, agent: AgentId, id: u16, units: u64) -> Result<(), Rejected> {
+        if id >= CELLS {
+            return Err(Rejected::InvalidStack);
+        }
+        if units == 0 {
+            return Err(Rejected::InsufficientForces);
+        }
+        self.charge(agent, self.spawn_cost(units))?;
+        let Economy { reaper_threshold, blend_spawn_slot: blend, .. } = self.economy;
+        let slot = self.slot;
+        let occupant = self.occupant_mut(agent, id);
+        let (reapers, progress) = accrue_reapers(occupant.reaper_progress, units, reaper_threshold);
+        if blend {
+            occupant.stack.spawn_slot = blend_spawn_slot(&occupant.stack, power(units, reapers), slot);
+        }
+        occupant.stack.units += units;
+        occupant.stack.reapers += reapers;
+        occupant.reaper_progress = progress;
+        self.ledgers[agent].spawns += 1;
+        self.totals.spawns += 1;
+        Ok(())
+    }
+
+    pub fn move_units(&mut self, agent: AgentId, from: u16, to: u16, units: u64, reapers: u64) -> Result<(), Rejected> {
+        if from >= CELLS || to >= CELLS {
+            return Err(Rejected::InvalidStack);
+        }
+        if !is_adjacent(from, to) {
+            return Err(Rejected::NotAdjacent);
+        }
+        if units == 0 && reapers == 0 {
+            return Err(Rejected::InsufficientForces);
+        }
+        let held = self.stack(agent, from).copied().ok_or(Rejected::InsufficientForces)?;
+        if held.units < units || held.reapers < reapers {
+            return Err(Rejected::InsufficientForces);
+        }
+        self.charge(agent, self.economy.move_cost)?;
+
+        let source = self.stack_mut(agent, from);
+        source.units -= units;
+        source.reapers -= reapers;
+        self.prune(from);
+        let blend = self.economy.blend_spawn_slot;
+        let dest = self.stack_mut(agent, to);
+        // Empty destinations start at the current slot; occupied ones may blend
+        if blend && (dest.units > 0 || dest.reapers > 0) {
+            dest.spawn_slot = blend_spawn_slot(dest, power(units, reapers), held.spawn_slot);
+        }
+        dest.units += units;
+        dest.reapers += reapers;
+        self.ledgers[agent].moves += 1;
+        self.totals.moves += 1;
+        Ok(())
+    }
+
+    /// `agent` sends `units`/`reapers` from its stack on `id` against
+    /// `target`'s stack on the same cell.
+    pub fn kill(
+        &mut self,
+        agent: AgentId,
+        id: u16,
+        target: AgentId,
+        units: u64,
+        reapers: u64,
+    ) -> Result<KillOutcome, Rejected> {
+        if id >= CELLS {
+            return Err(Rejected::InvalidStack);
+        }
+        if target == agent {
+            return Err(Rejected::InvalidTarget);
+        }
+        if units == 0 && reapers == 0 {
+            return Err(Rejected::InsufficientForces);
+        }
+        let attacker = self.stack(agent, id).copied().ok_or(Rejected::InsufficientForces)?;
+        if attacker.units < units || attacker.reapers < reapers {
+            return Err(Rejected::InsufficientForces);
+        }
+        let defender = self.stack(target, id).copied().ok_or(Rejected::InvalidTarget)?;
+
+        let params = self.economy.math;
+        let combat = resolve_combat(
+            defender.units,
+            units,
+            defender.reapers,
+            reapers,
+            power_decay_pct(&params, attacker.spawn_slot, self.slot),
+            power_decay_pct(&params, defender.spawn_slot, self.slot),
+        );
+        let pending = get_pending_bounty(&defender, &params, self.slot, self.vault);
+        let split = split_bounty(pending, &combat, params.burn_bps);
+        let paid = split.attacker_payout + split.defender_payout;
+        let burned = split.total_burn();
+        if paid + burned > self.vault {
+            return Err(Rejected::VaultShortfall);
+        }
+
+        self.vault -= paid + burned;
+        self.totals.extracted += paid;
+        self.totals.burned += burned;
+        self.totals.attacks += 1;
+        self.ledgers[agent].attacks += 1;
+        self.ledgers[agent].earned += split.attacker_payout;
+        self.ledgers[agent].balance += split.attacker_payout;
+        self.ledgers[target].defenses += 1;
+        self.ledgers[target].earned += split.defender_payout;
+        self.ledgers[target].balance += split.defender_payout;
+        if combat.attacker_won {
+            self.totals.kills_won += 1;
+            self.ledgers[agent].kills_won += 1;
+            self.ledgers[target].defenses_lost += 1;
+        }
+
+        let def = self.stack_mut(target, id);
+        def.units -= combat.defender_units_lost;
+        def.reapers -= combat.defender_reapers_lost;
+        let atk = self.stack_mut(agent, id);
+        atk.units = atk.units - units + combat.remaining_units;
+        atk.reapers = atk.reapers - reapers + combat.remaining_reapers;
+        self.prune(id);
+
+        Ok(KillOutcome {
+            attacker_won: combat.attacker_won,
+            attacker_payout: split.attacker_payout,
+            defender_payout: split.defender_payout,
+            burned,
+        })
+    }
+}
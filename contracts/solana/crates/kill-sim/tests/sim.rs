@@ -0,0 +1,133 @@
+use kill_math::{strike, Stack};
+use kill_sim::report::cycles;
+use kill_sim::sim::Sample;
+use kill_sim::strategy::{builtin, BUILTINS};
+use kill_sim::world::{step_toward, Rejected};
+use kill_sim::{Config, Economy, Report, Simulation, World};
+
+const KILL: u64 = 1_000_000;
+
+fn run(seed: u64) -> Report {
+    let mut sim = Simulation::new(Config {
+        slots: 400_000,
+        seed,
+        vault_seed: 1_000 * KILL,
+        ..Default::default()
+    });
+    for name in BUILTINS {
+        for _ in 0..25 {
+            let strategy = builtin(name, sim.rng()).unwrap();
+            sim.add_agent(strategy, 500_000 * KILL);
+        }
+    }
+    sim.run()
+}
+
+#[test]
+fn runs_are_reproducible_and_conserve_tokens() {
+    let report = run(7);
+    assert_eq!(report, run(7));
+    assert_ne!(report.samples, run(8).samples);
+
+    let last = report.samples.last().unwrap();
+    assert_eq!(last.slot, 400_000);
+    assert!(last.attacks > 0 && last.spawns > 0);
+
+    let supply = 1_000 * KILL + 100 * 500_000 * KILL;
+    let balances: u64 = report.agents.iter().map(|a| a.ledger.balance).sum();
+    assert_eq!(balances + last.vault + last.burned, supply);
+    assert_eq!(last.vault, 1_000 * KILL + last.added - last.extracted - last.burned);
+}
+
+#[test]
+fn kill_settles_like_kill_math() {
+    let mut world = World::new(Economy::default(), 0);
+    let (prey, sniper) = (world.add_agent(100_000 * KILL), world.add_agent(100_000 * KILL));
+    world.spawn(prey, 42, 1_332).unwrap();
+    let defender = *world.stack(prey, 42).unwrap();
+    assert_eq!((defender.reapers, defender.spawn_slot), (2, 0));
+
+    world.slot = 200_000;
+    world.spawn(sniper, 42, 2_000).unwrap();
+    let attacker = *world.stack(sniper, 42).unwrap();
+    let expected = strike(&world.economy.math, &attacker, &defender, 2_000, 3, world.vault, world.slot);
+    let (vault, burned) = (world.vault, world.totals.burned);
+
+    let outcome = world.kill(sniper, 42, prey, 2_000, 3).unwrap();
+    assert!(outcome.attacker_won);
+    assert_eq!(outcome.attacker_payout, expected.bounty.attacker_payout);
+    assert_eq!(world.vault, vault - expected.bounty.attacker_payout - expected.bounty.total_burn());
+    assert_eq!(world.totals.burned, burned + expected.bounty.total_burn());
+    assert_eq!(world.stack(prey, 42), None);
+    assert_eq!(world.stack(sniper, 42), Some(&attacker));
+    assert_eq!(world.kill(sniper, 42, prey, 1, 0), Err(Rejected::InvalidTarget));
+}
+
+#[test]
+fn reapers_accrue_across_spawns() {
+    let mut world = World::new(Economy::default(), 0);
+    let agent = world.add_agent(100_000 * KILL);
+    world.spawn(agent, 3, 665).unwrap();
+    world.spawn(agent, 3, 665).unwrap();
+    assert_eq!(world.stack(agent, 3).map(|s| s.reapers), Some(1));
+    assert_eq!(world.cell(3)[0].reaper_progress, 664);
+}
+
+#[test]
+fn blended_spawn_slots_track_capital_age() {
+    let economy = Economy { blend_spawn_slot: true, ..Economy::default() };
+    let mut world = World::new(economy, 0);
+    let agent = world.add_agent(100_000 * KILL);
+    world.spawn(agent, 0, 999).unwrap();
+    world.slot = 648_000;
+    world.spawn(agent, 0, 1).unwrap();
+    assert_eq!(world.stack(agent, 0).map(|s| s.spawn_slot), Some(388));
+
+    // A fresh destination starts at the current slot, then blends on merge
+    world.move_units(agent, 0, 1, 500, 0).unwrap();
+    assert_eq!(world.stack(agent, 1).map(|s| s.spawn_slot), Some(648_000));
+    world.move_units(agent, 0, 1, 500, 0).unwrap();
+    assert_eq!(world.stack(agent, 1).map(|s| s.spawn_slot), Some(324_194));
+}
+
+#[test]
+fn moves_follow_program_rules() {
+    let mut world = World::new(Economy::default(), 0);
+    let agent = world.add_agent(10_000 * KILL);
+    world.spawn(agent, 0, 100).unwrap();
+
+    assert_eq!(world.move_units(agent, 0, 7, 10, 0), Err(Rejected::NotAdjacent));
+    assert_eq!(world.move_units(agent, 0, 1, 101, 0), Err(Rejected::InsufficientForces));
+
+    world.slot = 5_000;
+    world.move_units(agent, 0, 1, 40, 0).unwrap();
+    assert_eq!(world.stack(agent, 1), Some(&Stack { units: 40, reapers: 0, spawn_slot: 5_000 }));
+    world.move_units(agent, 0, 1, 60, 0).unwrap();
+    assert_eq!(world.stack(agent, 0), None);
+    assert_eq!(world.cell(1)[0].reaper_progress, 0, "moves carry no reaper progress");
+    assert_eq!(world.balance(agent), 10_000 * KILL - 100 * 20 * KILL - 2 * 100 * KILL);
+
+    assert_eq!(step_toward(0, 215), 1);
+    assert_eq!(step_toward(5, 215), 11);
+    assert_eq!(step_toward(215, 215), 215);
+}
+
+#[test]
+fn sawtooth_cycles_need_threshold_swings() {
+    let vaults = [100, 150, 200, 190, 120, 130, 300, 280, 290, 100, 130];
+    let samples: Vec<Sample> = vaults
+        .iter()
+        .enumerate()
+        .map(|(i, &vault)| Sample { slot: i as u64 * 10, vault, ..Default::default() })
+        .collect();
+
+    let found = cycles(&samples, 2_000);
+    assert_eq!(found.len(), 2);
+    assert_eq!((found[0].start_slot, found[0].peak_slot, found[0].end_slot), (0, 20, 40));
+    assert_eq!((found[1].start_vault, found[1].peak_vault, found[1].end_vault), (120, 300, 100));
+    assert_eq!(found[1].drawdown_bps(), 6_666);
+    assert_eq!((found[1].accumulation_slots(), found[1].distribution_slots()), (20, 30));
+
+    // At 70% only the first rise counts, so no cycle completes
+    assert!(cycles(&samples, 7_000).is_empty());
+}
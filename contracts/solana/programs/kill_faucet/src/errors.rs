@@ -10,4 +10,7 @@ pub enum FaucetError {
 
     #[msg("Unauthorized — admin only")]
     Unauthorized,
+
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
 }
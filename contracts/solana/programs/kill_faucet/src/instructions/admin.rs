@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::FaucetError;
+use crate::state::{AdminAccepted, AdminProposed, FaucetConfig};
+
+use super::{grow_account, load_padded};
+
+// ── Admin handover ────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"faucet_config"],
+        bump = faucet_config.bump,
+        constraint = faucet_config.admin == admin.key() @ FaucetError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Propose `new_admin` as the next admin (step 1 of 2).  Control does not move
+/// until `new_admin` signs `accept_admin`; proposing `Pubkey::default()`
+/// cancels a pending handover.
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    config.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        admin: config.admin,
+        pending_admin: new_admin,
+        slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"faucet_config"],
+        bump = faucet_config.bump,
+        constraint = faucet_config.pending_admin != Pubkey::default() @ FaucetError::NotPendingAdmin,
+        constraint = faucet_config.pending_admin == new_admin.key() @ FaucetError::NotPendingAdmin,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+/// Take over as admin (step 2 of 2).  Must be signed by the pending admin.
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let previous_admin = config.admin;
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    emit!(AdminAccepted {
+        previous_admin,
+        new_admin: config.admin,
+        slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
+
+// ── FaucetConfig Migration ────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Possibly an older, shorter layout — loaded with `load_padded`.
+    /// Seeds pin the address; the handler checks the admin.
+    #[account(
+        mut,
+        seeds = [b"faucet_config"],
+        bump,
+    )]
+    pub faucet_config: UncheckedAccount<'info>,
+
+    /// Pays the extra rent for the grown account.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow an existing FaucetConfig to the current `FaucetConfig::SPACE`.  The
+/// added tail is zero-filled (no pending admin).  No-op if already current.
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let info = ctx.accounts.faucet_config.to_account_info();
+    let (config, old_len) = load_padded::<FaucetConfig>(&info, FaucetConfig::SPACE)?;
+    require_keys_eq!(config.admin, ctx.accounts.admin.key(), FaucetError::Unauthorized);
+
+    if old_len >= FaucetConfig::SPACE {
+        return Ok(());
+    }
+    grow_account(
+        &info,
+        FaucetConfig::SPACE,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )
+}
@@ -44,5 +44,6 @@ pub fn handler(ctx: Context<InitializeFaucet>) -> Result<()> {
     config.faucet_vault = ctx.accounts.faucet_vault.key();
     config.admin = ctx.accounts.admin.key();
     config.bump = ctx.bumps.faucet_config;
+    config.pending_admin = Pubkey::default();
     Ok(())
 }
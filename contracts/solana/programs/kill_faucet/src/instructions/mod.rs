@@ -1,2 +1,48 @@
+pub mod admin;
 pub mod claim;
 pub mod initialize;
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::FaucetError;
+
+// ── Shared helpers ─────────────────────────────────────────────────────────────
+
+/// Decode a program-owned `T` that may predate fields appended to its layout:
+/// the data is zero-padded to `space` first, so missing fields read as zero.
+/// Checks owner and discriminator; also returns the account's current length.
+pub fn load_padded<T: AccountDeserialize>(account: &AccountInfo, space: usize) -> Result<(T, usize)> {
+    require_keys_eq!(*account.owner, crate::ID, FaucetError::Unauthorized);
+    let mut data = account.try_borrow_data()?.to_vec();
+    let len = data.len();
+    data.resize(len.max(space), 0);
+    Ok((T::try_deserialize(&mut data.as_slice())?, len))
+}
+
+/// Grow a program-owned account to `new_len` bytes, first topping up its rent
+/// exemption from `payer`.  The added tail is zero-filled.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.resize(new_len)?;
+    Ok(())
+}
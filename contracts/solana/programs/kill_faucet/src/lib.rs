@@ -4,6 +4,7 @@ pub mod errors;
 pub mod instructions;
 pub mod state;
 
+use instructions::admin::*;
 use instructions::claim::*;
 use instructions::initialize::*;
 
@@ -27,4 +28,20 @@ pub mod kill_faucet {
     pub fn claim(ctx: Context<Claim>) -> Result<()> {
         instructions::claim::handler(ctx)
     }
+
+    /// Admin: propose a new admin.  Control moves only once the proposed key
+    /// signs `accept_admin`.  Proposing the default pubkey cancels.
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::admin::propose_admin(ctx, new_admin)
+    }
+
+    /// Pending admin: accept the handover proposed by `propose_admin`.
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::admin::accept_admin(ctx)
+    }
+
+    /// Admin: grow a FaucetConfig created before `pending_admin` existed.
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::admin::migrate_config(ctx)
+    }
 }
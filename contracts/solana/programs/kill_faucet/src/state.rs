@@ -17,10 +17,15 @@ pub struct FaucetConfig {
 
     /// Canonical bump for cheap PDA re-derivation
     pub bump: u8,
+
+    /// Admin proposed by `propose_admin`; takes over once it signs
+    /// `accept_admin`.  `Pubkey::default()` when no handover is pending.
+    /// Appended after `bump` so older accounts can be grown by `migrate_config`.
+    pub pending_admin: Pubkey,
 }
 
 impl FaucetConfig {
-    pub const SPACE: usize = 8 + 32 + 32 + 32 + 1;
+    pub const SPACE: usize = 8 + 32 + 32 + 32 + 1 + 32;
 }
 
 /// One-per-wallet claim record — PDA seeds: [b"claim_record", claimer.key()]
@@ -43,3 +48,20 @@ pub struct ClaimRecord {
 impl ClaimRecord {
     pub const SPACE: usize = 8 + 32 + 8 + 1;
 }
+
+// ── Events ────────────────────────────────────────────────────────────────────
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    /// `Pubkey::default()` when a pending handover is cancelled
+    pub pending_admin: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AdminAccepted {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub slot: u64,
+}
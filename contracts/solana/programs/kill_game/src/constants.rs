@@ -1,4 +1,7 @@
-/// 6.66% burn on every bounty payout (matches EVM BURN_BPS)
+// ── Economy defaults ──────────────────────────────────────────────────────────
+// Seeded into GameParams by `initialize_params`; tunable afterwards via `set_config`.
+
+/// 6.66% burn on every bounty payout, spawn fee and move fee (bounty burn matches EVM BURN_BPS)
 pub const BURN_BPS: u64 = 666;
 
 /// Cost to spawn — 20 KILL per unit at 6 decimal places
@@ -10,9 +13,6 @@ pub const REAPER_THRESHOLD: u64 = 666;
 /// Cost to move a stack — 100 KILL at 6 decimal places
 pub const MOVE_COST: u64 = 100_000_000;
 
-/// Base bounty earned per unit (matches EVM THERMAL_PARITY)
-pub const THERMAL_PARITY: u64 = 666;
-
 /// Maximum bounty multiplier (capped at 50×, reached after ~3 days)
 pub const MAX_MULTIPLIER: u64 = 50;
 
@@ -23,6 +23,62 @@ pub const SLOTS_PER_MULTIPLIER: u64 = 13_224;
 /// Bounty cap as a fraction of the game vault balance (25% = 2,500 / 10,000)
 pub const GLOBAL_CAP_BPS: u64 = 2_500;
 
+// ── Vault withdrawal defaults ─────────────────────────────────────────────────
+// Seeded into GameConfig by `initialize_game`; tunable via `set_withdraw_limits`.
+
+/// Slots a queued withdrawal waits before it can execute (~24 h at 0.4s/slot)
+pub const WITHDRAW_DELAY_SLOTS: u64 = 216_000;
+
+/// Most KILL that can leave the vault through withdrawals per epoch — 1M KILL
+pub const WITHDRAW_EPOCH_CAP: u64 = 1_000_000_000_000;
+
+/// Shortest delay `set_withdraw_limits` accepts (~6 h at 0.4s/slot)
+pub const MIN_WITHDRAW_DELAY_SLOTS: u64 = 54_000;
+
+/// Largest per-epoch cap `set_withdraw_limits` accepts — 10M KILL
+pub const MAX_WITHDRAW_EPOCH_CAP: u64 = 10_000_000_000_000;
+
+// ── Pause flags (GameConfig.pause_mask) ───────────────────────────────────────
+
+/// Blocks `spawn` and `spawn_batch` (new deposits)
+pub const PAUSE_SPAWN: u8 = 1 << 0;
+
+/// Blocks `move_units`
+pub const PAUSE_MOVE: u8 = 1 << 1;
+
+/// Blocks `kill`
+pub const PAUSE_KILL: u8 = 1 << 2;
+
+/// Blocks `claim_units`
+pub const PAUSE_CLAIM: u8 = 1 << 3;
+
+/// Every defined pause flag
+pub const PAUSE_ALL: u8 = PAUSE_SPAWN | PAUSE_MOVE | PAUSE_KILL | PAUSE_CLAIM;
+
+// ── Delegation flags (Delegation.allowed) ─────────────────────────────────────
+
+/// Delegate may `spawn` and `spawn_batch`
+pub const DELEGATE_SPAWN: u8 = 1 << 0;
+
+/// Delegate may `move_units`
+pub const DELEGATE_MOVE: u8 = 1 << 1;
+
+/// Delegate may `kill`
+pub const DELEGATE_KILL: u8 = 1 << 2;
+
+/// Every defined delegation flag
+pub const DELEGATE_ALL: u8 = DELEGATE_SPAWN | DELEGATE_MOVE | DELEGATE_KILL;
+
+// ── Parameter bounds (enforced by `set_config`) ───────────────────────────────
+
+/// Upper bound for GameParams.max_multiplier
+pub const MAX_MULTIPLIER_LIMIT: u64 = 1_000;
+
+// ── Fixed game rules ──────────────────────────────────────────────────────────
+
+/// Base bounty earned per unit (matches EVM THERMAL_PARITY)
+pub const THERMAL_PARITY: u64 = kill_math::THERMAL_PARITY;
+
 /// 3D grid side length (6 × 6 × 6 = 216 total stacks)
 pub const GRID_SIZE: u16 = 6;
 
@@ -30,7 +86,22 @@ pub const GRID_SIZE: u16 = 6;
 pub const MAX_STACK_ID: u16 = 215;
 
 /// Basis-points denominator (10,000 = 100%)
-pub const BPS_DENOM: u64 = 10_000;
+pub const BPS_DENOM: u64 = kill_math::BPS_DENOM;
+
+/// Occupant slots added each time a CellIndex account grows
+pub const CELL_INDEX_GROWTH: usize = 4;
+
+/// Most entries in one `spawn_batch`.  Each new cell costs two bump searches
+/// plus two account creations; a full batch of new cells fits the default
+/// 200k compute budget (and a legacy transaction).
+pub const MAX_SPAWN_BATCH: usize = 8;
+
+/// Slots after `commit_kill` during which `reveal_kill` is accepted (~60 s);
+/// afterwards the commitment can only be refunded
+pub const KILL_REVEAL_WINDOW_SLOTS: u64 = 150;
+
+/// Agents ranked by the on-chain Leaderboard
+pub const LEADERBOARD_SIZE: usize = 10;
 
 /// Minimum combat power a stack retains after full age-decay (5%)
-pub const MIN_DECAY_PCT: u64 = 5;
+pub const MIN_DECAY_PCT: u64 = kill_math::MIN_DECAY_PCT;
@@ -31,4 +31,85 @@ pub enum KillError {
 
     #[msg("Unauthorized — admin only")]
     Unauthorized,
+
+    #[msg("Game parameter out of bounds")]
+    InvalidParams,
+
+    #[msg("No airdrop is active")]
+    AirdropInactive,
+
+    #[msg("Merkle proof does not match the airdrop root")]
+    InvalidProof,
+
+    #[msg("Airdrop amount is below the cost of one unit")]
+    AirdropTooSmall,
+
+    #[msg("Remaining accounts do not match the expected PDAs")]
+    InvalidRemainingAccounts,
+
+    #[msg("Duplicate stack ID in batch")]
+    DuplicateStackId,
+
+    #[msg("Stack still holds units or reapers")]
+    StackNotEmpty,
+
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+
+    #[msg("Withdrawal delay has not elapsed")]
+    WithdrawalLocked,
+
+    #[msg("Withdrawal exceeds the per-epoch cap")]
+    WithdrawCapExceeded,
+
+    #[msg("Spawning is paused")]
+    SpawnPaused,
+
+    #[msg("Moving is paused")]
+    MovePaused,
+
+    #[msg("Killing is paused")]
+    KillPaused,
+
+    #[msg("Airdrop claims are paused")]
+    ClaimPaused,
+
+    #[msg("No active season")]
+    SeasonInactive,
+
+    #[msg("Season has not reached its end slot")]
+    SeasonNotOver,
+
+    #[msg("Delegation has expired")]
+    DelegationExpired,
+
+    #[msg("Delegation does not allow this instruction")]
+    DelegationNotAllowed,
+
+    #[msg("Delegation KILL spending cap exceeded")]
+    DelegationCapExceeded,
+
+    #[msg("Revealed strike does not match the commitment")]
+    CommitmentMismatch,
+
+    #[msg("Reveal must land after the commit slot")]
+    RevealTooEarly,
+
+    #[msg("Reveal window has closed")]
+    RevealWindowClosed,
+
+    #[msg("Commitment can still be revealed")]
+    CommitmentActive,
+
+    #[msg("Defender power exceeds the attacker's max_defender_power")]
+    DefenderTooStrong,
+
+    #[msg("Attacker bounty is below min_attacker_bounty")]
+    BountyBelowMinimum,
+
+    #[msg("Strike would not win and require_win is set")]
+    StrikeWouldLose,
+
+    #[msg("Too many entries in spawn_batch")]
+    BatchTooLarge,
 }
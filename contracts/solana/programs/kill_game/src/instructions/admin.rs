@@ -1,13 +1,66 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
+use crate::constants::*;
 use crate::errors::KillError;
-use crate::state::GameConfig;
+use crate::state::{
+    AdminAccepted, AdminProposed, Airdrop, AirdropUpdated, ConfigUpdated, GameConfig, GameParams,
+    Leaderboard, PauseUpdated, PendingWithdrawal, Role, RoleUpdated, WithdrawCancelled,
+    WithdrawExecuted, WithdrawLimitsUpdated, WithdrawQueued,
+};
+
+use super::{grow_account, load_padded};
 
 // ── Pause / Unpause ────────────────────────────────────────────────────────────
 
 #[derive(Accounts)]
 pub struct AdminConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.pauser == pauser.key() @ KillError::Unauthorized,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    pub pauser: Signer<'info>,
+}
+
+/// Pause or unpause the game — pauser only.  While paused, every gameplay
+/// instruction reverts with GamePaused.
+pub fn set_paused(ctx: Context<AdminConfig>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.game_config;
+    config.paused = paused;
+
+    emit!(PauseUpdated {
+        paused,
+        pause_mask: config.pause_mask,
+        slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
+
+/// Pause individual instructions — pauser only.  `pause_mask` is a set of
+/// `PAUSE_*` flags; each paused instruction reverts with its own error
+/// (SpawnPaused, MovePaused, KillPaused, ClaimPaused).  Pass 0 to clear.
+pub fn set_pause_mask(ctx: Context<AdminConfig>, pause_mask: u8) -> Result<()> {
+    require!(pause_mask & !PAUSE_ALL == 0, KillError::InvalidParams);
+
+    let config = &mut ctx.accounts.game_config;
+    config.pause_mask = pause_mask;
+
+    emit!(PauseUpdated {
+        paused: config.paused,
+        pause_mask,
+        slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
+
+// ── Admin authority ───────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct AdminAuthority<'info> {
     #[account(
         mut,
         seeds = [b"game_config"],
@@ -19,16 +72,148 @@ pub struct AdminConfig<'info> {
     pub admin: Signer<'info>,
 }
 
-/// Pause or unpause the game.  While paused, spawn/move/kill revert.
-pub fn set_paused(ctx: Context<AdminConfig>, paused: bool) -> Result<()> {
-    ctx.accounts.game_config.paused = paused;
+/// Assign `role` to `authority`.  Admin only; emits RoleUpdated.
+fn set_role(ctx: Context<AdminAuthority>, role: Role, authority: Pubkey) -> Result<()> {
+    require!(authority != Pubkey::default(), KillError::InvalidParams);
+
+    let config = &mut ctx.accounts.game_config;
+    let slot = match role {
+        Role::Pauser => &mut config.pauser,
+        Role::Treasurer => &mut config.treasurer,
+        Role::ParamsAuthority => &mut config.params_authority,
+    };
+    let previous = *slot;
+    *slot = authority;
+
+    emit!(RoleUpdated {
+        role,
+        previous,
+        authority,
+        slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
+
+/// Hand the pause switch to `pauser`.
+pub fn set_pauser(ctx: Context<AdminAuthority>, pauser: Pubkey) -> Result<()> {
+    set_role(ctx, Role::Pauser, pauser)
+}
+
+/// Hand vault withdrawals to `treasurer`.
+pub fn set_treasurer(ctx: Context<AdminAuthority>, treasurer: Pubkey) -> Result<()> {
+    set_role(ctx, Role::Treasurer, treasurer)
+}
+
+/// Hand economy and airdrop configuration to `params_authority`.
+pub fn set_params_authority(ctx: Context<AdminAuthority>, params_authority: Pubkey) -> Result<()> {
+    set_role(ctx, Role::ParamsAuthority, params_authority)
+}
+
+// ── Admin handover ────────────────────────────────────────────────────────────
+
+/// Propose `new_admin` as the next admin (step 1 of 2).  Control does not move
+/// until `new_admin` signs `accept_admin`; proposing `Pubkey::default()`
+/// cancels a pending handover.
+pub fn propose_admin(ctx: Context<AdminAuthority>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.game_config;
+    config.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        admin: config.admin,
+        pending_admin: new_admin,
+        slot: Clock::get()?.slot,
+    });
     Ok(())
 }
 
-// ── Emergency Vault Withdrawal ────────────────────────────────────────────────
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.pending_admin != Pubkey::default() @ KillError::NotPendingAdmin,
+        constraint = game_config.pending_admin == new_admin.key() @ KillError::NotPendingAdmin,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+/// Take over as admin (step 2 of 2).  Must be signed by the pending admin.
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.game_config;
+    let previous_admin = config.admin;
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    emit!(AdminAccepted {
+        previous_admin,
+        new_admin: config.admin,
+        slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
+
+// ── Economy Parameters ────────────────────────────────────────────────────────
+
+/// Instruction data for `set_config` — the full set of tunable economy knobs.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GameParamsArgs {
+    pub spawn_cost: u64,
+    pub move_cost: u64,
+    pub max_multiplier: u64,
+    pub slots_per_multiplier: u64,
+    pub global_cap_bps: u64,
+    pub burn_bps: u64,
+    pub reaper_threshold: u64,
+    pub blend_spawn_slot: bool,
+}
 
 #[derive(Accounts)]
-pub struct AdminWithdraw<'info> {
+pub struct InitializeParams<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.admin == admin.key() @ KillError::Unauthorized,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Economy parameters — created here once, seeded with the constants.rs defaults.
+    /// Seeds: [b"game_params"]
+    #[account(
+        init,
+        payer = admin,
+        space = GameParams::SPACE,
+        seeds = [b"game_params"],
+        bump
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the GameParams PDA with the default economy.  Required once before
+/// gameplay (and once on deployments that predate GameParams).
+pub fn initialize_params(ctx: Context<InitializeParams>) -> Result<()> {
+    let params = &mut ctx.accounts.game_params;
+    params.spawn_cost = SPAWN_COST;
+    params.move_cost = MOVE_COST;
+    params.max_multiplier = MAX_MULTIPLIER;
+    params.slots_per_multiplier = SLOTS_PER_MULTIPLIER;
+    params.global_cap_bps = GLOBAL_CAP_BPS;
+    params.burn_bps = BURN_BPS;
+    params.reaper_threshold = REAPER_THRESHOLD;
+    params.bump = ctx.bumps.game_params;
+    params.blend_spawn_slot = false;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeLeaderboard<'info> {
     #[account(
         seeds = [b"game_config"],
         bump = game_config.bump,
@@ -36,6 +221,267 @@ pub struct AdminWithdraw<'info> {
     )]
     pub game_config: Account<'info, GameConfig>,
 
+    /// Top-agents table — created here once at its full size.
+    /// Seeds: [b"leaderboard"]
+    #[account(
+        init,
+        payer = admin,
+        space = Leaderboard::SPACE,
+        seeds = [b"leaderboard"],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the empty Leaderboard PDA.  Required once before `kill` (and once on
+/// deployments that predate the leaderboard).
+pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.entries = Vec::new();
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AdminParams<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.params_authority == params_authority.key() @ KillError::Unauthorized,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"game_params"],
+        bump = game_params.bump,
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    pub params_authority: Signer<'info>,
+}
+
+/// Replace the economy parameters (EVM `setConfig` parity).  Takes effect on
+/// the next spawn/move/kill; existing stacks are re-priced at their current age.
+pub fn set_config(ctx: Context<AdminParams>, args: GameParamsArgs) -> Result<()> {
+    require!(args.spawn_cost > 0, KillError::InvalidParams);
+    require!(
+        args.max_multiplier > 0 && args.max_multiplier <= MAX_MULTIPLIER_LIMIT,
+        KillError::InvalidParams
+    );
+    require!(args.slots_per_multiplier > 0, KillError::InvalidParams);
+    require!(
+        args.global_cap_bps > 0 && args.global_cap_bps <= BPS_DENOM,
+        KillError::InvalidParams
+    );
+    require!(args.burn_bps <= BPS_DENOM, KillError::InvalidParams);
+    require!(args.reaper_threshold > 0, KillError::InvalidParams);
+
+    let params = &mut ctx.accounts.game_params;
+    params.spawn_cost = args.spawn_cost;
+    params.move_cost = args.move_cost;
+    params.max_multiplier = args.max_multiplier;
+    params.slots_per_multiplier = args.slots_per_multiplier;
+    params.global_cap_bps = args.global_cap_bps;
+    params.burn_bps = args.burn_bps;
+    params.reaper_threshold = args.reaper_threshold;
+    params.blend_spawn_slot = args.blend_spawn_slot;
+
+    emit!(ConfigUpdated {
+        spawn_cost: args.spawn_cost,
+        move_cost: args.move_cost,
+        max_multiplier: args.max_multiplier,
+        slots_per_multiplier: args.slots_per_multiplier,
+        global_cap_bps: args.global_cap_bps,
+        burn_bps: args.burn_bps,
+        reaper_threshold: args.reaper_threshold,
+        blend_spawn_slot: args.blend_spawn_slot,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+// ── Merkle Airdrop ────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct AdminAirdrop<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.params_authority == params_authority.key() @ KillError::Unauthorized,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Airdrop config — created on first use.  Seeds: [b"airdrop"]
+    #[account(
+        init_if_needed,
+        payer = params_authority,
+        space = Airdrop::SPACE,
+        seeds = [b"airdrop"],
+        bump
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+
+    #[account(mut)]
+    pub params_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the airdrop allow-list root and per-claim KILL value (EVM
+/// `setMerkleRoot` / `setAirdropAmount` parity).  An all-zero root disables
+/// claiming.  Wallets that already claimed stay claimed across root changes.
+pub fn set_merkle_root(
+    ctx: Context<AdminAirdrop>,
+    merkle_root: [u8; 32],
+    airdrop_amount: u64,
+) -> Result<()> {
+    require!(airdrop_amount > 0, KillError::InvalidParams);
+
+    let airdrop = &mut ctx.accounts.airdrop;
+    airdrop.merkle_root = merkle_root;
+    airdrop.airdrop_amount = airdrop_amount;
+    airdrop.bump = ctx.bumps.airdrop;
+
+    emit!(AirdropUpdated {
+        merkle_root,
+        airdrop_amount,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+// ── Vault Withdrawals ─────────────────────────────────────────────────────────
+// Queue → wait `withdraw_delay_slots` → execute, with at most
+// `withdraw_epoch_cap` KILL leaving the vault per epoch.  Every step emits an
+// event so players see a withdrawal coming before it lands.
+
+/// Stage a new withdrawal delay and per-epoch cap — admin only, so the
+/// treasurer cannot loosen its own limits.  The new limits take over once the
+/// current delay has passed, so players always get at least that much warning;
+/// staging again replaces the pending change.  Already-queued withdrawals keep
+/// their executable slot.
+pub fn set_withdraw_limits(
+    ctx: Context<AdminAuthority>,
+    delay_slots: u64,
+    epoch_cap: u64,
+) -> Result<()> {
+    require!(delay_slots >= MIN_WITHDRAW_DELAY_SLOTS, KillError::InvalidParams);
+    require!(epoch_cap <= MAX_WITHDRAW_EPOCH_CAP, KillError::InvalidParams);
+
+    let current_slot = Clock::get()?.slot;
+    let config = &mut ctx.accounts.game_config;
+    config.apply_staged_withdraw_limits(current_slot);
+
+    let effective_slot = current_slot
+        .checked_add(config.withdraw_delay_slots)
+        .ok_or(KillError::Overflow)?;
+    config.staged_withdraw_delay_slots = delay_slots;
+    config.staged_withdraw_epoch_cap = epoch_cap;
+    config.withdraw_limits_effective_slot = effective_slot;
+
+    emit!(WithdrawLimitsUpdated {
+        delay_slots,
+        epoch_cap,
+        effective_slot,
+        slot: current_slot,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct QueueWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.treasurer == treasurer.key() @ KillError::Unauthorized,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// The queued withdrawal — id is the current `withdrawal_nonce`.
+    /// Seeds: [b"withdrawal", id as [u8;8] little-endian]
+    #[account(
+        init,
+        payer = treasurer,
+        space = PendingWithdrawal::SPACE,
+        seeds = [b"withdrawal".as_ref(), &game_config.withdrawal_nonce.to_le_bytes()],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    /// KILL token account that will receive the funds
+    #[account(
+        constraint = destination.mint == game_config.kill_mint,
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a withdrawal from the game vault (step 1 of 2) — treasurer only.
+/// Emits WithdrawQueued.
+pub fn queue_withdraw(ctx: Context<QueueWithdraw>, amount: u64) -> Result<()> {
+    let current_slot = Clock::get()?.slot;
+    let config = &mut ctx.accounts.game_config;
+    config.apply_staged_withdraw_limits(current_slot);
+    require!(amount > 0, KillError::InvalidParams);
+    require!(amount <= config.withdraw_epoch_cap, KillError::WithdrawCapExceeded);
+
+    let id = config.withdrawal_nonce;
+    let executable_slot = current_slot
+        .checked_add(config.withdraw_delay_slots)
+        .ok_or(KillError::Overflow)?;
+    config.withdrawal_nonce = id.checked_add(1).ok_or(KillError::Overflow)?;
+
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    pending.id = id;
+    pending.destination = ctx.accounts.destination.key();
+    pending.amount = amount;
+    pending.queued_slot = current_slot;
+    pending.executable_slot = executable_slot;
+    pending.bump = ctx.bumps.pending_withdrawal;
+
+    emit!(WithdrawQueued {
+        id,
+        destination: pending.destination,
+        amount,
+        executable_slot,
+        slot: current_slot,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct AdminWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.treasurer == treasurer.key() @ KillError::Unauthorized,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// The withdrawal to execute — closed, rent back to the treasurer.
+    #[account(
+        mut,
+        close = treasurer,
+        seeds = [b"withdrawal".as_ref(), &id.to_le_bytes()],
+        bump = pending_withdrawal.bump,
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
     /// Game vault (source)
     #[account(
         mut,
@@ -43,18 +489,44 @@ pub struct AdminWithdraw<'info> {
     )]
     pub game_vault: Account<'info, TokenAccount>,
 
-    /// Admin's (or any other) destination token account
-    #[account(mut)]
+    /// Destination fixed at queue time
+    #[account(
+        mut,
+        constraint = destination.key() == pending_withdrawal.destination,
+    )]
     pub destination: Account<'info, TokenAccount>,
 
-    pub admin: Signer<'info>,
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
 }
 
-/// Emergency drain of the game vault — admin only.
-pub fn withdraw(ctx: Context<AdminWithdraw>, amount: u64) -> Result<()> {
-    let config_bump = ctx.accounts.game_config.bump;
+/// Execute a queued withdrawal once its delay has elapsed (step 2 of 2),
+/// within the per-epoch cap.  Emits WithdrawExecuted and GlobalStats.
+pub fn execute_withdraw(ctx: Context<AdminWithdraw>, id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let amount = ctx.accounts.pending_withdrawal.amount;
+    require!(
+        clock.slot >= ctx.accounts.pending_withdrawal.executable_slot,
+        KillError::WithdrawalLocked
+    );
+
+    // Per-epoch rate limit — the window resets when the epoch rolls over.
+    let config = &mut ctx.accounts.game_config;
+    config.apply_staged_withdraw_limits(clock.slot);
+    if config.withdraw_epoch != clock.epoch {
+        config.withdraw_epoch = clock.epoch;
+        config.withdrawn_in_epoch = 0;
+    }
+    let withdrawn = config
+        .withdrawn_in_epoch
+        .checked_add(amount)
+        .ok_or(KillError::Overflow)?;
+    require!(withdrawn <= config.withdraw_epoch_cap, KillError::WithdrawCapExceeded);
+    config.withdrawn_in_epoch = withdrawn;
+
+    let config_bump = config.bump;
     let signer_seeds: &[&[&[u8]]] = &[&[b"game_config", &[config_bump]]];
 
     token::transfer(
@@ -70,5 +542,110 @@ pub fn withdraw(ctx: Context<AdminWithdraw>, amount: u64) -> Result<()> {
         amount,
     )?;
 
+    let config = &mut ctx.accounts.game_config;
+    config.total_kill_extracted = config.total_kill_extracted.saturating_add(amount);
+
+    emit!(WithdrawExecuted {
+        id,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        slot: clock.slot,
+    });
+    emit!(config.global_stats(clock.slot));
+
     Ok(())
 }
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CancelWithdraw<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.treasurer == treasurer.key() @ KillError::Unauthorized,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// The withdrawal to cancel — closed, rent back to the treasurer.
+    #[account(
+        mut,
+        close = treasurer,
+        seeds = [b"withdrawal".as_ref(), &id.to_le_bytes()],
+        bump = pending_withdrawal.bump,
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+}
+
+/// Cancel a queued withdrawal.  Emits WithdrawCancelled.
+pub fn cancel_withdraw(ctx: Context<CancelWithdraw>, id: u64) -> Result<()> {
+    emit!(WithdrawCancelled {
+        id,
+        amount: ctx.accounts.pending_withdrawal.amount,
+        slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
+
+// ── GameConfig Migration ──────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Possibly an older, shorter layout — loaded with `load_padded`.
+    /// Seeds pin the address; the handler checks the admin.
+    #[account(
+        mut,
+        seeds = [b"game_config"],
+        bump,
+    )]
+    pub game_config: UncheckedAccount<'info>,
+
+    /// Pays the extra rent for the grown account.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow an existing GameConfig to the current `GameConfig::SPACE`.
+///
+/// New fields are always appended, so the old bytes stay valid and the added
+/// tail is zero-filled (every new counter starts at 0); unassigned roles
+/// default to the admin and missing withdrawal limits to the `initialize_game`
+/// defaults.  No-op if the account is already current.
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let info = ctx.accounts.game_config.to_account_info();
+    let (mut config, old_len) = load_padded::<GameConfig>(&info, GameConfig::SPACE)?;
+    require_keys_eq!(config.admin, ctx.accounts.admin.key(), KillError::Unauthorized);
+
+    if old_len >= GameConfig::SPACE {
+        return Ok(());
+    }
+
+    grow_account(
+        &info,
+        GameConfig::SPACE,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // Roles did not exist before — the admin holds all of them until reassigned.
+    for role in [
+        &mut config.pauser,
+        &mut config.treasurer,
+        &mut config.params_authority,
+    ] {
+        if *role == Pubkey::default() {
+            *role = config.admin;
+        }
+    }
+    // Neither did withdrawal limits — start from the `initialize_game` defaults.
+    if config.withdraw_delay_slots == 0 {
+        config.withdraw_delay_slots = WITHDRAW_DELAY_SLOTS;
+        config.withdraw_epoch_cap = WITHDRAW_EPOCH_CAP;
+    }
+    let mut data = info.try_borrow_mut_data()?;
+    config.try_serialize(&mut &mut data[..])
+}
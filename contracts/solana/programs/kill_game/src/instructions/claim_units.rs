@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::KillError;
+use crate::state::{
+    AgentProfile, AgentStack, Airdrop, AirdropClaim, CellIndex, Claimed, GameConfig, GameParams,
+    Season, StackSpawned,
+};
+
+use super::{init_profile_if_new, require_season_active, sync_cell_index, verify_merkle_proof};
+
+/// Claim airdropped units directly onto a stack.
+///
+/// Equivalent to the EVM `claim(proof, stackId)` function.  The claimer proves
+/// membership in the airdrop Merkle tree (leaf = keccak256(claimer pubkey)) and
+/// receives `airdrop_amount / spawn_cost` units plus the usual free Reapers,
+/// spawned or reinforced at `stack_id`.  No KILL moves — the vault backs the
+/// units, exactly as if they had been spawned.
+///
+/// Unlike the EVM version there is no auto-attack on a weak occupant; claimers
+/// follow up with a normal `kill` if they want one.
+#[derive(Accounts)]
+#[instruction(proof: Vec<[u8; 32]>, stack_id: u16)]
+pub struct ClaimUnits<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = !game_config.paused @ KillError::GamePaused,
+        constraint = !game_config.is_paused(PAUSE_CLAIM) @ KillError::ClaimPaused,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Economy parameters — converts the airdrop amount to units and reapers.
+    #[account(
+        seeds = [b"game_params"],
+        bump = game_params.bump,
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    #[account(
+        mut,
+        seeds = [b"airdrop"],
+        bump = airdrop.bump,
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+
+    /// Current season — claimed units land on its grid.
+    #[account(
+        seeds = [b"season".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Claim record — `init` fails if this wallet already claimed.
+    /// Seeds: [b"airdrop_claim", claimer.key()]
+    #[account(
+        init,
+        payer = claimer,
+        space = AirdropClaim::SPACE,
+        seeds = [b"airdrop_claim", claimer.key().as_ref()],
+        bump
+    )]
+    pub claim_record: Account<'info, AirdropClaim>,
+
+    /// The claimer's stack at this position.  Created on first use.
+    /// Seeds: [b"agent_stack", season.id as [u8;4] LE, claimer.key(), stack_id as [u8;2] LE]
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = AgentStack::SPACE,
+        seeds = [
+            b"agent_stack".as_ref(),
+            &season.id.to_le_bytes(),
+            claimer.key().as_ref(),
+            &stack_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub agent_stack: Account<'info, AgentStack>,
+
+    /// Occupancy index for this grid position.
+    /// Seeds: [b"cell_index", season.id as [u8;4] LE, stack_id as [u8;2] LE]
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = CellIndex::space_for(CELL_INDEX_GROWTH),
+        seeds = [b"cell_index".as_ref(), &season.id.to_le_bytes(), &stack_id.to_le_bytes()],
+        bump
+    )]
+    pub cell_index: Account<'info, CellIndex>,
+
+    /// Claimer's lifetime P&L record.  Created on first use.
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = AgentProfile::SPACE,
+        seeds = [b"agent_profile", claimer.key().as_ref()],
+        bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimUnits>, proof: Vec<[u8; 32]>, stack_id: u16) -> Result<()> {
+    require!(stack_id <= MAX_STACK_ID, KillError::InvalidStackId);
+
+    let airdrop = &mut ctx.accounts.airdrop;
+    require!(airdrop.merkle_root != [0u8; 32], KillError::AirdropInactive);
+
+    let claimer = ctx.accounts.claimer.key();
+    let leaf = solana_keccak_hasher::hashv(&[claimer.as_ref()]).to_bytes();
+    require!(
+        verify_merkle_proof(&proof, &airdrop.merkle_root, leaf),
+        KillError::InvalidProof
+    );
+
+    let params = &ctx.accounts.game_params;
+    let units = airdrop.airdrop_amount / params.spawn_cost;
+    require!(units > 0, KillError::AirdropTooSmall);
+    airdrop.total_claims = airdrop.total_claims.saturating_add(1);
+
+    let current_slot = Clock::get()?.slot;
+    require_season_active(&ctx.accounts.season, current_slot)?;
+
+    // Spawn onto the chosen stack — same bookkeeping as `spawn`, minus the payment.
+    let stack = &mut ctx.accounts.agent_stack;
+    if stack.units == 0 && stack.reapers == 0 {
+        stack.agent = claimer;
+        stack.stack_id = stack_id;
+        stack.spawn_slot = current_slot;
+        stack.kill_slot = 0;
+        stack.bump = ctx.bumps.agent_stack;
+        stack.season = ctx.accounts.season.id;
+        stack.reaper_progress = 0;
+    }
+    let auto_reapers = stack
+        .add_spawned_units(units, params, current_slot)
+        .ok_or(KillError::Overflow)?;
+
+    let record = &mut ctx.accounts.claim_record;
+    record.claimer = claimer;
+    record.stack_id = stack_id;
+    record.units = units;
+    record.slot = current_slot;
+    record.bump = ctx.bumps.claim_record;
+
+    init_profile_if_new(&mut ctx.accounts.agent_profile, claimer, ctx.bumps.agent_profile);
+
+    sync_cell_index(
+        &mut ctx.accounts.cell_index,
+        claimer,
+        true,
+        &ctx.accounts.claimer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    ctx.accounts.cell_index.stack_id = stack_id;
+    ctx.accounts.cell_index.bump = ctx.bumps.cell_index;
+    ctx.accounts.cell_index.season = ctx.accounts.season.id;
+
+    emit!(Claimed {
+        claimer,
+        stack_id,
+        units,
+        reapers: auto_reapers,
+        slot: current_slot,
+    });
+    emit!(StackSpawned {
+        agent: claimer,
+        stack_id,
+        units: ctx.accounts.agent_stack.units,
+        reapers: ctx.accounts.agent_stack.reapers,
+        slot: current_slot,
+        deposited: 0,
+        burned: 0,
+        spawned_units: units,
+        spawned_reapers: auto_reapers,
+    });
+
+    Ok(())
+}
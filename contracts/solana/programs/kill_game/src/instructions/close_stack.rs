@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::KillError;
+use crate::state::{AgentStack, CellIndex, Season};
+
+use super::{close_empty_stack, sync_cell_index};
+
+/// Close one of the signer's empty stacks and return its rent.
+///
+/// Only stacks with `units == 0 && reapers == 0` can be closed.  The stack is
+/// delisted from its CellIndex before closing, so the index never points at
+/// a closed account — whether the stack was emptied elsewhere or zeroed here
+/// after its season ended.
+/// The AgentProfile is untouched — lifetime P&L outlives the stack.
+/// Re-spawning on the same position later simply re-creates the PDA.
+///
+/// Stacks from an ended season are frozen and can be closed whatever they
+/// still hold — their units have no further use once the season is over.
+#[derive(Accounts)]
+#[instruction(season_id: u32, stack_id: u16)]
+pub struct CloseStack<'info> {
+    /// Season the stack belongs to.
+    #[account(
+        seeds = [b"season".as_ref(), &season_id.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Stack to close — must be owned by the signer, and empty unless its
+    /// season has ended.
+    /// Seeds: [b"agent_stack", season_id as [u8;4] LE, agent.key(), stack_id as [u8;2] LE]
+    #[account(
+        mut,
+        seeds = [
+            b"agent_stack".as_ref(),
+            &season_id.to_le_bytes(),
+            agent.key().as_ref(),
+            &stack_id.to_le_bytes(),
+        ],
+        bump = agent_stack.bump,
+        constraint = agent_stack.agent == agent.key(),
+        constraint = (season.ended || (agent_stack.units == 0 && agent_stack.reapers == 0))
+            @ KillError::StackNotEmpty,
+    )]
+    pub agent_stack: Account<'info, AgentStack>,
+
+    /// Occupancy index for this grid position — the stack is delisted here.
+    /// Seeds: [b"cell_index", season_id as [u8;4] LE, stack_id as [u8;2] LE]
+    #[account(
+        init_if_needed,
+        payer = agent,
+        space = CellIndex::space_for(CELL_INDEX_GROWTH),
+        seeds = [
+            b"cell_index".as_ref(),
+            &season_id.to_le_bytes(),
+            &stack_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub cell_index: Account<'info, CellIndex>,
+
+    /// Stack owner — receives the reclaimed rent.
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CloseStack>, season_id: u32, stack_id: u16) -> Result<()> {
+    require!(stack_id <= MAX_STACK_ID, KillError::InvalidStackId);
+
+    let current_slot = Clock::get()?.slot;
+    if ctx.accounts.season.ended {
+        let stack = &mut ctx.accounts.agent_stack;
+        stack.units = 0;
+        stack.reapers = 0;
+    }
+
+    sync_cell_index(
+        &mut ctx.accounts.cell_index,
+        ctx.accounts.agent.key(),
+        false,
+        &ctx.accounts.agent.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    ctx.accounts.cell_index.stack_id = stack_id;
+    ctx.accounts.cell_index.bump = ctx.bumps.cell_index;
+    ctx.accounts.cell_index.season = season_id;
+
+    close_empty_stack(
+        &ctx.accounts.agent_stack,
+        &ctx.accounts.agent.to_account_info(),
+        current_slot,
+    )
+}
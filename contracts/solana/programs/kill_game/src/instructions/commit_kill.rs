@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::KillError;
+use crate::state::{
+    AgentStack, CommitRefunded, Delegation, GameConfig, KillCommitment, KillCommitted, Season,
+};
+
+use super::{authorize_agent, require_season_active};
+
+// ── Commit ────────────────────────────────────────────────────────────────────
+
+/// First phase of a two-phase strike.
+///
+/// Locks `units` and `reapers` on the attacker's stack and records them in a
+/// KillCommitment together with the hash of the intended strike (see
+/// `kill_commitment_hash`).  Defenders see that a strike is coming from this
+/// cell but not whom it targets or how much of the commitment it sends.
+/// Locked forces stay on the stack and defend it as usual, but `move_units`
+/// and `kill` cannot spend them until `reveal_kill` or `refund_commit`
+/// releases the lock.  One pending commitment per stack.
+#[derive(Accounts)]
+#[instruction(attacker_stack_id: u16)]
+pub struct CommitKill<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = !game_config.paused @ KillError::GamePaused,
+        constraint = !game_config.is_paused(PAUSE_KILL) @ KillError::KillPaused,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Current season — gameplay is only accepted while it is active.
+    #[account(
+        seeds = [b"season".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Attacker's stack — the committed forces are locked here.
+    #[account(
+        mut,
+        seeds = [
+            b"agent_stack".as_ref(),
+            &season.id.to_le_bytes(),
+            attacker.key().as_ref(),
+            &attacker_stack_id.to_le_bytes(),
+        ],
+        bump = attacker_stack.bump,
+        constraint = attacker_stack.agent == attacker.key(),
+    )]
+    pub attacker_stack: Account<'info, AgentStack>,
+
+    /// The commitment — `init` fails while one is already pending.
+    /// Seeds: [b"kill_commit", attacker_stack.key()]
+    #[account(
+        init,
+        payer = authority,
+        space = KillCommitment::SPACE,
+        seeds = [b"kill_commit", attacker_stack.key().as_ref()],
+        bump
+    )]
+    pub commitment: Account<'info, KillCommitment>,
+
+    /// CHECK: The attacking wallet.  Must sign itself or be the owner of
+    /// `delegation`; checked in the handler by `authorize_agent`.
+    pub attacker: UncheckedAccount<'info>,
+
+    /// Attacker's session key — required only when `authority` is a delegate.
+    #[account(
+        mut,
+        seeds = [b"delegation", attacker.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// The attacker or its delegate — signs and pays rent.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn commit_kill(
+    ctx: Context<CommitKill>,
+    attacker_stack_id: u16,
+    units: u64,
+    reapers: u64,
+    hash: [u8; 32],
+) -> Result<()> {
+    require!(attacker_stack_id <= MAX_STACK_ID, KillError::InvalidStackId);
+    require!(units > 0 || reapers > 0, KillError::EmptyAttacker);
+    let current_slot = Clock::get()?.slot;
+    require_season_active(&ctx.accounts.season, current_slot)?;
+    authorize_agent(
+        ctx.accounts.attacker.key(),
+        ctx.accounts.authority.key(),
+        ctx.accounts.delegation.as_mut(),
+        DELEGATE_KILL,
+        0,
+        current_slot,
+    )?;
+
+    let stack = &mut ctx.accounts.attacker_stack;
+    let (free_units, free_reapers) = stack.unlocked();
+    require!(
+        units <= free_units && reapers <= free_reapers,
+        KillError::InsufficientBalance
+    );
+    stack.locked_units += units;
+    stack.locked_reapers += reapers;
+
+    let commitment = &mut ctx.accounts.commitment;
+    commitment.attacker = ctx.accounts.attacker.key();
+    commitment.season = ctx.accounts.season.id;
+    commitment.stack_id = attacker_stack_id;
+    commitment.hash = hash;
+    commitment.units = units;
+    commitment.reapers = reapers;
+    commitment.commit_slot = current_slot;
+    commitment.bump = ctx.bumps.commitment;
+
+    emit!(KillCommitted {
+        attacker: commitment.attacker,
+        stack_id: attacker_stack_id,
+        units,
+        reapers,
+        reveal_deadline: current_slot.saturating_add(KILL_REVEAL_WINDOW_SLOTS),
+        slot: current_slot,
+    });
+    Ok(())
+}
+
+// ── Refund ────────────────────────────────────────────────────────────────────
+
+/// Permissionless: release the forces an expired commitment locked on the
+/// attacker's stack and close it (rent to the attacker).
+///
+/// Callable once KILL_REVEAL_WINDOW_SLOTS have passed since the commit.  A
+/// stack closed in the meantime has nothing left to release; the commitment
+/// is closed all the same.
+#[derive(Accounts)]
+pub struct RefundCommit<'info> {
+    #[account(
+        mut,
+        close = attacker,
+        constraint = commitment.attacker == attacker.key() @ KillError::Unauthorized,
+    )]
+    pub commitment: Account<'info, KillCommitment>,
+
+    /// CHECK: The stack the forces were locked on — loaded in the handler
+    /// only if it still exists.  Seeds pin the address.
+    /// Seeds: [b"agent_stack", commitment.season LE, commitment.attacker, commitment.stack_id LE]
+    #[account(
+        mut,
+        seeds = [
+            b"agent_stack".as_ref(),
+            &commitment.season.to_le_bytes(),
+            commitment.attacker.as_ref(),
+            &commitment.stack_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub attacker_stack: UncheckedAccount<'info>,
+
+    /// CHECK: The committing wallet — receives the commitment's rent.
+    /// Validated against `commitment.attacker`.
+    #[account(mut)]
+    pub attacker: UncheckedAccount<'info>,
+}
+
+pub fn refund_commit(ctx: Context<RefundCommit>) -> Result<()> {
+    let current_slot = Clock::get()?.slot;
+    let commitment = &ctx.accounts.commitment;
+    require!(
+        current_slot > commitment.commit_slot.saturating_add(KILL_REVEAL_WINDOW_SLOTS),
+        KillError::CommitmentActive
+    );
+
+    // Decoding checks the discriminator; the seeds already pin the address.
+    let info = ctx.accounts.attacker_stack.to_account_info();
+    if *info.owner == crate::ID {
+        let mut stack = AgentStack::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        stack.unlock(commitment.units, commitment.reapers);
+        stack.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    }
+
+    emit!(CommitRefunded {
+        attacker: commitment.attacker,
+        stack_id: commitment.stack_id,
+        units: commitment.units,
+        reapers: commitment.reapers,
+        slot: current_slot,
+    });
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve, Revoke, Token, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::KillError;
+use crate::state::{Delegation, DelegationRevoked, DelegationSet, GameConfig};
+
+// ── Set delegation ────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct SetDelegation<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// The owner's session key — created on first use, replaced on later calls.
+    /// Seeds: [b"delegation", owner.key()]
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = Delegation::SPACE,
+        seeds = [b"delegation", owner.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    /// Owner's KILL token account — the Delegation PDA is approved on it for
+    /// `spend_cap`, so the delegate's fees are paid from here.
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key(),
+        constraint = owner_token_account.mint == game_config.kill_mint,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Authorize `delegate` to sign gameplay for the owner until `expiry_slot`,
+/// limited to the `DELEGATE_*` flags in `allowed` and `spend_cap` KILL of
+/// fees.  Calling again replaces the delegation and resets its spend.
+pub fn set_delegation(
+    ctx: Context<SetDelegation>,
+    delegate: Pubkey,
+    expiry_slot: u64,
+    allowed: u8,
+    spend_cap: u64,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let current_slot = Clock::get()?.slot;
+    require!(
+        delegate != Pubkey::default() && delegate != owner,
+        KillError::InvalidParams
+    );
+    require!(
+        allowed != 0 && allowed & !DELEGATE_ALL == 0,
+        KillError::InvalidParams
+    );
+    require!(expiry_slot > current_slot, KillError::InvalidParams);
+
+    let delegation = &mut ctx.accounts.delegation;
+    delegation.owner = owner;
+    delegation.delegate = delegate;
+    delegation.expiry_slot = expiry_slot;
+    delegation.allowed = allowed;
+    delegation.spend_cap = spend_cap;
+    delegation.spent = 0;
+    delegation.bump = ctx.bumps.delegation;
+
+    token::approve(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Approve {
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                delegate: ctx.accounts.delegation.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        spend_cap,
+    )?;
+
+    emit!(DelegationSet {
+        owner,
+        delegate,
+        expiry_slot,
+        allowed,
+        spend_cap,
+        slot: current_slot,
+    });
+    Ok(())
+}
+
+// ── Revoke delegation ─────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"delegation", owner.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    /// Owner's KILL token account — its SPL approval is revoked.
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key(),
+        constraint = owner_token_account.mint == game_config.kill_mint,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// End the delegation at once: close the PDA (rent back to the owner) and
+/// revoke its SPL approval.
+pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+    token::revoke(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Revoke {
+            source: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        },
+    ))?;
+
+    let delegation = &ctx.accounts.delegation;
+    emit!(DelegationRevoked {
+        owner: delegation.owner,
+        delegate: delegation.delegate,
+        spent: delegation.spent,
+        slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
+use crate::constants::*;
 use crate::state::GameConfig;
 
 /// Initializes the singleton GameConfig PDA and its associated vault token
@@ -51,5 +52,22 @@ pub fn handler(ctx: Context<InitializeGame>) -> Result<()> {
     config.total_kills = 0;
     config.paused = false;
     config.bump = ctx.bumps.game_config;
+    config.total_units_killed = 0;
+    config.total_reapers_killed = 0;
+    config.total_kill_added = 0;
+    config.total_kill_extracted = 0;
+    config.total_kill_burned = 0;
+    config.pending_admin = Pubkey::default();
+    config.withdraw_delay_slots = WITHDRAW_DELAY_SLOTS;
+    config.withdraw_epoch_cap = WITHDRAW_EPOCH_CAP;
+    config.withdraw_epoch = 0;
+    config.withdrawn_in_epoch = 0;
+    config.withdrawal_nonce = 0;
+    config.pauser = config.admin;
+    config.treasurer = config.admin;
+    config.params_authority = config.admin;
+    config.pause_mask = 0;
+    config.current_season = 0;
+    config.season_end_slot = 0;
     Ok(())
 }
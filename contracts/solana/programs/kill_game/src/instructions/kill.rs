@@ -3,9 +3,29 @@ use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::KillError;
-use crate::state::{AgentStack, GameConfig, KillEvent};
+use crate::state::{
+    AgentProfile, AgentStack, CellIndex, Delegation, GameConfig, GameParams, KillCommitment,
+    KillEvent, KillQuote, Leaderboard, Season, SeasonStanding,
+};
 
-use super::{get_pending_bounty, power_decay_pct, resolve_combat};
+use super::{
+    authorize_agent, close_empty_stack, init_profile_if_new, init_standing_if_new, kill_commitment_hash,
+    quote_strike, require_season_active, sync_cell_index,
+};
+
+/// Optional slippage limits on a strike, checked against the outcome computed
+/// when the transaction executes.  `KillGuards::default()` checks nothing.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct KillGuards {
+    /// Upper bound on the defender's raw power (units + reapers × THERMAL_PARITY,
+    /// before decay) — reverts with `DefenderTooStrong` if it was reinforced.
+    pub max_defender_power: Option<u64>,
+    /// Lower bound on the attacker's payout after burn — reverts with
+    /// `BountyBelowMinimum`.
+    pub min_attacker_bounty: Option<u64>,
+    /// Revert with `StrikeWouldLose` unless the attacker wins.
+    pub require_win: bool,
+}
 
 /// Attack an enemy stack on the same grid position.
 ///
@@ -15,41 +35,153 @@ use super::{get_pending_bounty, power_decay_pct, resolve_combat};
 ///   battlePool  = pending × min(totalPowerLost, THERMAL_PARITY) / THERMAL_PARITY
 ///   atkBounty   = battlePool × defPowerLost / totalPowerLost  → to attacker
 ///   defBounty   = battlePool × atkPowerLost / totalPowerLost  → to defender
-///   burn        = BURN_BPS% of each bounty, subtracted before payout
+///   burn        = burn_bps of each bounty, subtracted before payout
 ///
 /// Attacker wins → all defender forces destroyed; attacker keeps all sent forces.
 /// Defender wins → attacker loses all sent forces; defender takes Lanchester partial loss.
+///
+/// With `close_empty`, whichever stack the fight leaves empty is closed and its
+/// rent returned to its owner.
+///
+/// `guards` (see KillGuards) revert the strike before any transfer when the
+/// live outcome is worse than the attacker expected.
+///
+/// Signed by the attacker or by its delegate (see `set_delegation`); bounties
+/// are always paid to the attacker's own token account.
 #[derive(Accounts)]
-#[instruction(attacker_stack_id: u16, defender_stack_id: u16)]
+#[instruction(attacker_stack_id: u16)]
 pub struct Kill<'info> {
     #[account(
         mut,
         seeds = [b"game_config"],
         bump = game_config.bump,
         constraint = !game_config.paused @ KillError::GamePaused,
+        constraint = !game_config.is_paused(PAUSE_KILL) @ KillError::KillPaused,
     )]
     pub game_config: Account<'info, GameConfig>,
 
-    /// Attacker's stack — must be owned by the signer and non-empty.
+    /// Economy parameters — spawn/move cost, multiplier curve, burn and reaper rates.
+    #[account(
+        seeds = [b"game_params"],
+        bump = game_params.bump,
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    /// Current season — gameplay is only accepted while it is active.
+    /// Seeds: [b"season", game_config.current_season as [u8;4] little-endian]
+    #[account(
+        mut,
+        seeds = [b"season".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Attacker's stack — must be owned by the attacker and non-empty.
     #[account(
         mut,
-        seeds = [b"agent_stack", attacker.key().as_ref(), &attacker_stack_id.to_le_bytes()],
+        seeds = [
+            b"agent_stack".as_ref(),
+            &season.id.to_le_bytes(),
+            attacker.key().as_ref(),
+            &attacker_stack_id.to_le_bytes(),
+        ],
         bump = attacker_stack.bump,
         constraint = attacker_stack.agent == attacker.key(),
-        constraint = (attacker_stack.units > 0 || attacker_stack.reapers > 0) @ KillError::EmptyAttacker,
     )]
     pub attacker_stack: Account<'info, AgentStack>,
 
+    /// Pending strike on the attacker's stack — required by `reveal_kill`,
+    /// ignored by `kill`.
+    /// Seeds: [b"kill_commit", attacker_stack.key()]
+    #[account(
+        mut,
+        seeds = [b"kill_commit", attacker_stack.key().as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Option<Box<Account<'info, KillCommitment>>>,
+
     /// Defender's stack — must be non-empty and owned by a different agent.
     #[account(
         mut,
-        seeds = [b"agent_stack", defender.key().as_ref(), &defender_stack_id.to_le_bytes()],
+        seeds = [
+            b"agent_stack".as_ref(),
+            &season.id.to_le_bytes(),
+            defender.key().as_ref(),
+            &attacker_stack_id.to_le_bytes(),
+        ],
         bump = defender_stack.bump,
         constraint = (defender_stack.units > 0 || defender_stack.reapers > 0) @ KillError::EmptyDefender,
         constraint = defender_stack.agent != attacker.key() @ KillError::SelfAttack,
     )]
     pub defender_stack: Account<'info, AgentStack>,
 
+    /// Occupancy index for the contested cell — whichever side is wiped out is delisted.
+    /// Seeds: [b"cell_index", season.id as [u8;4] LE, stack_id as [u8;2] LE]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CellIndex::space_for(CELL_INDEX_GROWTH),
+        seeds = [
+            b"cell_index".as_ref(),
+            &season.id.to_le_bytes(),
+            &attacker_stack_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub cell_index: Account<'info, CellIndex>,
+
+    /// Attacker's lifetime P&L record.  Created on first use.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AgentProfile::SPACE,
+        seeds = [b"agent_profile", attacker.key().as_ref()],
+        bump
+    )]
+    pub attacker_profile: Account<'info, AgentProfile>,
+
+    /// Defender's lifetime P&L record.  Created (attacker pays) if the defender's
+    /// stack predates AgentProfile, so older stacks cannot dodge attacks.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AgentProfile::SPACE,
+        seeds = [b"agent_profile", defender.key().as_ref()],
+        bump
+    )]
+    pub defender_profile: Account<'info, AgentProfile>,
+
+    // Standings are boxed to keep this large context within the BPF stack frame.
+    /// Attacker's results for the current season.  Created on first use.
+    /// Seeds: [b"season_standing", season.id as [u8;4] little-endian, attacker.key()]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SeasonStanding::SPACE,
+        seeds = [b"season_standing".as_ref(), &season.id.to_le_bytes(), attacker.key().as_ref()],
+        bump
+    )]
+    pub attacker_standing: Box<Account<'info, SeasonStanding>>,
+
+    /// Defender's results for the current season.  Created (attacker pays) on first use.
+    /// Seeds: [b"season_standing", season.id as [u8;4] little-endian, defender.key()]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SeasonStanding::SPACE,
+        seeds = [b"season_standing".as_ref(), &season.id.to_le_bytes(), defender.key().as_ref()],
+        bump
+    )]
+    pub defender_standing: Box<Account<'info, SeasonStanding>>,
+
+    /// Top agents by bounty received — re-ranked for whoever is paid.
+    #[account(
+        mut,
+        seeds = [b"leaderboard"],
+        bump = leaderboard.bump,
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
     /// Attacker's KILL token account — receives the net bounty payout if attacker wins.
     #[account(
         mut,
@@ -80,14 +212,32 @@ pub struct Kill<'info> {
     )]
     pub kill_mint: Account<'info, Mint>,
 
+    /// CHECK: The attacking wallet.  Must sign itself or be the owner of
+    /// `delegation`; checked in the handler by `authorize_agent`.  Writable to
+    /// receive the rent of a wiped-out attacker stack.
     #[account(mut)]
-    pub attacker: Signer<'info>,
+    pub attacker: UncheckedAccount<'info>,
 
-    /// CHECK: Only used to derive the defender_stack PDA seed — not signed,
-    /// not written to.  The stack's agent field is validated by the seeds.
+    /// Attacker's session key — required only when `authority` is a delegate.
+    #[account(
+        mut,
+        seeds = [b"delegation", attacker.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Box<Account<'info, Delegation>>>,
+
+    /// The attacker or its delegate — signs and pays rent.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Used to derive the defender_stack PDA seed and, with `close_empty`,
+    /// to receive the rent of a wiped-out defender stack — not signed.
+    /// The stack's agent field is validated by the seeds.
+    #[account(mut)]
     pub defender: UncheckedAccount<'info>,
 
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
@@ -96,72 +246,74 @@ pub fn handler(
     defender_stack_id: u16,
     sent_units: u64,
     sent_reapers: u64,
+    close_empty: bool,
+    guards: KillGuards,
 ) -> Result<()> {
     require!(
         attacker_stack_id == defender_stack_id,
         KillError::NotSameStack
     );
+    require!(
+        ctx.accounts.attacker_stack.units > 0 || ctx.accounts.attacker_stack.reapers > 0,
+        KillError::EmptyAttacker
+    );
     require!(sent_units > 0 || sent_reapers > 0, KillError::EmptyAttacker);
+    // Forces locked by a pending commit_kill can only be sent by revealing it
+    let (free_units, free_reapers) = ctx.accounts.attacker_stack.unlocked();
     require!(
-        sent_units <= ctx.accounts.attacker_stack.units
-            && sent_reapers <= ctx.accounts.attacker_stack.reapers,
+        sent_units <= free_units && sent_reapers <= free_reapers,
         KillError::InsufficientBalance
     );
 
     let current_slot = Clock::get()?.slot;
+    require_season_active(&ctx.accounts.season, current_slot)?;
+    authorize_agent(
+        ctx.accounts.attacker.key(),
+        ctx.accounts.authority.key(),
+        ctx.accounts.delegation.as_deref_mut(),
+        DELEGATE_KILL,
+        0,
+        current_slot,
+    )?;
+    let params = &ctx.accounts.game_params;
 
     // Snapshot defender before combat
     let def_units   = ctx.accounts.defender_stack.units;
     let def_reapers = ctx.accounts.defender_stack.reapers;
 
-    // Power decay — older stacks fight at reduced effectiveness (5%–100%).
-    // Inverse of the bounty multiplier: a 3-day-old stack has 50× bounty but 5% power.
-    let atk_decay = power_decay_pct(ctx.accounts.attacker_stack.spawn_slot, current_slot);
-    let def_decay = power_decay_pct(ctx.accounts.defender_stack.spawn_slot, current_slot);
-
-    // ── Combat ────────────────────────────────────────────────────────────────
-    // Returns: (won, rem_atk_u, rem_atk_r, atk_u_lost, atk_r_lost, def_u_lost, def_r_lost)
-    // Decay percentages scale effective power for win check + Lanchester ratio,
-    // but all returned loss/survivor values are in actual (not decayed) unit counts.
-    let (won, rem_units, rem_reapers, atk_u_lost, atk_r_lost, def_u_lost, def_r_lost) =
-        resolve_combat(def_units, sent_units, def_reapers, sent_reapers, atk_decay, def_decay);
-
-    // ── Bounty calculation (EVM _applyRewards parity) ─────────────────────────
-    // Power destroyed by each side
-    let t_p_lost = def_u_lost.saturating_add(def_r_lost.saturating_mul(THERMAL_PARITY));
-    let a_p_lost = atk_u_lost.saturating_add(atk_r_lost.saturating_mul(THERMAL_PARITY));
-    let total_p_lost = t_p_lost.saturating_add(a_p_lost);
-
+    // Combat and bounty math — shared with `quote_kill` so previews match exactly.
     let vault_amount = ctx.accounts.game_vault.amount;
-    let pending = get_pending_bounty(&ctx.accounts.defender_stack, current_slot, vault_amount);
-
-    // battlePool scales by how much total power was destroyed (EVM parity)
-    let battle_pool = if total_p_lost == 0 {
-        0u64
-    } else if total_p_lost >= THERMAL_PARITY {
-        pending
-    } else {
-        pending.saturating_mul(total_p_lost) / THERMAL_PARITY
-    };
-
-    // Split battlePool proportionally to power each side destroyed
-    let atk_bounty = if total_p_lost == 0 || t_p_lost == 0 {
-        0u64
-    } else {
-        battle_pool.saturating_mul(t_p_lost) / total_p_lost
-    };
-    let def_bounty = if total_p_lost == 0 || a_p_lost == 0 {
-        0u64
-    } else {
-        battle_pool.saturating_mul(a_p_lost) / total_p_lost
-    };
-
-    // Apply BURN_BPS to each bounty
-    let atk_burn   = atk_bounty.saturating_mul(BURN_BPS) / BPS_DENOM;
-    let atk_payout = atk_bounty.saturating_sub(atk_burn);
-    let def_burn   = def_bounty.saturating_mul(BURN_BPS) / BPS_DENOM;
-    let def_payout = def_bounty.saturating_sub(def_burn);
-    let total_burn = atk_burn.saturating_add(def_burn);
+    let KillQuote {
+        won,
+        remaining_units: rem_units,
+        remaining_reapers: rem_reapers,
+        attacker_units_lost: atk_u_lost,
+        attacker_reapers_lost: atk_r_lost,
+        defender_units_lost: def_u_lost,
+        defender_reapers_lost: def_r_lost,
+        defender_power: def_power,
+        attacker_bounty: atk_payout,
+        defender_bounty: def_payout,
+        total_burned: total_burn,
+        ..
+    } = quote_strike(
+        params,
+        &ctx.accounts.attacker_stack,
+        &ctx.accounts.defender_stack,
+        sent_units,
+        sent_reapers,
+        vault_amount,
+        current_slot,
+    );
+
+    // ── Slippage guards ────────────────────────────────────────────────────────
+    if let Some(max_power) = guards.max_defender_power {
+        require!(def_power <= max_power, KillError::DefenderTooStrong);
+    }
+    if let Some(min_bounty) = guards.min_attacker_bounty {
+        require!(atk_payout >= min_bounty, KillError::BountyBelowMinimum);
+    }
+    require!(won || !guards.require_win, KillError::StrikeWouldLose);
 
     // PDA signer seeds — the game_config PDA signs on behalf of the vault
     let config_bump = ctx.accounts.game_config.bump;
@@ -216,22 +368,82 @@ pub fn handler(
     }
 
     // ── Update stacks ──────────────────────────────────────────────────────────
-    // Defender: subtract Lanchester loss (all units if attacker won)
+    // Defender: subtract Lanchester loss (all units if attacker won); locked
+    // forces defend too and are lost once the unlocked ones are gone
     let defender = &mut ctx.accounts.defender_stack;
-    defender.units   = defender.units.saturating_sub(def_u_lost);
-    defender.reapers = defender.reapers.saturating_sub(def_r_lost);
+    defender.remove_losses(def_u_lost, def_r_lost);
 
     // Attacker: subtract sent, add back survivors (rem = 0 if lost, = sent if won)
     let attacker = &mut ctx.accounts.attacker_stack;
     attacker.units   = attacker.units.saturating_sub(sent_units) + rem_units;
     attacker.reapers = attacker.reapers.saturating_sub(sent_reapers) + rem_reapers;
 
+    // ── Agent profiles ─────────────────────────────────────────────────────────
+    let atk_profile = &mut ctx.accounts.attacker_profile;
+    init_profile_if_new(atk_profile, ctx.accounts.attacker.key(), ctx.bumps.attacker_profile);
+    atk_profile.bounty_as_attacker = atk_profile.bounty_as_attacker.saturating_add(atk_payout);
+    atk_profile.units_lost = atk_profile.units_lost.saturating_add(atk_u_lost);
+    atk_profile.reapers_lost = atk_profile.reapers_lost.saturating_add(atk_r_lost);
+    if won {
+        atk_profile.kills_won = atk_profile.kills_won.saturating_add(1);
+    }
+
+    let def_profile = &mut ctx.accounts.defender_profile;
+    init_profile_if_new(def_profile, ctx.accounts.defender.key(), ctx.bumps.defender_profile);
+    def_profile.bounty_as_defender = def_profile.bounty_as_defender.saturating_add(def_payout);
+    def_profile.units_lost = def_profile.units_lost.saturating_add(def_u_lost);
+    def_profile.reapers_lost = def_profile.reapers_lost.saturating_add(def_r_lost);
+
+    // ── Leaderboard ────────────────────────────────────────────────────────────
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    if atk_payout > 0 {
+        let total = atk_profile
+            .bounty_as_attacker
+            .saturating_add(atk_profile.bounty_as_defender);
+        leaderboard.record(atk_profile.agent, total);
+    }
+    if def_payout > 0 {
+        let total = def_profile
+            .bounty_as_attacker
+            .saturating_add(def_profile.bounty_as_defender);
+        leaderboard.record(def_profile.agent, total);
+    }
+
+    // ── Season standings ───────────────────────────────────────────────────────
+    let season = &mut ctx.accounts.season;
+    let atk_standing = &mut ctx.accounts.attacker_standing;
+    init_standing_if_new(atk_standing, season, ctx.accounts.attacker.key(), ctx.bumps.attacker_standing);
+    atk_standing.bounty_earned = atk_standing.bounty_earned.saturating_add(atk_payout);
+    atk_standing.units_lost = atk_standing.units_lost.saturating_add(atk_u_lost);
+    atk_standing.reapers_lost = atk_standing.reapers_lost.saturating_add(atk_r_lost);
+    if won {
+        atk_standing.kills_won = atk_standing.kills_won.saturating_add(1);
+        season.total_kills = season.total_kills.saturating_add(1);
+    }
+
+    let def_standing = &mut ctx.accounts.defender_standing;
+    init_standing_if_new(def_standing, season, ctx.accounts.defender.key(), ctx.bumps.defender_standing);
+    def_standing.bounty_earned = def_standing.bounty_earned.saturating_add(def_payout);
+    def_standing.units_lost = def_standing.units_lost.saturating_add(def_u_lost);
+    def_standing.reapers_lost = def_standing.reapers_lost.saturating_add(def_r_lost);
+
+    // ── Global counters ────────────────────────────────────────────────────────
+    let config = &mut ctx.accounts.game_config;
     if won {
         attacker.kill_slot = current_slot;
-        // ── Global kill counter (attacker wins only) ───────────────────────────
-        ctx.accounts.game_config.total_kills =
-            ctx.accounts.game_config.total_kills.saturating_add(1);
+        // Kill count tracks attacker wins only
+        config.total_kills = config.total_kills.saturating_add(1);
     }
+    config.total_units_killed = config
+        .total_units_killed
+        .saturating_add(atk_u_lost.saturating_add(def_u_lost));
+    config.total_reapers_killed = config
+        .total_reapers_killed
+        .saturating_add(atk_r_lost.saturating_add(def_r_lost));
+    config.total_kill_extracted = config
+        .total_kill_extracted
+        .saturating_add(atk_payout.saturating_add(def_payout));
+    config.total_kill_burned = config.total_kill_burned.saturating_add(total_burn);
 
     emit!(KillEvent {
         attacker: ctx.accounts.attacker.key(),
@@ -254,5 +466,104 @@ pub fn handler(
         defender_reapers_lost: def_r_lost,
     });
 
+    // ── Occupancy index ────────────────────────────────────────────────────────
+    let payer = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let atk_occupied =
+        ctx.accounts.attacker_stack.units > 0 || ctx.accounts.attacker_stack.reapers > 0;
+    let def_occupied =
+        ctx.accounts.defender_stack.units > 0 || ctx.accounts.defender_stack.reapers > 0;
+    sync_cell_index(
+        &mut ctx.accounts.cell_index,
+        ctx.accounts.attacker.key(),
+        atk_occupied,
+        &payer,
+        &system_program,
+    )?;
+    sync_cell_index(
+        &mut ctx.accounts.cell_index,
+        ctx.accounts.defender.key(),
+        def_occupied,
+        &payer,
+        &system_program,
+    )?;
+    ctx.accounts.cell_index.stack_id = defender_stack_id;
+    ctx.accounts.cell_index.bump = ctx.bumps.cell_index;
+    ctx.accounts.cell_index.season = ctx.accounts.season.id;
+
+    if close_empty {
+        if !atk_occupied {
+            close_empty_stack(
+                &ctx.accounts.attacker_stack,
+                &ctx.accounts.attacker.to_account_info(),
+                current_slot,
+            )?;
+        }
+        if !def_occupied {
+            close_empty_stack(
+                &ctx.accounts.defender_stack,
+                &ctx.accounts.defender.to_account_info(),
+                current_slot,
+            )?;
+        }
+    }
+
+    emit!(ctx.accounts.game_config.global_stats(current_slot));
+
     Ok(())
 }
+
+/// Second phase of a committed strike (see `commit_kill`).
+///
+/// Must land after the commit slot and within KILL_REVEAL_WINDOW_SLOTS of it.
+/// The revealed target and amounts must hash to the commitment and fit within
+/// the committed units.  The lock on the attacker's stack is released, the
+/// commitment closes (rent to the attacker) and the strike resolves exactly
+/// like `kill` — so it fails if the stack lost the forces meanwhile.
+pub fn reveal_kill(
+    ctx: Context<Kill>,
+    attacker_stack_id: u16,
+    defender: Pubkey,
+    sent_units: u64,
+    sent_reapers: u64,
+    salt: [u8; 32],
+    guards: KillGuards,
+) -> Result<()> {
+    let current_slot = Clock::get()?.slot;
+    let commitment = ctx
+        .accounts
+        .commitment
+        .take()
+        .ok_or(KillError::CommitmentMismatch)?;
+    require_keys_eq!(defender, ctx.accounts.defender.key(), KillError::CommitmentMismatch);
+    require!(current_slot > commitment.commit_slot, KillError::RevealTooEarly);
+    require!(
+        current_slot <= commitment.commit_slot.saturating_add(KILL_REVEAL_WINDOW_SLOTS),
+        KillError::RevealWindowClosed
+    );
+    require!(
+        kill_commitment_hash(attacker_stack_id, &defender, sent_units, sent_reapers, &salt)
+            == commitment.hash,
+        KillError::CommitmentMismatch
+    );
+    require!(
+        sent_units <= commitment.units && sent_reapers <= commitment.reapers,
+        KillError::CommitmentMismatch
+    );
+
+    // Release the lock, then fight as a normal kill.
+    ctx.accounts
+        .attacker_stack
+        .unlock(commitment.units, commitment.reapers);
+    commitment.close(ctx.accounts.attacker.to_account_info())?;
+
+    handler(
+        ctx,
+        attacker_stack_id,
+        attacker_stack_id,
+        sent_units,
+        sent_reapers,
+        false,
+        guards,
+    )
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::errors::KillError;
+use crate::state::{AgentStack, CellIndex, GameConfig, GameParams, StackMigrated};
+
+use super::{load_padded, sync_cell_index};
+
+/// Permissionless crank: move one stack created before seasons existed into
+/// the current season.
+///
+/// Those stacks live at the unseasoned seeds [b"agent_stack", agent,
+/// stack_id] in the original 75-byte layout, which no other instruction
+/// loads.  Their units, reapers, spawn and kill slots carry over to the
+/// agent's stack on the same cell this season — merged into it (blended with
+/// `blend_spawn_slot`) if the agent already holds one — and the stack is
+/// listed in its CellIndex.  The legacy account is closed and its rent goes
+/// to the agent; the caller pays for any new accounts.
+#[derive(Accounts)]
+#[instruction(stack_id: u16)]
+pub struct MigrateStack<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.current_season > 0 @ KillError::SeasonInactive,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"game_params"],
+        bump = game_params.bump,
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    /// CHECK: The original, shorter layout — loaded with `load_padded`.
+    /// Seeds pin the address.
+    /// Seeds: [b"agent_stack", agent, stack_id as [u8;2] LE]
+    #[account(
+        mut,
+        seeds = [b"agent_stack".as_ref(), agent.key().as_ref(), &stack_id.to_le_bytes()],
+        bump,
+    )]
+    pub legacy_stack: UncheckedAccount<'info>,
+
+    /// The agent's stack on this cell in the current season.
+    /// Seeds: [b"agent_stack", current_season LE, agent, stack_id LE]
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AgentStack::SPACE,
+        seeds = [
+            b"agent_stack".as_ref(),
+            &game_config.current_season.to_le_bytes(),
+            agent.key().as_ref(),
+            &stack_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub agent_stack: Account<'info, AgentStack>,
+
+    /// Occupancy index for this grid position in the current season.
+    /// Seeds: [b"cell_index", current_season LE, stack_id LE]
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CellIndex::space_for(CELL_INDEX_GROWTH),
+        seeds = [
+            b"cell_index".as_ref(),
+            &game_config.current_season.to_le_bytes(),
+            &stack_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub cell_index: Account<'info, CellIndex>,
+
+    /// CHECK: The stack owner — both stack addresses derive from it, and it
+    /// receives the legacy account's rent.
+    #[account(mut)]
+    pub agent: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateStack>, stack_id: u16) -> Result<()> {
+    require!(stack_id <= MAX_STACK_ID, KillError::InvalidStackId);
+    let current_slot = Clock::get()?.slot;
+    let agent = ctx.accounts.agent.key();
+    let season = ctx.accounts.game_config.current_season;
+
+    let info = ctx.accounts.legacy_stack.to_account_info();
+    let (legacy, _) = load_padded::<AgentStack>(&info, AgentStack::SPACE)?;
+    require_keys_eq!(legacy.agent, agent, KillError::Unauthorized);
+
+    let stack = &mut ctx.accounts.agent_stack;
+    let occupied = stack.units > 0 || stack.reapers > 0;
+    if !occupied {
+        stack.agent = agent;
+        stack.stack_id = stack_id;
+        stack.spawn_slot = legacy.spawn_slot;
+        stack.kill_slot = legacy.kill_slot;
+        stack.bump = ctx.bumps.agent_stack;
+        stack.season = season;
+        stack.reaper_progress = 0;
+    }
+    let blend = ctx.accounts.game_params.blend_spawn_slot && occupied;
+    stack
+        .add_forces(legacy.units, legacy.reapers, legacy.spawn_slot, blend)
+        .ok_or(KillError::Overflow)?;
+    let listed = stack.units > 0 || stack.reapers > 0;
+
+    sync_cell_index(
+        &mut ctx.accounts.cell_index,
+        agent,
+        listed,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    ctx.accounts.cell_index.stack_id = stack_id;
+    ctx.accounts.cell_index.bump = ctx.bumps.cell_index;
+    ctx.accounts.cell_index.season = season;
+
+    // Close the legacy account by hand (UncheckedAccount has no `close =`).
+    let rent_returned = info.lamports();
+    let agent_info = ctx.accounts.agent.to_account_info();
+    **agent_info.try_borrow_mut_lamports()? = agent_info
+        .lamports()
+        .checked_add(rent_returned)
+        .ok_or(KillError::Overflow)?;
+    **info.try_borrow_mut_lamports()? = 0;
+    info.assign(&system_program::ID);
+    info.resize(0)?;
+
+    emit!(StackMigrated {
+        agent,
+        stack_id,
+        season,
+        units: legacy.units,
+        reapers: legacy.reapers,
+        rent_returned,
+        slot: current_slot,
+    });
+    Ok(())
+}
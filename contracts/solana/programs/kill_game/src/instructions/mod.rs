@@ -1,11 +1,28 @@
 pub mod admin;
+pub mod claim_units;
+pub mod close_stack;
+pub mod commit_kill;
+pub mod delegation;
 pub mod initialize;
 pub mod kill;
+pub mod migrate_stack;
 pub mod move_units;
+pub mod quote;
+pub mod season;
 pub mod spawn;
+pub mod spawn_batch;
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Burn, Transfer};
+use solana_keccak_hasher as keccak;
 
 use crate::constants::*;
-use crate::state::AgentStack;
+use crate::errors::KillError;
+use crate::state::{
+    AgentProfile, AgentStack, CellIndex, Delegation, GameParams, KillQuote, Season, SeasonStanding,
+    StackClosed,
+};
 
 // ── Shared helpers ─────────────────────────────────────────────────────────────
 
@@ -24,100 +41,394 @@ pub fn is_adjacent(a: u16, b: u16) -> bool {
     (ax - bx).abs() + (ay - by).abs() + (az - bz).abs() == 1
 }
 
-/// Power decay percentage for a stack based on its age in slots.
-///
-/// Mirrors the bounty multiplier in reverse: at mult=1 (fresh) a stack fights at
-/// 100% power; at mult=50 (~3 days) it fights at 5% power (MIN_DECAY_PCT).
+/// Bounty multiplier for a stack based on its age in slots.
 ///
-///   mult       = clamp(1 + age_slots / SLOTS_PER_MULTIPLIER, 1, MAX_MULTIPLIER)
-///   decay_pct  = max(MIN_DECAY_PCT, 100 - (mult - 1) × 95 / 49)
-///
-/// Returns a value in [5, 100] inclusive.  Moving to an empty stack resets
-/// spawn_slot (handled in move_units), which restores full combat power.
-pub fn power_decay_pct(spawn_slot: u64, current_slot: u64) -> u64 {
-    let age = current_slot.saturating_sub(spawn_slot);
-    let mult = (1u64 + age / SLOTS_PER_MULTIPLIER).min(MAX_MULTIPLIER);
-    let decay = 100u64.saturating_sub((mult - 1).saturating_mul(95) / 49);
-    decay.max(MIN_DECAY_PCT)
+///   mult = clamp(1 + age_slots / slots_per_multiplier, 1, max_multiplier)
+pub fn age_multiplier(params: &GameParams, spawn_slot: u64, current_slot: u64) -> u64 {
+    kill_math::age_multiplier(&params.math(), spawn_slot, current_slot)
+}
+
+/// Fill in the identity fields of an AgentProfile created by `init_if_needed`.
+/// No-op for a profile that already exists.
+pub fn init_profile_if_new(profile: &mut AgentProfile, agent: Pubkey, bump: u8) {
+    if profile.agent == Pubkey::default() {
+        profile.agent = agent;
+        profile.bump = bump;
+    }
+}
+
+/// Reject gameplay outside the season's [start_slot, end_slot) window or
+/// after it has been ended.
+pub fn require_season_active(season: &Season, slot: u64) -> Result<()> {
+    require!(season.is_active(slot), KillError::SeasonInactive);
+    Ok(())
+}
+
+/// Fill in the identity fields of a SeasonStanding created by
+/// `init_if_needed` and count the agent into the season.  No-op for a
+/// standing that already exists.
+pub fn init_standing_if_new(
+    standing: &mut SeasonStanding,
+    season: &mut Season,
+    agent: Pubkey,
+    bump: u8,
+) {
+    if standing.agent == Pubkey::default() {
+        standing.season = season.id;
+        standing.agent = agent;
+        standing.bump = bump;
+        season.agents = season.agents.saturating_add(1);
+    }
 }
 
-/// Combat resolution matching EVM KillGame.sol `_resolveCombat`, extended with
-/// per-stack power decay.
+/// Close an empty AgentStack, sending its rent to `recipient` (the stack's
+/// owner), and emit StackClosed.  Callers must have delisted the stack from its
+/// CellIndex already.
+pub fn close_empty_stack<'info>(
+    stack: &Account<'info, AgentStack>,
+    recipient: &AccountInfo<'info>,
+    slot: u64,
+) -> Result<()> {
+    require!(stack.units == 0 && stack.reapers == 0, KillError::StackNotEmpty);
+    require_keys_eq!(recipient.key(), stack.agent, KillError::Unauthorized);
+
+    let rent_returned = stack.to_account_info().lamports();
+    stack.close(recipient.clone())?;
+
+    emit!(StackClosed {
+        agent: stack.agent,
+        stack_id: stack.stack_id,
+        rent_returned,
+        slot,
+    });
+    Ok(())
+}
+
+/// Grow a program-owned account to `new_len` bytes, first topping up its rent
+/// exemption from `payer`.  The added tail is zero-filled.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.resize(new_len)?;
+    Ok(())
+}
+
+/// Decode a program-owned `T` that may predate fields appended to its layout:
+/// the data is zero-padded to `space` first, so missing fields read as zero.
+/// Checks owner and discriminator; also returns the account's current length.
 ///
-/// Reapers count as THERMAL_PARITY (666) units each.  Effective power is scaled
-/// by each stack's `decay_pct` (5–100) before the combat comparison and the
-/// Lanchester ratio, so aged stacks fight weaker.  Actual on-chain unit counts
-/// (not effective counts) are returned for all loss/survivor values.
+/// For accounts taken as `UncheckedAccount` because they may be too short
+/// for `Account<T>`.
+pub fn load_padded<T: AccountDeserialize>(account: &AccountInfo, space: usize) -> Result<(T, usize)> {
+    require_keys_eq!(*account.owner, crate::ID, KillError::Unauthorized);
+    let mut data = account.try_borrow_data()?.to_vec();
+    let len = data.len();
+    data.resize(len.max(space), 0);
+    Ok((T::try_deserialize(&mut data.as_slice())?, len))
+}
+
+/// Share of a spawn/move fee that is burned (README tokenomics: 6.66%).
+pub fn fee_burn(fee: u64, burn_bps: u64) -> u64 {
+    kill_math::fee_burn(fee, burn_bps)
+}
+
+/// Collect a spawn/move fee from the agent: `burned` is burned straight from
+/// the agent's token account and the remainder is deposited in the game vault.
 ///
-/// Defender receives a 10% power bonus: compare `atkEff × 10  vs  defEff × 11`.
+/// With `delegation`, the fee is paid by the Delegation PDA acting as SPL
+/// delegate on the agent's token account instead of by `agent`.
+#[allow(clippy::too_many_arguments)]
+pub fn collect_fee<'info>(
+    token_program: &AccountInfo<'info>,
+    agent_token_account: &AccountInfo<'info>,
+    game_vault: &AccountInfo<'info>,
+    kill_mint: &AccountInfo<'info>,
+    agent: &AccountInfo<'info>,
+    delegation: Option<&Account<'info, Delegation>>,
+    fee: u64,
+    burned: u64,
+) -> Result<()> {
+    let deposited = fee.checked_sub(burned).ok_or(KillError::Overflow)?;
+
+    let (authority, owner, bump) = match delegation {
+        Some(d) => (d.to_account_info(), d.owner, d.bump),
+        None => (agent.clone(), Pubkey::default(), 0),
+    };
+    let delegation_seeds: &[&[u8]] = &[b"delegation", owner.as_ref(), &[bump]];
+    let signer_seeds: &[&[&[u8]]] = if delegation.is_some() {
+        &[delegation_seeds]
+    } else {
+        &[]
+    };
+
+    if deposited > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                Transfer {
+                    from: agent_token_account.clone(),
+                    to: game_vault.clone(),
+                    authority: authority.clone(),
+                },
+                signer_seeds,
+            ),
+            deposited,
+        )?;
+    }
+    if burned > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                Burn {
+                    mint: kill_mint.clone(),
+                    from: agent_token_account.clone(),
+                    authority,
+                },
+                signer_seeds,
+            ),
+            burned,
+        )?;
+    }
+    Ok(())
+}
+
+/// Check that `authority` may act for `agent` in an instruction covered by
+/// the `action` delegation flag, spending `spend` KILL.
 ///
-/// Returns `(attacker_won, rem_atk_units, rem_atk_reapers, atk_units_lost, atk_reapers_lost, def_units_lost, def_reapers_lost)`.
-/// - Win:  attacker keeps ALL sent forces.  All defender forces destroyed.
-/// - Loss: attacker loses all sent forces.
-///         Defender suffers Lanchester partial loss using effective power ratio
-///         applied to actual unit counts:
-///         defLost = actual_defCount × (atkEff×10)² / (defEff×11)²
-pub fn resolve_combat(
-    def_units: u64,
-    atk_units: u64,
-    def_reapers: u64,
-    atk_reapers: u64,
-    atk_decay: u64,
-    def_decay: u64,
-) -> (bool, u64, u64, u64, u64, u64, u64) {
-    let def_raw = def_units.saturating_add(def_reapers.saturating_mul(THERMAL_PARITY));
-    let atk_raw = atk_units.saturating_add(atk_reapers.saturating_mul(THERMAL_PARITY));
-
-    // Effective power = raw × decay_pct (kept as ×100 to avoid premature division)
-    // 10% defender bonus: atkEff×10 > defEff×11
-    let atk_eff = (atk_raw as u128).saturating_mul(atk_decay as u128);
-    let def_eff = (def_raw as u128).saturating_mul(def_decay as u128);
-
-    if atk_eff.saturating_mul(10) > def_eff.saturating_mul(11) {
-        // Attacker wins — returns ACTUAL unit counts; winner keeps all sent forces
-        (true, atk_units, atk_reapers, 0, 0, def_units, def_reapers)
+/// The agent itself always may.  Anyone else needs the agent's Delegation
+/// naming them as delegate, unexpired, allowing `action`, with `spend` left
+/// under its cap — which is then consumed.  Returns true when acting through
+/// the delegation (fees must then be paid via `collect_fee`'s `delegation`).
+pub fn authorize_agent(
+    agent: Pubkey,
+    authority: Pubkey,
+    delegation: Option<&mut Account<Delegation>>,
+    action: u8,
+    spend: u64,
+    slot: u64,
+) -> Result<bool> {
+    if authority == agent {
+        return Ok(false);
+    }
+    let delegation = delegation.ok_or(KillError::Unauthorized)?;
+    require_keys_eq!(delegation.owner, agent, KillError::Unauthorized);
+    require_keys_eq!(delegation.delegate, authority, KillError::Unauthorized);
+    require!(slot < delegation.expiry_slot, KillError::DelegationExpired);
+    require!(delegation.allowed & action != 0, KillError::DelegationNotAllowed);
+
+    let spent = delegation.spent.checked_add(spend).ok_or(KillError::Overflow)?;
+    require!(spent <= delegation.spend_cap, KillError::DelegationCapExceeded);
+    delegation.spent = spent;
+    Ok(true)
+}
+
+/// Create a program-owned PDA by hand — the `init` path for accounts passed as
+/// remaining accounts.  Like Anchor's `init`, tolerates an address that was
+/// pre-funded with lamports.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer: &[&[&[u8]]] = &[signer_seeds];
+    if account.lamports() == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
     } else {
-        // Defender wins — attacker loses all sent forces
-        // Lanchester partial loss using effective power ratio on ACTUAL def counts:
-        //   defLost = actual_defCount × (atkEff×10)² / (defEff×11)²
-        let atk_p = atk_eff.saturating_mul(10);
-        let def_p = def_eff.saturating_mul(11);
-        let p_sq = atk_p.saturating_mul(atk_p);
-        let d_sq = def_p.saturating_mul(def_p);
-
-        let def_u_lost = if d_sq == 0 {
-            0u64
-        } else {
-            ((def_units as u128).saturating_mul(p_sq) / d_sq)
-                .min(def_units as u128) as u64
-        };
-        let def_r_lost = if d_sq == 0 {
-            0u64
-        } else {
-            ((def_reapers as u128).saturating_mul(p_sq) / d_sq)
-                .min(def_reapers as u128) as u64
-        };
+        let top_up = rent.saturating_sub(account.lamports());
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                signer,
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Assign {
+                    account_to_assign: account.clone(),
+                },
+                signer,
+            ),
+            &crate::ID,
+        )?;
+    }
+    Ok(())
+}
 
-        (false, 0, 0, atk_units, atk_reapers, def_u_lost, def_r_lost)
+/// Bring a cell's occupant list in line with one agent's stack: listed while
+/// `occupied`, removed once the stack is empty.  Growing the list past the
+/// account's capacity reallocates it, with `payer` covering the extra rent.
+///
+/// Also heals stacks that predate CellIndex — they are listed the next time
+/// they are touched.
+pub fn sync_cell_index<'info>(
+    cell: &mut Account<'info, CellIndex>,
+    agent: Pubkey,
+    occupied: bool,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let pos = cell.occupants.iter().position(|k| *k == agent);
+    match (occupied, pos) {
+        (true, None) => {
+            cell.occupants.push(agent);
+            let info = cell.to_account_info();
+            if info.data_len() < CellIndex::space_for(cell.occupants.len()) {
+                let slots = cell.occupants.len().next_multiple_of(CELL_INDEX_GROWTH);
+                grow_account(&info, CellIndex::space_for(slots), payer, system_program)?;
+            }
+        }
+        (false, Some(i)) => {
+            cell.occupants.swap_remove(i);
+        }
+        _ => {}
     }
+    Ok(())
+}
+
+/// Hash binding a committed strike to its reveal:
+/// keccak256(attacker_stack_id LE ‖ defender ‖ sent_units LE ‖ sent_reapers LE ‖ salt).
+pub fn kill_commitment_hash(
+    attacker_stack_id: u16,
+    defender: &Pubkey,
+    sent_units: u64,
+    sent_reapers: u64,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    keccak::hashv(&[
+        &attacker_stack_id.to_le_bytes(),
+        defender.as_ref(),
+        &sent_units.to_le_bytes(),
+        &sent_reapers.to_le_bytes(),
+        salt,
+    ])
+    .to_bytes()
+}
+
+/// Verify an OpenZeppelin-style Merkle proof (sorted-pair keccak256).
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
 }
 
-/// Calculate the bounty owed for a defender stack based on its age in slots.
+/// Power decay percentage for a stack based on its age in slots.
 ///
-/// Matches EVM KillGame.sol getPendingBounty():
-///   power      = units + reapers × THERMAL_PARITY
-///   multiplier = clamp(1 + age_slots / SLOTS_PER_MULTIPLIER, 1, MAX_MULTIPLIER)
-///   raw_bounty = power × SPAWN_COST × multiplier
-///   cap        = vault_amount × GLOBAL_CAP_BPS / BPS_DENOM  (25% of treasury)
-///   bounty     = min(raw_bounty, cap)
-pub fn get_pending_bounty(stack: &AgentStack, current_slot: u64, vault_amount: u64) -> u64 {
-    if stack.units == 0 && stack.reapers == 0 {
-        return 0;
+/// Mirrors the bounty multiplier in reverse: at mult=1 (fresh) a stack fights at
+/// 100% power; at mult=max_multiplier (~3 days by default) it fights at 5% power
+/// (MIN_DECAY_PCT).  See `kill_math::power_decay_pct`.
+/// Moving to an empty stack resets spawn_slot (handled in move_units), which
+/// restores full combat power.  With `GameParams::blend_spawn_slot`,
+/// reinforcements and merges shift spawn_slot by their share of power, so the
+/// decay tracks the age of the capital rather than of the stack.
+pub fn power_decay_pct(params: &GameParams, spawn_slot: u64, current_slot: u64) -> u64 {
+    kill_math::power_decay_pct(&params.math(), spawn_slot, current_slot)
+}
+
+/// Bounty owed for destroying `stack` outright (EVM getPendingBounty parity) —
+/// see `kill_math::get_pending_bounty`.
+pub fn get_pending_bounty(
+    stack: &AgentStack,
+    params: &GameParams,
+    current_slot: u64,
+    vault_amount: u64,
+) -> u64 {
+    kill_math::get_pending_bounty(&stack.math(), &params.math(), current_slot, vault_amount)
+}
+
+/// Full outcome of `attacker` sending `sent_units`/`sent_reapers` against
+/// `defender` at `current_slot`: power decay, combat (EVM `_resolveCombat`
+/// with decay), and the EVM `_applyRewards` bounty split with burn.  Used by
+/// `kill` to settle and by `quote_kill` to preview, so the two cannot drift;
+/// the formulas live in `kill_math::strike`.
+pub fn quote_strike(
+    params: &GameParams,
+    attacker: &AgentStack,
+    defender: &AgentStack,
+    sent_units: u64,
+    sent_reapers: u64,
+    vault_amount: u64,
+    current_slot: u64,
+) -> KillQuote {
+    let strike = kill_math::strike(
+        &params.math(),
+        &attacker.math(),
+        &defender.math(),
+        sent_units,
+        sent_reapers,
+        vault_amount,
+        current_slot,
+    );
+    let combat = strike.combat;
+    KillQuote {
+        attacker_decay_pct: strike.attacker_decay_pct,
+        defender_decay_pct: strike.defender_decay_pct,
+        defender_power: strike.defender_power,
+        won: combat.attacker_won,
+        remaining_units: combat.remaining_units,
+        remaining_reapers: combat.remaining_reapers,
+        attacker_units_lost: combat.attacker_units_lost,
+        attacker_reapers_lost: combat.attacker_reapers_lost,
+        defender_units_lost: combat.defender_units_lost,
+        defender_reapers_lost: combat.defender_reapers_lost,
+        pending_bounty: strike.pending_bounty,
+        battle_pool: strike.bounty.battle_pool,
+        attacker_bounty: strike.bounty.attacker_payout,
+        defender_bounty: strike.bounty.defender_payout,
+        total_burned: strike.bounty.total_burn(),
+        slot: current_slot,
     }
-    let age_slots = current_slot.saturating_sub(stack.spawn_slot);
-    let mult = (1u64 + age_slots / SLOTS_PER_MULTIPLIER).min(MAX_MULTIPLIER);
-    let power = stack.units.saturating_add(stack.reapers.saturating_mul(THERMAL_PARITY));
-    let raw = power.saturating_mul(SPAWN_COST).saturating_mul(mult);
-    let cap = vault_amount.saturating_mul(GLOBAL_CAP_BPS) / BPS_DENOM;
-    if cap == 0 { raw } else { raw.min(cap) }
 }
@@ -1,33 +1,67 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::KillError;
-use crate::state::{AgentStack, GameConfig, StackMoved};
+use crate::state::{
+    AgentProfile, AgentStack, CellIndex, Delegation, GameConfig, GameParams, Season,
+    SeasonStanding, StackMoved,
+};
 
-use super::is_adjacent;
+use super::{
+    authorize_agent, close_empty_stack, collect_fee, fee_burn, init_profile_if_new, init_standing_if_new,
+    is_adjacent, require_season_active, sync_cell_index,
+};
 
 /// Move a specified number of units/reapers from one stack to an adjacent stack.
 ///
 /// Equivalent to the EVM `move(fromStack, toStack, units, reaper)` function.
-/// Costs MOVE_COST KILL tokens.  Only the specified amounts are moved — partial
+/// Costs `move_cost` KILL tokens (`burn_bps` burned, the rest to the vault).  Only the specified amounts are moved — partial
 /// moves are supported (EVM parity).  Only adjacent moves are allowed
 /// (Manhattan distance = 1 in the 6×6×6 grid).
 /// If the destination was empty, spawn_slot is reset to the current slot (1× multiplier).
+/// Merging into an occupied stack keeps its spawn_slot, unless `blend_spawn_slot`
+/// is set in GameParams: then it becomes the power-weighted average of both
+/// stacks' spawn slots.
+/// With `close_empty`, a source stack left empty is closed and its rent returned.
+/// Signed by the agent or by its delegate (see `set_delegation`).
 #[derive(Accounts)]
 #[instruction(from_stack_id: u16, to_stack_id: u16)]
 pub struct MoveUnits<'info> {
     #[account(
+        mut,
         seeds = [b"game_config"],
         bump = game_config.bump,
         constraint = !game_config.paused @ KillError::GamePaused,
+        constraint = !game_config.is_paused(PAUSE_MOVE) @ KillError::MovePaused,
     )]
     pub game_config: Account<'info, GameConfig>,
 
+    /// Economy parameters — spawn/move cost, multiplier curve, burn and reaper rates.
+    #[account(
+        seeds = [b"game_params"],
+        bump = game_params.bump,
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    /// Current season — gameplay is only accepted while it is active.
+    /// Seeds: [b"season", game_config.current_season as [u8;4] little-endian]
+    #[account(
+        mut,
+        seeds = [b"season".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
     /// Source stack — must be owned by the signer and non-empty.
     #[account(
         mut,
-        seeds = [b"agent_stack", agent.key().as_ref(), &from_stack_id.to_le_bytes()],
+        seeds = [
+            b"agent_stack".as_ref(),
+            &season.id.to_le_bytes(),
+            agent.key().as_ref(),
+            &from_stack_id.to_le_bytes(),
+        ],
         bump = from_stack.bump,
         constraint = from_stack.agent == agent.key(),
         constraint = (from_stack.units > 0 || from_stack.reapers > 0) @ KillError::EmptyAttacker,
@@ -37,13 +71,62 @@ pub struct MoveUnits<'info> {
     /// Destination stack — created if it does not yet exist for this agent at this position.
     #[account(
         init_if_needed,
-        payer = agent,
+        payer = authority,
         space = AgentStack::SPACE,
-        seeds = [b"agent_stack", agent.key().as_ref(), &to_stack_id.to_le_bytes()],
+        seeds = [
+            b"agent_stack".as_ref(),
+            &season.id.to_le_bytes(),
+            agent.key().as_ref(),
+            &to_stack_id.to_le_bytes(),
+        ],
         bump
     )]
     pub to_stack: Account<'info, AgentStack>,
 
+    /// Occupancy index for the source cell — the agent is delisted if the source empties.
+    /// Seeds: [b"cell_index", season.id as [u8;4] LE, stack_id as [u8;2] LE]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CellIndex::space_for(CELL_INDEX_GROWTH),
+        seeds = [b"cell_index".as_ref(), &season.id.to_le_bytes(), &from_stack_id.to_le_bytes()],
+        bump
+    )]
+    pub from_cell: Account<'info, CellIndex>,
+
+    /// Occupancy index for the destination cell — the agent is listed on arrival.
+    /// Seeds: [b"cell_index", season.id as [u8;4] LE, stack_id as [u8;2] LE]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CellIndex::space_for(CELL_INDEX_GROWTH),
+        seeds = [b"cell_index".as_ref(), &season.id.to_le_bytes(), &to_stack_id.to_le_bytes()],
+        bump
+    )]
+    pub to_cell: Account<'info, CellIndex>,
+
+    /// Agent's lifetime P&L record.  Created on first use.
+    /// Seeds: [b"agent_profile", agent.key()]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AgentProfile::SPACE,
+        seeds = [b"agent_profile", agent.key().as_ref()],
+        bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    /// Agent's results for the current season.  Created on first use.
+    /// Seeds: [b"season_standing", season.id as [u8;4] little-endian, agent.key()]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SeasonStanding::SPACE,
+        seeds = [b"season_standing".as_ref(), &season.id.to_le_bytes(), agent.key().as_ref()],
+        bump
+    )]
+    pub season_standing: Account<'info, SeasonStanding>,
+
     /// Agent's KILL token account — move cost is debited from here.
     #[account(
         mut,
@@ -52,17 +135,37 @@ pub struct MoveUnits<'info> {
     )]
     pub agent_token_account: Account<'info, TokenAccount>,
 
-    /// Game vault — receives the move cost.
+    /// Game vault — receives the move cost net of the burn.
     #[account(
         mut,
         constraint = game_vault.key() == game_config.game_vault,
     )]
     pub game_vault: Account<'info, TokenAccount>,
 
+    /// KILL mint — needed by the token program's Burn CPI for the fee burn.
+    #[account(
+        mut,
+        constraint = kill_mint.key() == game_config.kill_mint,
+    )]
     pub kill_mint: Account<'info, Mint>,
 
+    /// CHECK: The wallet the stacks belong to.  Must sign itself or be the
+    /// owner of `delegation`; checked in the handler by `authorize_agent`.
+    /// Writable to receive the rent of a closed source stack.
     #[account(mut)]
-    pub agent: Signer<'info>,
+    pub agent: UncheckedAccount<'info>,
+
+    /// Agent's session key — required only when `authority` is a delegate.
+    #[account(
+        mut,
+        seeds = [b"delegation", agent.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// The agent or its delegate — signs and pays rent.
+    #[account(mut)]
+    pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -74,51 +177,81 @@ pub fn handler(
     to_stack_id: u16,
     units: u64,
     reapers: u64,
+    close_empty: bool,
 ) -> Result<()> {
     require!(from_stack_id <= MAX_STACK_ID, KillError::InvalidStackId);
     require!(to_stack_id <= MAX_STACK_ID, KillError::InvalidStackId);
     require!(is_adjacent(from_stack_id, to_stack_id), KillError::NotAdjacent);
     require!(units > 0 || reapers > 0, KillError::EmptyAttacker);
+    // Forces locked by a pending commit_kill stay put
+    let (free_units, free_reapers) = ctx.accounts.from_stack.unlocked();
     require!(
-        units <= ctx.accounts.from_stack.units
-            && reapers <= ctx.accounts.from_stack.reapers,
+        units <= free_units && reapers <= free_reapers,
         KillError::InsufficientBalance
     );
+    let current_slot = Clock::get()?.slot;
+    require_season_active(&ctx.accounts.season, current_slot)?;
 
-    // Pay move cost
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.agent_token_account.to_account_info(),
-                to: ctx.accounts.game_vault.to_account_info(),
-                authority: ctx.accounts.agent.to_account_info(),
-            },
-        ),
-        MOVE_COST,
+    // Pay move cost: burn share burned, remainder → vault
+    let move_cost = ctx.accounts.game_params.move_cost;
+    let burned = fee_burn(move_cost, ctx.accounts.game_params.burn_bps);
+    let delegated = authorize_agent(
+        ctx.accounts.agent.key(),
+        ctx.accounts.authority.key(),
+        ctx.accounts.delegation.as_mut(),
+        DELEGATE_MOVE,
+        move_cost,
+        current_slot,
+    )?;
+    collect_fee(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.agent_token_account.to_account_info(),
+        &ctx.accounts.game_vault.to_account_info(),
+        &ctx.accounts.kill_mint.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        ctx.accounts.delegation.as_ref().filter(|_| delegated),
+        move_cost,
+        burned,
     )?;
 
-    let current_slot = Clock::get()?.slot;
+    let config = &mut ctx.accounts.game_config;
+    config.total_kill_added = config.total_kill_added.saturating_add(move_cost);
+    config.total_kill_burned = config.total_kill_burned.saturating_add(burned);
+
+    let profile = &mut ctx.accounts.agent_profile;
+    init_profile_if_new(profile, ctx.accounts.agent.key(), ctx.bumps.agent_profile);
+    profile.kill_spent = profile.kill_spent.saturating_add(move_cost);
+
+    let standing = &mut ctx.accounts.season_standing;
+    init_standing_if_new(
+        standing,
+        &mut ctx.accounts.season,
+        ctx.accounts.agent.key(),
+        ctx.bumps.season_standing,
+    );
+    standing.kill_spent = standing.kill_spent.saturating_add(move_cost);
 
     // Deduct from source (partial move — source may retain units)
     let from = &mut ctx.accounts.from_stack;
     from.units = from.units.saturating_sub(units);
     from.reapers = from.reapers.saturating_sub(reapers);
+    let from_spawn_slot = from.spawn_slot;
 
-    // Merge into destination — initialize metadata on first occupation
+    // Merge into destination — initialize metadata on first occupation.  With
+    // blend_spawn_slot, merging into an occupied stack averages in the moved
+    // forces' spawn slot by power.
     let to = &mut ctx.accounts.to_stack;
+    let blend = ctx.accounts.game_params.blend_spawn_slot && (to.units > 0 || to.reapers > 0);
     if to.units == 0 && to.reapers == 0 {
         to.agent = ctx.accounts.agent.key();
         to.stack_id = to_stack_id;
         to.spawn_slot = current_slot;
         to.kill_slot = 0;
         to.bump = ctx.bumps.to_stack;
+        to.season = ctx.accounts.season.id;
+        to.reaper_progress = 0;
     }
-    to.units = to.units.checked_add(units).ok_or(KillError::Overflow)?;
-    to.reapers = to
-        .reapers
-        .checked_add(reapers)
-        .ok_or(KillError::Overflow)?;
+    to.add_forces(units, reapers, from_spawn_slot, blend).ok_or(KillError::Overflow)?;
 
     emit!(StackMoved {
         agent: ctx.accounts.agent.key(),
@@ -127,7 +260,33 @@ pub fn handler(
         units,
         reapers,
         slot: current_slot,
+        deposited: move_cost - burned,
+        burned,
     });
 
+    // ── Occupancy index ────────────────────────────────────────────────────────
+    let agent = ctx.accounts.agent.key();
+    let payer = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let from_occupied = ctx.accounts.from_stack.units > 0 || ctx.accounts.from_stack.reapers > 0;
+    sync_cell_index(&mut ctx.accounts.from_cell, agent, from_occupied, &payer, &system_program)?;
+    sync_cell_index(&mut ctx.accounts.to_cell, agent, true, &payer, &system_program)?;
+    ctx.accounts.from_cell.stack_id = from_stack_id;
+    ctx.accounts.from_cell.bump = ctx.bumps.from_cell;
+    ctx.accounts.to_cell.stack_id = to_stack_id;
+    ctx.accounts.to_cell.bump = ctx.bumps.to_cell;
+    ctx.accounts.from_cell.season = ctx.accounts.season.id;
+    ctx.accounts.to_cell.season = ctx.accounts.season.id;
+
+    if close_empty && !from_occupied {
+        close_empty_stack(
+            &ctx.accounts.from_stack,
+            &ctx.accounts.agent.to_account_info(),
+            current_slot,
+        )?;
+    }
+
+    emit!(ctx.accounts.game_config.global_stats(current_slot));
+
     Ok(())
 }
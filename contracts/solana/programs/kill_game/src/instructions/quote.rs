@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::KillError;
+use crate::state::{AgentStack, BountyQuote, GameConfig, GameParams, KillQuote};
+
+use super::{age_multiplier, get_pending_bounty, power_decay_pct, quote_strike};
+
+// ── Quote kill ────────────────────────────────────────────────────────────────
+
+/// Read-only preview of a strike between two live stacks.
+///
+/// Runs the same math as `kill` at the current slot and returns the result
+/// as `KillQuote` return data; nothing is written.  Intended for
+/// `simulateTransaction` — no signer besides the fee payer is required.
+#[derive(Accounts)]
+pub struct QuoteKill<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"game_params"],
+        bump = game_params.bump,
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    pub attacker_stack: Account<'info, AgentStack>,
+
+    #[account(
+        constraint = defender_stack.stack_id == attacker_stack.stack_id @ KillError::NotSameStack,
+        constraint = defender_stack.season == attacker_stack.season @ KillError::NotSameStack,
+        constraint = (defender_stack.units > 0 || defender_stack.reapers > 0) @ KillError::EmptyDefender,
+        constraint = defender_stack.agent != attacker_stack.agent @ KillError::SelfAttack,
+    )]
+    pub defender_stack: Account<'info, AgentStack>,
+
+    /// Bounty vault — its balance caps the pending bounty.
+    #[account(constraint = game_vault.key() == game_config.game_vault)]
+    pub game_vault: Account<'info, TokenAccount>,
+}
+
+pub fn quote_kill(ctx: Context<QuoteKill>, sent_units: u64, sent_reapers: u64) -> Result<KillQuote> {
+    let attacker = &ctx.accounts.attacker_stack;
+    require!(sent_units > 0 || sent_reapers > 0, KillError::EmptyAttacker);
+    let (free_units, free_reapers) = attacker.unlocked();
+    require!(
+        sent_units <= free_units && sent_reapers <= free_reapers,
+        KillError::InsufficientBalance
+    );
+
+    Ok(quote_strike(
+        &ctx.accounts.game_params,
+        attacker,
+        &ctx.accounts.defender_stack,
+        sent_units,
+        sent_reapers,
+        ctx.accounts.game_vault.amount,
+        Clock::get()?.slot,
+    ))
+}
+
+// ── Quote bounty ──────────────────────────────────────────────────────────────
+
+/// Read-only view of one stack's age multiplier, power decay and pending
+/// bounty at the current slot, returned as `BountyQuote` return data.
+#[derive(Accounts)]
+pub struct QuoteBounty<'info> {
+    #[account(
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"game_params"],
+        bump = game_params.bump,
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    pub stack: Account<'info, AgentStack>,
+
+    /// Bounty vault — its balance caps the pending bounty.
+    #[account(constraint = game_vault.key() == game_config.game_vault)]
+    pub game_vault: Account<'info, TokenAccount>,
+}
+
+pub fn quote_bounty(ctx: Context<QuoteBounty>) -> Result<BountyQuote> {
+    let params = &ctx.accounts.game_params;
+    let stack = &ctx.accounts.stack;
+    let current_slot = Clock::get()?.slot;
+
+    Ok(BountyQuote {
+        agent: stack.agent,
+        stack_id: stack.stack_id,
+        power: kill_math::power(stack.units, stack.reapers),
+        multiplier: age_multiplier(params, stack.spawn_slot, current_slot),
+        decay_pct: power_decay_pct(params, stack.spawn_slot, current_slot),
+        pending_bounty: get_pending_bounty(stack, params, current_slot, ctx.accounts.game_vault.amount),
+        slot: current_slot,
+    })
+}
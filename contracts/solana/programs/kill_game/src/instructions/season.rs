@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::KillError;
+use crate::state::{
+    GameConfig, Season, SeasonEndChanged, SeasonEnded, SeasonResult, SeasonStanding, SeasonStarted,
+};
+
+// ── Start season ──────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct StartSeason<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.params_authority == params_authority.key() @ KillError::Unauthorized,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// The next season — `current_season + 1`.
+    /// Seeds: [b"season", id as [u8;4] little-endian]
+    #[account(
+        init,
+        payer = params_authority,
+        space = Season::SPACE,
+        seeds = [b"season".as_ref(), &(game_config.current_season + 1).to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(mut)]
+    pub params_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open the next season — params authority only.  The previous season must
+/// be past its end slot.  Stacks and cells of the new season start empty;
+/// older seasons' accounts stay readable and closable.
+pub fn start_season(ctx: Context<StartSeason>, start_slot: u64, end_slot: u64) -> Result<()> {
+    let current_slot = Clock::get()?.slot;
+    let config = &mut ctx.accounts.game_config;
+    require!(current_slot >= config.season_end_slot, KillError::SeasonNotOver);
+    require!(
+        end_slot > start_slot && end_slot > current_slot,
+        KillError::InvalidParams
+    );
+
+    config.current_season += 1;
+    config.season_end_slot = end_slot;
+
+    let season = &mut ctx.accounts.season;
+    season.id = config.current_season;
+    season.start_slot = start_slot;
+    season.end_slot = end_slot;
+    season.ended = false;
+    season.total_kills = 0;
+    season.agents = 0;
+    season.results_recorded = 0;
+    season.bump = ctx.bumps.season;
+
+    emit!(SeasonStarted {
+        season: season.id,
+        start_slot,
+        end_slot,
+        slot: current_slot,
+    });
+    Ok(())
+}
+
+// ── Set season end ────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct SetSeasonEnd<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = game_config.params_authority == params_authority.key() @ KillError::Unauthorized,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"season".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    pub params_authority: Signer<'info>,
+}
+
+/// Move the current season's end slot — params authority only.  Extends a
+/// running season or cuts it short (down to the current slot); a season past
+/// its end slot is final.  Emits SeasonEndChanged.
+pub fn set_season_end(ctx: Context<SetSeasonEnd>, end_slot: u64) -> Result<()> {
+    let current_slot = Clock::get()?.slot;
+    let season = &mut ctx.accounts.season;
+    require!(
+        !season.ended && current_slot < season.end_slot,
+        KillError::SeasonInactive
+    );
+    require!(
+        end_slot >= season.start_slot && end_slot >= current_slot,
+        KillError::InvalidParams
+    );
+
+    let old_end_slot = season.end_slot;
+    season.end_slot = end_slot;
+    ctx.accounts.game_config.season_end_slot = end_slot;
+
+    emit!(SeasonEndChanged {
+        season: season.id,
+        old_end_slot,
+        end_slot,
+        slot: current_slot,
+    });
+    Ok(())
+}
+
+// ── End season ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct EndSeason<'info> {
+    #[account(
+        mut,
+        seeds = [b"season".as_ref(), &season_id.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+}
+
+/// Permissionless crank: freeze a season once its end slot has passed and
+/// record final standings.
+///
+/// The first call marks the season ended and emits SeasonEnded.  Pass any
+/// number of the season's SeasonStanding accounts (writable) as remaining
+/// accounts; each is marked final and emits one SeasonResult.  Call again with
+/// further standings until `results_recorded == agents`.  Already-final
+/// standings are skipped, so repeated calls are harmless.
+pub fn end_season<'info>(
+    ctx: Context<'_, '_, 'info, 'info, EndSeason<'info>>,
+    season_id: u32,
+) -> Result<()> {
+    let current_slot = Clock::get()?.slot;
+    let season = &mut ctx.accounts.season;
+    require!(current_slot >= season.end_slot, KillError::SeasonNotOver);
+
+    if !season.ended {
+        season.ended = true;
+        emit!(SeasonEnded {
+            season: season_id,
+            total_kills: season.total_kills,
+            agents: season.agents,
+            slot: current_slot,
+        });
+    }
+
+    for info in ctx.remaining_accounts.iter() {
+        let mut standing: Account<'info, SeasonStanding> = Account::try_from(info)?;
+        require!(standing.season == season_id, KillError::InvalidRemainingAccounts);
+        if standing.finalized {
+            continue;
+        }
+        standing.finalized = true;
+        season.results_recorded = season.results_recorded.saturating_add(1);
+
+        emit!(SeasonResult {
+            season: season_id,
+            agent: standing.agent,
+            kill_spent: standing.kill_spent,
+            bounty_earned: standing.bounty_earned,
+            units_lost: standing.units_lost,
+            reapers_lost: standing.reapers_lost,
+            kills_won: standing.kills_won,
+            slot: current_slot,
+        });
+        standing.exit(&crate::ID)?;
+    }
+    Ok(())
+}
@@ -1,39 +1,112 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::KillError;
-use crate::state::{AgentStack, GameConfig, StackSpawned};
+use crate::state::{
+    AgentProfile, AgentStack, CellIndex, Delegation, GameConfig, GameParams, Season,
+    SeasonStanding, StackSpawned,
+};
+
+use super::{
+    authorize_agent, collect_fee, fee_burn, init_profile_if_new, init_standing_if_new,
+    require_season_active, sync_cell_index,
+};
 
 /// Spawn or reinforce a stack at a given grid position.
 ///
-/// Costs SPAWN_COST KILL tokens **per unit**, transferred to the game vault.
+/// Costs `spawn_cost` KILL tokens **per unit**: `burn_bps` of the fee is burned,
+/// the rest is transferred to the game vault.
 /// Reapers cannot be spawned explicitly — one free Reaper is granted automatically
-/// for every REAPER_THRESHOLD (666) units spawned in this call.
+/// for every `reaper_threshold` (666 by default) units spawned onto the stack,
+/// counted across calls (`AgentStack::reaper_progress` carries the remainder).
 /// If an AgentStack PDA already exists for this agent+position, units/reapers are
 /// added to it (reinforcement). Otherwise a new stack account is created.
+/// Reinforcing keeps the stack's `spawn_slot`, unless `blend_spawn_slot` is set
+/// in GameParams: then it becomes the power-weighted average of the old slot
+/// and the current one, so new capital does not inherit the stack's age.
+///
+/// Signed by the agent or by its delegate (see `set_delegation`); the signer
+/// pays any rent, the stack and the KILL debit stay with the agent.
 #[derive(Accounts)]
 #[instruction(stack_id: u16, units: u64)]
 pub struct Spawn<'info> {
-    /// Game config — validates the game is not paused and provides vault address.
+    /// Game config — validates the game is not paused, provides vault address
+    /// and accumulates the global KILL-added counter.
     #[account(
+        mut,
         seeds = [b"game_config"],
         bump = game_config.bump,
         constraint = !game_config.paused @ KillError::GamePaused,
+        constraint = !game_config.is_paused(PAUSE_SPAWN) @ KillError::SpawnPaused,
     )]
     pub game_config: Account<'info, GameConfig>,
 
+    /// Economy parameters — spawn/move cost, multiplier curve, burn and reaper rates.
+    #[account(
+        seeds = [b"game_params"],
+        bump = game_params.bump,
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    /// Current season — gameplay is only accepted while it is active.
+    /// Seeds: [b"season", game_config.current_season as [u8;4] little-endian]
+    #[account(
+        mut,
+        seeds = [b"season".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
     /// The agent's stack at this position.  Created on first spawn; updated on reinforcement.
-    /// Seeds: [b"agent_stack", agent.key(), stack_id as [u8;2] little-endian]
+    /// Seeds: [b"agent_stack", season.id as [u8;4] LE, agent.key(), stack_id as [u8;2] LE]
     #[account(
         init_if_needed,
-        payer = agent,
+        payer = authority,
         space = AgentStack::SPACE,
-        seeds = [b"agent_stack", agent.key().as_ref(), &stack_id.to_le_bytes()],
+        seeds = [
+            b"agent_stack".as_ref(),
+            &season.id.to_le_bytes(),
+            agent.key().as_ref(),
+            &stack_id.to_le_bytes(),
+        ],
         bump
     )]
     pub agent_stack: Account<'info, AgentStack>,
 
+    /// Occupancy index for this grid position — the agent is listed once spawned.
+    /// Seeds: [b"cell_index", season.id as [u8;4] LE, stack_id as [u8;2] LE]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CellIndex::space_for(CELL_INDEX_GROWTH),
+        seeds = [b"cell_index".as_ref(), &season.id.to_le_bytes(), &stack_id.to_le_bytes()],
+        bump
+    )]
+    pub cell_index: Account<'info, CellIndex>,
+
+    /// Agent's lifetime P&L record.  Created on first use.
+    /// Seeds: [b"agent_profile", agent.key()]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AgentProfile::SPACE,
+        seeds = [b"agent_profile", agent.key().as_ref()],
+        bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    /// Agent's results for the current season.  Created on first use.
+    /// Seeds: [b"season_standing", season.id as [u8;4] little-endian, agent.key()]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SeasonStanding::SPACE,
+        seeds = [b"season_standing".as_ref(), &season.id.to_le_bytes(), agent.key().as_ref()],
+        bump
+    )]
+    pub season_standing: Account<'info, SeasonStanding>,
+
     /// Agent's KILL token account — spawn cost is debited from here.
     #[account(
         mut,
@@ -42,17 +115,35 @@ pub struct Spawn<'info> {
     )]
     pub agent_token_account: Account<'info, TokenAccount>,
 
-    /// Game vault — receives the spawn cost.
+    /// Game vault — receives the spawn cost net of the burn.
     #[account(
         mut,
         constraint = game_vault.key() == game_config.game_vault,
     )]
     pub game_vault: Account<'info, TokenAccount>,
 
+    /// KILL mint — needed by the token program's Burn CPI for the fee burn.
+    #[account(
+        mut,
+        constraint = kill_mint.key() == game_config.kill_mint,
+    )]
     pub kill_mint: Account<'info, Mint>,
 
+    /// CHECK: The wallet the stack belongs to.  Must sign itself or be the
+    /// owner of `delegation`; checked in the handler by `authorize_agent`.
+    pub agent: UncheckedAccount<'info>,
+
+    /// Agent's session key — required only when `authority` is a delegate.
+    #[account(
+        mut,
+        seeds = [b"delegation", agent.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// The agent or its delegate — signs and pays rent.
     #[account(mut)]
-    pub agent: Signer<'info>,
+    pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -61,23 +152,51 @@ pub struct Spawn<'info> {
 pub fn handler(ctx: Context<Spawn>, stack_id: u16, units: u64) -> Result<()> {
     require!(stack_id <= MAX_STACK_ID, KillError::InvalidStackId);
     require!(units > 0, KillError::EmptyAttacker);
-
-    // Debit units × SPAWN_COST from agent → vault
-    let cost = units.checked_mul(SPAWN_COST).ok_or(KillError::Overflow)?;
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.agent_token_account.to_account_info(),
-                to: ctx.accounts.game_vault.to_account_info(),
-                authority: ctx.accounts.agent.to_account_info(),
-            },
-        ),
+    let current_slot = Clock::get()?.slot;
+    require_season_active(&ctx.accounts.season, current_slot)?;
+
+    let params = &ctx.accounts.game_params;
+
+    // Debit units × spawn_cost from agent: burn share burned, remainder → vault
+    let cost = units.checked_mul(params.spawn_cost).ok_or(KillError::Overflow)?;
+    let burned = fee_burn(cost, params.burn_bps);
+    let delegated = authorize_agent(
+        ctx.accounts.agent.key(),
+        ctx.accounts.authority.key(),
+        ctx.accounts.delegation.as_mut(),
+        DELEGATE_SPAWN,
+        cost,
+        current_slot,
+    )?;
+    collect_fee(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.agent_token_account.to_account_info(),
+        &ctx.accounts.game_vault.to_account_info(),
+        &ctx.accounts.kill_mint.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        ctx.accounts.delegation.as_ref().filter(|_| delegated),
         cost,
+        burned,
     )?;
 
+    let config = &mut ctx.accounts.game_config;
+    config.total_kill_added = config.total_kill_added.saturating_add(cost);
+    config.total_kill_burned = config.total_kill_burned.saturating_add(burned);
+
+    let profile = &mut ctx.accounts.agent_profile;
+    init_profile_if_new(profile, ctx.accounts.agent.key(), ctx.bumps.agent_profile);
+    profile.kill_spent = profile.kill_spent.saturating_add(cost);
+
+    let standing = &mut ctx.accounts.season_standing;
+    init_standing_if_new(
+        standing,
+        &mut ctx.accounts.season,
+        ctx.accounts.agent.key(),
+        ctx.bumps.season_standing,
+    );
+    standing.kill_spent = standing.kill_spent.saturating_add(cost);
+
     let stack = &mut ctx.accounts.agent_stack;
-    let current_slot = Clock::get()?.slot;
 
     // On first creation, initialize metadata fields.
     // `init_if_needed` re-uses the account when it already exists, so we only
@@ -88,15 +207,15 @@ pub fn handler(ctx: Context<Spawn>, stack_id: u16, units: u64) -> Result<()> {
         stack.spawn_slot = current_slot;
         stack.kill_slot = 0;
         stack.bump = ctx.bumps.agent_stack;
+        stack.season = ctx.accounts.season.id;
+        stack.reaper_progress = 0;
     }
 
-    // One free Reaper per REAPER_THRESHOLD units spawned in this call.
-    let auto_reapers = units / REAPER_THRESHOLD;
-
-    stack.units = stack.units.checked_add(units).ok_or(KillError::Overflow)?;
-    stack.reapers = stack
-        .reapers
-        .checked_add(auto_reapers)
+    // One free Reaper per reaper_threshold units spawned onto this stack,
+    // counted across reinforcements.  With blend_spawn_slot, reinforcing pulls
+    // spawn_slot toward the current slot by the new forces' share of power.
+    let spawned_reapers = stack
+        .add_spawned_units(units, &ctx.accounts.game_params, current_slot)
         .ok_or(KillError::Overflow)?;
 
     emit!(StackSpawned {
@@ -105,7 +224,24 @@ pub fn handler(ctx: Context<Spawn>, stack_id: u16, units: u64) -> Result<()> {
         units: stack.units,
         reapers: stack.reapers,
         slot: current_slot,
+        deposited: cost - burned,
+        burned,
+        spawned_units: units,
+        spawned_reapers,
     });
 
+    sync_cell_index(
+        &mut ctx.accounts.cell_index,
+        ctx.accounts.agent.key(),
+        true,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    ctx.accounts.cell_index.stack_id = stack_id;
+    ctx.accounts.cell_index.bump = ctx.bumps.cell_index;
+    ctx.accounts.cell_index.season = ctx.accounts.season.id;
+
+    emit!(ctx.accounts.game_config.global_stats(current_slot));
+
     Ok(())
 }
@@ -0,0 +1,317 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::KillError;
+use crate::state::{
+    AgentProfile, AgentStack, CellIndex, Delegation, GameConfig, GameParams, Season,
+    SeasonStanding, StackSpawned,
+};
+
+use super::{
+    authorize_agent, collect_fee, create_pda_account, fee_burn, init_profile_if_new, init_standing_if_new,
+    require_season_active, sync_cell_index,
+};
+
+/// One cell of a `spawn_batch` deployment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SpawnEntry {
+    pub stack_id: u16,
+    pub units: u64,
+}
+
+/// Spawn or reinforce many stacks in one instruction.
+///
+/// Same rules as `spawn`, applied per entry, but the whole deployment is paid
+/// with one aggregate fee of Σunits × `spawn_cost` (one burn, one transfer).
+/// The burn is the sum of the per-cell burns, so every StackSpawned event
+/// reports exactly what its cell contributed.  For each entry, in
+/// order, the caller passes two writable remaining accounts:
+///
+///   [agent_stack PDA for (season, agent, stack_id), cell_index PDA for (season, stack_id)]
+///
+/// Missing accounts are created (the signer pays rent).  Stack IDs must be unique
+/// within a batch, and a batch holds at most MAX_SPAWN_BATCH entries.  Only
+/// new accounts pay for a bump search; existing ones are checked against
+/// their stored bump.
+#[derive(Accounts)]
+pub struct SpawnBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_config"],
+        bump = game_config.bump,
+        constraint = !game_config.paused @ KillError::GamePaused,
+        constraint = !game_config.is_paused(PAUSE_SPAWN) @ KillError::SpawnPaused,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Economy parameters — spawn cost and reaper rate.
+    #[account(
+        seeds = [b"game_params"],
+        bump = game_params.bump,
+    )]
+    pub game_params: Account<'info, GameParams>,
+
+    /// Current season — gameplay is only accepted while it is active.
+    /// Seeds: [b"season", game_config.current_season as [u8;4] little-endian]
+    #[account(
+        mut,
+        seeds = [b"season".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Agent's lifetime P&L record.  Created on first use.
+    /// Seeds: [b"agent_profile", agent.key()]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AgentProfile::SPACE,
+        seeds = [b"agent_profile", agent.key().as_ref()],
+        bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    /// Agent's results for the current season.  Created on first use.
+    /// Seeds: [b"season_standing", season.id as [u8;4] little-endian, agent.key()]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SeasonStanding::SPACE,
+        seeds = [b"season_standing".as_ref(), &season.id.to_le_bytes(), agent.key().as_ref()],
+        bump
+    )]
+    pub season_standing: Account<'info, SeasonStanding>,
+
+    /// Agent's KILL token account — the aggregate cost is debited from here.
+    #[account(
+        mut,
+        constraint = agent_token_account.owner == agent.key(),
+        constraint = agent_token_account.mint == game_config.kill_mint,
+    )]
+    pub agent_token_account: Account<'info, TokenAccount>,
+
+    /// Game vault — receives the aggregate cost net of the burn.
+    #[account(
+        mut,
+        constraint = game_vault.key() == game_config.game_vault,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
+
+    /// KILL mint — needed by the token program's Burn CPI for the fee burn.
+    #[account(
+        mut,
+        constraint = kill_mint.key() == game_config.kill_mint,
+    )]
+    pub kill_mint: Account<'info, Mint>,
+
+    /// CHECK: The wallet the stack belongs to.  Must sign itself or be the
+    /// owner of `delegation`; checked in the handler by `authorize_agent`.
+    pub agent: UncheckedAccount<'info>,
+
+    /// Agent's session key — required only when `authority` is a delegate.
+    #[account(
+        mut,
+        seeds = [b"delegation", agent.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// The agent or its delegate — signs and pays rent.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SpawnBatch<'info>>,
+    entries: Vec<SpawnEntry>,
+) -> Result<()> {
+    require!(!entries.is_empty(), KillError::EmptyAttacker);
+    require!(entries.len() <= MAX_SPAWN_BATCH, KillError::BatchTooLarge);
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * 2,
+        KillError::InvalidRemainingAccounts
+    );
+    let current_slot = Clock::get()?.slot;
+    require_season_active(&ctx.accounts.season, current_slot)?;
+
+    let params = &ctx.accounts.game_params;
+    let spawn_cost = params.spawn_cost;
+    let burn_bps = params.burn_bps;
+
+    // Validate every entry and total the fee before touching any account.
+    let mut seen = [false; MAX_STACK_ID as usize + 1];
+    let mut cost = 0u64;
+    let mut burned = 0u64;
+    for entry in &entries {
+        require!(entry.stack_id <= MAX_STACK_ID, KillError::InvalidStackId);
+        require!(entry.units > 0, KillError::EmptyAttacker);
+        require!(!seen[entry.stack_id as usize], KillError::DuplicateStackId);
+        seen[entry.stack_id as usize] = true;
+        let cell_cost = entry
+            .units
+            .checked_mul(spawn_cost)
+            .ok_or(KillError::Overflow)?;
+        cost = cost.checked_add(cell_cost).ok_or(KillError::Overflow)?;
+        burned += fee_burn(cell_cost, burn_bps);
+    }
+
+    // One aggregate debit of Σunits × spawn_cost: burn share burned, remainder → vault
+    let delegated = authorize_agent(
+        ctx.accounts.agent.key(),
+        ctx.accounts.authority.key(),
+        ctx.accounts.delegation.as_mut(),
+        DELEGATE_SPAWN,
+        cost,
+        current_slot,
+    )?;
+    collect_fee(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.agent_token_account.to_account_info(),
+        &ctx.accounts.game_vault.to_account_info(),
+        &ctx.accounts.kill_mint.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        ctx.accounts.delegation.as_ref().filter(|_| delegated),
+        cost,
+        burned,
+    )?;
+
+    let config = &mut ctx.accounts.game_config;
+    config.total_kill_added = config.total_kill_added.saturating_add(cost);
+    config.total_kill_burned = config.total_kill_burned.saturating_add(burned);
+
+    let agent = ctx.accounts.agent.key();
+    let profile = &mut ctx.accounts.agent_profile;
+    init_profile_if_new(profile, agent, ctx.bumps.agent_profile);
+    profile.kill_spent = profile.kill_spent.saturating_add(cost);
+
+    let standing = &mut ctx.accounts.season_standing;
+    init_standing_if_new(standing, &mut ctx.accounts.season, agent, ctx.bumps.season_standing);
+    standing.kill_spent = standing.kill_spent.saturating_add(cost);
+
+    let season = ctx.accounts.season.id;
+    let season_bytes = season.to_le_bytes();
+    let payer = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    for (entry, pair) in entries.iter().zip(ctx.remaining_accounts.chunks_exact(2)) {
+        let id_bytes = entry.stack_id.to_le_bytes();
+
+        // ── Agent stack ────────────────────────────────────────────────────────
+        let stack_info = &pair[0];
+        let stack_seeds: [&[u8]; 4] = [b"agent_stack", &season_bytes, agent.as_ref(), &id_bytes];
+        let stack_created = stack_info.owner != &crate::ID;
+        if stack_created {
+            let stack_bump = find_pda_bump(stack_info, &stack_seeds)?;
+            create_pda_account(
+                stack_info,
+                AgentStack::SPACE,
+                &[b"agent_stack", &season_bytes, agent.as_ref(), &id_bytes, &[stack_bump]],
+                &payer,
+                &system_program,
+            )?;
+            AgentStack {
+                agent,
+                stack_id: entry.stack_id,
+                units: 0,
+                reapers: 0,
+                spawn_slot: current_slot,
+                kill_slot: 0,
+                bump: stack_bump,
+                season,
+                reaper_progress: 0,
+                locked_units: 0,
+                locked_reapers: 0,
+            }
+            .try_serialize(&mut &mut stack_info.try_borrow_mut_data()?[..])?;
+        }
+        let mut stack = Account::<AgentStack>::try_from(stack_info)?;
+        if !stack_created {
+            check_pda_bump(stack_info, &stack_seeds, stack.bump)?;
+        }
+
+        if stack.units == 0 && stack.reapers == 0 {
+            stack.agent = agent;
+            stack.stack_id = entry.stack_id;
+            stack.spawn_slot = current_slot;
+            stack.kill_slot = 0;
+            stack.season = season;
+            stack.reaper_progress = 0;
+        }
+
+        // This cell's share of the aggregate fee, for the event.
+        let cell_cost = entry.units * spawn_cost;
+        let cell_burned = fee_burn(cell_cost, burn_bps);
+
+        // One free Reaper per reaper_threshold units spawned on this cell,
+        // counted across reinforcements.
+        let spawned_reapers = stack
+            .add_spawned_units(entry.units, params, current_slot)
+            .ok_or(KillError::Overflow)?;
+        stack.exit(&crate::ID)?;
+
+        emit!(StackSpawned {
+            agent,
+            stack_id: entry.stack_id,
+            units: stack.units,
+            reapers: stack.reapers,
+            slot: current_slot,
+            deposited: cell_cost - cell_burned,
+            burned: cell_burned,
+            spawned_units: entry.units,
+            spawned_reapers,
+        });
+
+        // ── Cell index ─────────────────────────────────────────────────────────
+        let cell_info = &pair[1];
+        let cell_seeds: [&[u8]; 3] = [b"cell_index", &season_bytes, &id_bytes];
+        let cell_created = cell_info.owner != &crate::ID;
+        if cell_created {
+            let cell_bump = find_pda_bump(cell_info, &cell_seeds)?;
+            create_pda_account(
+                cell_info,
+                CellIndex::space_for(CELL_INDEX_GROWTH),
+                &[b"cell_index", &season_bytes, &id_bytes, &[cell_bump]],
+                &payer,
+                &system_program,
+            )?;
+            CellIndex {
+                stack_id: entry.stack_id,
+                bump: cell_bump,
+                season,
+                occupants: Vec::new(),
+            }
+            .try_serialize(&mut &mut cell_info.try_borrow_mut_data()?[..])?;
+        }
+        let mut cell = Account::<CellIndex>::try_from(cell_info)?;
+        if !cell_created {
+            check_pda_bump(cell_info, &cell_seeds, cell.bump)?;
+        }
+        sync_cell_index(&mut cell, agent, true, &payer, &system_program)?;
+        cell.exit(&crate::ID)?;
+    }
+
+    emit!(ctx.accounts.game_config.global_stats(current_slot));
+
+    Ok(())
+}
+
+/// Canonical bump of the PDA for `seeds`, which must be `info`'s address.
+fn find_pda_bump(info: &AccountInfo, seeds: &[&[u8]]) -> Result<u8> {
+    let (key, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(info.key(), key, KillError::InvalidRemainingAccounts);
+    Ok(bump)
+}
+
+/// Check `info` is the PDA for `seeds` at the bump stored in the account —
+/// one hash instead of a bump search.  Every account this program creates
+/// stores its canonical bump.
+fn check_pda_bump(info: &AccountInfo, seeds: &[&[u8]], bump: u8) -> Result<()> {
+    let key = Pubkey::create_program_address(&[seeds, &[&[bump]]].concat(), &crate::ID)
+        .map_err(|_| error!(KillError::InvalidRemainingAccounts))?;
+    require_keys_eq!(info.key(), key, KillError::InvalidRemainingAccounts);
+    Ok(())
+}
@@ -6,10 +6,19 @@ pub mod instructions;
 pub mod state;
 
 use instructions::admin::*;
+use instructions::claim_units::*;
+use instructions::close_stack::*;
+use instructions::commit_kill::*;
+use instructions::delegation::*;
 use instructions::initialize::*;
 use instructions::kill::*;
+use instructions::migrate_stack::*;
 use instructions::move_units::*;
+use instructions::quote::*;
+use instructions::season::*;
 use instructions::spawn::*;
+use instructions::spawn_batch::*;
+use state::{BountyQuote, KillQuote};
 
 // PLACEHOLDER — after first `anchor build`, run:
 //   anchor keys list
@@ -27,44 +36,270 @@ pub mod kill_game {
     }
 
     /// Spawn or reinforce a stack at a grid position (0–215).
-    /// Costs SPAWN_COST KILL tokens per unit → vault.
-    /// One free Reaper is granted per 666 units spawned.
+    /// Costs `spawn_cost` KILL tokens per unit: `burn_bps` of the fee is burned,
+    /// the rest goes to the vault.
+    /// One free Reaper is granted per `reaper_threshold` (666) units spawned onto
+    /// the stack, counted across reinforcements.  With `blend_spawn_slot`,
+    /// reinforcing averages the stack's spawn slot with the current one by power.
     pub fn spawn(ctx: Context<Spawn>, stack_id: u16, units: u64) -> Result<()> {
         instructions::spawn::handler(ctx, stack_id, units)
     }
 
+    /// Spawn or reinforce many grid positions in one instruction with a single
+    /// aggregate KILL fee (one burn, one transfer).  Per entry, pass [agent_stack, cell_index] as
+    /// remaining accounts.  Emits one StackSpawned per cell.
+    pub fn spawn_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SpawnBatch<'info>>,
+        entries: Vec<SpawnEntry>,
+    ) -> Result<()> {
+        instructions::spawn_batch::handler(ctx, entries)
+    }
+
     /// Move a specified number of units/reapers from one adjacent grid position to another.
-    /// Partial moves are supported (EVM parity).  Costs MOVE_COST KILL tokens → vault.
+    /// Partial moves are supported (EVM parity).  Costs `move_cost` KILL tokens,
+    /// split between burn and vault like the spawn fee.
+    /// With `close_empty`, a source stack left empty is closed and its rent returned.
+    /// With `blend_spawn_slot`, merging into an occupied stack averages both
+    /// spawn slots by power.
     pub fn move_units(
         ctx: Context<MoveUnits>,
         from_stack_id: u16,
         to_stack_id: u16,
         units: u64,
         reapers: u64,
+        close_empty: bool,
     ) -> Result<()> {
-        instructions::move_units::handler(ctx, from_stack_id, to_stack_id, units, reapers)
+        instructions::move_units::handler(ctx, from_stack_id, to_stack_id, units, reapers, close_empty)
     }
 
     /// Attack an adjacent enemy stack.
     /// If the attacker wins, bounty is paid out and a portion burned.
     /// If the attacker loses, their stack is cleared with no reward.
+    /// With `close_empty`, any stack wiped out by the fight is closed and its
+    /// rent returned to its owner.  `guards` revert the strike when the
+    /// defender is stronger, the bounty smaller, or the outcome a loss
+    /// compared to what the attacker expected.
     pub fn kill(
         ctx: Context<Kill>,
         attacker_stack_id: u16,
         defender_stack_id: u16,
         sent_units: u64,
         sent_reapers: u64,
+        close_empty: bool,
+        guards: KillGuards,
+    ) -> Result<()> {
+        instructions::kill::handler(
+            ctx,
+            attacker_stack_id,
+            defender_stack_id,
+            sent_units,
+            sent_reapers,
+            close_empty,
+            guards,
+        )
+    }
+
+    /// Commit to a secret strike: lock `units`/`reapers` on the attacker's
+    /// stack and store the hash of (stack id, defender, sent units, sent
+    /// reapers, salt).  Reveal with `reveal_kill` within the reveal window.
+    pub fn commit_kill(
+        ctx: Context<CommitKill>,
+        attacker_stack_id: u16,
+        units: u64,
+        reapers: u64,
+        hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_kill::commit_kill(ctx, attacker_stack_id, units, reapers, hash)
+    }
+
+    /// Reveal and execute a committed strike.  Takes the `kill` accounts plus
+    /// the commitment; resolves exactly like `kill`.
+    pub fn reveal_kill(
+        ctx: Context<Kill>,
+        attacker_stack_id: u16,
+        defender: Pubkey,
+        sent_units: u64,
+        sent_reapers: u64,
+        salt: [u8; 32],
+        guards: KillGuards,
     ) -> Result<()> {
-        instructions::kill::handler(ctx, attacker_stack_id, defender_stack_id, sent_units, sent_reapers)
+        instructions::kill::reveal_kill(
+            ctx,
+            attacker_stack_id,
+            defender,
+            sent_units,
+            sent_reapers,
+            salt,
+            guards,
+        )
+    }
+
+    /// Permissionless: release the forces locked by an expired, unrevealed
+    /// commitment.
+    pub fn refund_commit(ctx: Context<RefundCommit>) -> Result<()> {
+        instructions::commit_kill::refund_commit(ctx)
     }
 
-    /// Admin: pause or unpause all gameplay instructions.
+    /// View: preview a strike against live stacks at the current slot.
+    /// Returns `KillQuote` via return data — call with `simulateTransaction`.
+    pub fn quote_kill(ctx: Context<QuoteKill>, sent_units: u64, sent_reapers: u64) -> Result<KillQuote> {
+        instructions::quote::quote_kill(ctx, sent_units, sent_reapers)
+    }
+
+    /// View: a stack's multiplier, power decay and pending bounty at the
+    /// current slot.  Returns `BountyQuote` via return data.
+    pub fn quote_bounty(ctx: Context<QuoteBounty>) -> Result<BountyQuote> {
+        instructions::quote::quote_bounty(ctx)
+    }
+
+    /// Claim airdropped units onto a stack with a Merkle proof of eligibility.
+    /// One claim per wallet (EVM `claim` parity, without the auto-attack).
+    pub fn claim_units(ctx: Context<ClaimUnits>, proof: Vec<[u8; 32]>, stack_id: u16) -> Result<()> {
+        instructions::claim_units::handler(ctx, proof, stack_id)
+    }
+
+    /// Close one of the signer's empty stacks and reclaim its rent.  Stacks
+    /// from an ended season can be closed whatever they hold.
+    pub fn close_stack(ctx: Context<CloseStack>, season_id: u32, stack_id: u16) -> Result<()> {
+        instructions::close_stack::handler(ctx, season_id, stack_id)
+    }
+
+    /// Authorize a hot key to sign spawn/move_units/kill for the signer until
+    /// `expiry_slot`, limited to the `DELEGATE_*` flags in `allowed` and
+    /// `spend_cap` KILL of fees.  Replaces any existing delegation.
+    pub fn set_delegation(
+        ctx: Context<SetDelegation>,
+        delegate: Pubkey,
+        expiry_slot: u64,
+        allowed: u8,
+        spend_cap: u64,
+    ) -> Result<()> {
+        instructions::delegation::set_delegation(ctx, delegate, expiry_slot, allowed, spend_cap)
+    }
+
+    /// Revoke the signer's delegation and reclaim its rent.
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::delegation::revoke_delegation(ctx)
+    }
+
+    /// Permissionless: move a stack created before seasons existed (unseasoned
+    /// seeds, original layout) into the current season and close the old
+    /// account.  Legacy stacks must be migrated before they can be played.
+    pub fn migrate_stack(ctx: Context<MigrateStack>, stack_id: u16) -> Result<()> {
+        instructions::migrate_stack::handler(ctx, stack_id)
+    }
+
+    /// Pauser: pause or unpause all gameplay instructions.
     pub fn set_paused(ctx: Context<AdminConfig>, paused: bool) -> Result<()> {
         instructions::admin::set_paused(ctx, paused)
     }
 
-    /// Admin: emergency withdrawal from the game vault.
-    pub fn admin_withdraw(ctx: Context<AdminWithdraw>, amount: u64) -> Result<()> {
-        instructions::admin::withdraw(ctx, amount)
+    /// Pauser: pause individual instructions with a bitmask of `PAUSE_*` flags
+    /// (spawn, move, kill, claim).  Pass 0 to clear.
+    pub fn set_pause_mask(ctx: Context<AdminConfig>, pause_mask: u8) -> Result<()> {
+        instructions::admin::set_pause_mask(ctx, pause_mask)
+    }
+
+    /// Admin: propose a new admin.  Control moves only once the proposed key
+    /// signs `accept_admin`.  Proposing the default pubkey cancels.
+    pub fn propose_admin(ctx: Context<AdminAuthority>, new_admin: Pubkey) -> Result<()> {
+        instructions::admin::propose_admin(ctx, new_admin)
+    }
+
+    /// Pending admin: accept the handover proposed by `propose_admin`.
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::admin::accept_admin(ctx)
+    }
+
+    /// Admin: assign the pauser role (pause/unpause).
+    pub fn set_pauser(ctx: Context<AdminAuthority>, pauser: Pubkey) -> Result<()> {
+        instructions::admin::set_pauser(ctx, pauser)
+    }
+
+    /// Admin: assign the treasurer role (vault withdrawals).
+    pub fn set_treasurer(ctx: Context<AdminAuthority>, treasurer: Pubkey) -> Result<()> {
+        instructions::admin::set_treasurer(ctx, treasurer)
+    }
+
+    /// Admin: assign the params authority role (set_config, set_merkle_root).
+    pub fn set_params_authority(ctx: Context<AdminAuthority>, params_authority: Pubkey) -> Result<()> {
+        instructions::admin::set_params_authority(ctx, params_authority)
+    }
+
+    /// Admin: create the GameParams PDA seeded with the default economy.
+    pub fn initialize_params(ctx: Context<InitializeParams>) -> Result<()> {
+        instructions::admin::initialize_params(ctx)
+    }
+
+    /// Admin: create the Leaderboard PDA ranking agents by bounty received.
+    pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+        instructions::admin::initialize_leaderboard(ctx)
+    }
+
+    /// Params authority: retune the economy (EVM `setConfig` parity).  Emits ConfigUpdated.
+    pub fn set_config(ctx: Context<AdminParams>, args: GameParamsArgs) -> Result<()> {
+        instructions::admin::set_config(ctx, args)
+    }
+
+    /// Params authority: set the airdrop Merkle root and per-claim KILL value.
+    pub fn set_merkle_root(
+        ctx: Context<AdminAirdrop>,
+        merkle_root: [u8; 32],
+        airdrop_amount: u64,
+    ) -> Result<()> {
+        instructions::admin::set_merkle_root(ctx, merkle_root, airdrop_amount)
+    }
+
+    /// Admin: stage a new vault withdrawal delay (slots) and per-epoch cap;
+    /// they apply once the current delay has passed.
+    pub fn set_withdraw_limits(
+        ctx: Context<AdminAuthority>,
+        delay_slots: u64,
+        epoch_cap: u64,
+    ) -> Result<()> {
+        instructions::admin::set_withdraw_limits(ctx, delay_slots, epoch_cap)
+    }
+
+    /// Treasurer: queue a withdrawal from the game vault.  It can execute once
+    /// `withdraw_delay_slots` have passed.
+    pub fn queue_withdraw(ctx: Context<QueueWithdraw>, amount: u64) -> Result<()> {
+        instructions::admin::queue_withdraw(ctx, amount)
+    }
+
+    /// Treasurer: execute a queued withdrawal, subject to the per-epoch cap.
+    pub fn execute_withdraw(ctx: Context<AdminWithdraw>, id: u64) -> Result<()> {
+        instructions::admin::execute_withdraw(ctx, id)
+    }
+
+    /// Treasurer: cancel a queued withdrawal.
+    pub fn cancel_withdraw(ctx: Context<CancelWithdraw>, id: u64) -> Result<()> {
+        instructions::admin::cancel_withdraw(ctx, id)
+    }
+
+    /// Params authority: open the next season on a fresh grid.  The previous
+    /// season must be past its end slot.
+    pub fn start_season(ctx: Context<StartSeason>, start_slot: u64, end_slot: u64) -> Result<()> {
+        instructions::season::start_season(ctx, start_slot, end_slot)
+    }
+
+    /// Params authority: move the current season's end slot.
+    pub fn set_season_end(ctx: Context<SetSeasonEnd>, end_slot: u64) -> Result<()> {
+        instructions::season::set_season_end(ctx, end_slot)
+    }
+
+    /// Permissionless: freeze a finished season and record final standings.
+    /// Pass the season's SeasonStanding accounts (writable) as remaining accounts.
+    pub fn end_season<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EndSeason<'info>>,
+        season_id: u32,
+    ) -> Result<()> {
+        instructions::season::end_season(ctx, season_id)
+    }
+
+    /// Admin: grow a GameConfig created by an older program version to the
+    /// current layout.  Newly appended counters start zeroed; roles and
+    /// withdrawal limits start at their `initialize_game` defaults.
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::admin::migrate_config(ctx)
     }
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::LEADERBOARD_SIZE;
+
 /// Singleton game configuration — PDA seeds: [b"game_config"]
 ///
 /// There is exactly one of these per deployment. It holds the mint address,
@@ -15,25 +17,220 @@ pub struct GameConfig {
     /// Game vault token account (PDA authority = this account)
     pub game_vault: Pubkey,
 
-    /// Protocol admin wallet (can pause and emergency-withdraw)
+    /// Protocol admin wallet — root authority: assigns the roles below, sets
+    /// withdrawal limits and hands itself over via propose/accept_admin
     pub admin: Pubkey,
 
     /// Lifetime kill count across all agents
     pub total_kills: u64,
 
-    /// If true, spawn/move/kill instructions are rejected
+    /// If true, every gameplay instruction is rejected (global kill switch;
+    /// see `pause_mask` for per-instruction pauses)
     pub paused: bool,
 
     /// Canonical bump used to re-derive this PDA cheaply
     pub bump: u8,
+
+    // ── Global economy counters (EVM GlobalStats parity) ──────────────────────
+    // Appended after `bump` so accounts created before they existed can be
+    // grown in place by `migrate_config`.
+
+    /// Lifetime units destroyed in combat (both sides)
+    pub total_units_killed: u64,
+
+    /// Lifetime reapers destroyed in combat (both sides)
+    pub total_reapers_killed: u64,
+
+    /// Lifetime KILL paid for spawns and moves (including the burned fee share)
+    pub total_kill_added: u64,
+
+    /// Lifetime KILL paid out of the vault (bounties and admin withdrawals)
+    pub total_kill_extracted: u64,
+
+    /// Lifetime KILL burned (spawn/move fee share and bounty burns)
+    pub total_kill_burned: u64,
+
+    /// Admin proposed by `propose_admin`; takes over once it signs
+    /// `accept_admin`.  `Pubkey::default()` when no handover is pending.
+    pub pending_admin: Pubkey,
+
+    // ── Vault withdrawal limits ───────────────────────────────────────────────
+    // Seeded with the constants.rs defaults by `initialize_game` (and by
+    // `migrate_config` for older accounts); retuned via `set_withdraw_limits`.
+
+    /// Slots a queued withdrawal must wait before `execute_withdraw`
+    pub withdraw_delay_slots: u64,
+
+    /// Most KILL (raw) that withdrawals can move out of the vault per epoch
+    pub withdraw_epoch_cap: u64,
+
+    /// Epoch that `withdrawn_in_epoch` refers to
+    pub withdraw_epoch: u64,
+
+    /// KILL withdrawn so far in `withdraw_epoch`
+    pub withdrawn_in_epoch: u64,
+
+    /// Id assigned to the next queued withdrawal
+    pub withdrawal_nonce: u64,
+
+    // ── Roles ─────────────────────────────────────────────────────────────────
+    // Set to `admin` by `initialize_game` (and by `migrate_config` for older
+    // accounts); reassigned by the admin with the per-role setters.
+
+    /// Can pause and unpause gameplay (hot key)
+    pub pauser: Pubkey,
+
+    /// Can queue, execute and cancel vault withdrawals (cold key)
+    pub treasurer: Pubkey,
+
+    /// Can retune GameParams and the airdrop
+    pub params_authority: Pubkey,
+
+    /// Per-instruction pause flags (`PAUSE_*` in constants.rs), set by
+    /// `set_pause_mask`.  Checked in addition to `paused`.
+    pub pause_mask: u8,
+
+    /// Id of the latest season (0 = none started yet).  Gameplay accounts are
+    /// seeded by this id, so each season plays on a fresh grid.
+    pub current_season: u32,
+
+    /// End slot of `current_season` (mirrors Season.end_slot so
+    /// `start_season` can check the previous season is over without loading it)
+    pub season_end_slot: u64,
+
+    // ── Staged withdrawal limits ──────────────────────────────────────────────
+    // `set_withdraw_limits` parks new limits here; they replace the live ones
+    // once `withdraw_limits_effective_slot` is reached, so loosening them
+    // takes at least the current delay.
+
+    /// Delay that takes over at `withdraw_limits_effective_slot`
+    pub staged_withdraw_delay_slots: u64,
+
+    /// Epoch cap that takes over at `withdraw_limits_effective_slot`
+    pub staged_withdraw_epoch_cap: u64,
+
+    /// Slot the staged limits apply from (0 = nothing staged)
+    pub withdraw_limits_effective_slot: u64,
 }
 
 impl GameConfig {
     /// Account discriminator (8) + fields
-    pub const SPACE: usize = 8 + 32 + 32 + 32 + 8 + 1 + 1;
+    pub const SPACE: usize =
+        8 + 32 + 32 + 32 + 8 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 32 + 32 + 1 + 4 + 8 + 8 + 8 + 8;
+
+    /// True if any of `flags` is set in the pause mask.
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_mask & flags != 0
+    }
+
+    /// Promote staged withdrawal limits once their effective slot is reached.
+    pub fn apply_staged_withdraw_limits(&mut self, slot: u64) {
+        if self.withdraw_limits_effective_slot != 0 && slot >= self.withdraw_limits_effective_slot {
+            self.withdraw_delay_slots = self.staged_withdraw_delay_slots;
+            self.withdraw_epoch_cap = self.staged_withdraw_epoch_cap;
+            self.withdraw_limits_effective_slot = 0;
+        }
+    }
+
+    /// Snapshot of the global counters, emitted after every state change.
+    pub fn global_stats(&self, slot: u64) -> GlobalStats {
+        GlobalStats {
+            total_units_killed: self.total_units_killed,
+            total_reapers_killed: self.total_reapers_killed,
+            total_kill_added: self.total_kill_added,
+            total_kill_extracted: self.total_kill_extracted,
+            total_kill_burned: self.total_kill_burned,
+            slot,
+        }
+    }
+}
+
+/// A queued vault withdrawal — PDA seeds: [b"withdrawal", id as [u8;8] little-endian]
+///
+/// Created by `queue_withdraw`; closed (rent back to the treasurer) by
+/// `execute_withdraw` once `executable_slot` is reached, or by `cancel_withdraw`.
+#[account]
+#[derive(Debug)]
+pub struct PendingWithdrawal {
+    /// Sequential id (GameConfig.withdrawal_nonce at queue time)
+    pub id: u64,
+
+    /// KILL token account that receives the funds
+    pub destination: Pubkey,
+
+    /// KILL (raw) to withdraw
+    pub amount: u64,
+
+    /// Slot the withdrawal was queued
+    pub queued_slot: u64,
+
+    /// First slot at which it can execute
+    pub executable_slot: u64,
+
+    /// Canonical bump
+    pub bump: u8,
+}
+
+impl PendingWithdrawal {
+    /// Account discriminator (8) + fields
+    pub const SPACE: usize = 8 + 8 + 32 + 8 + 8 + 8 + 1;
+}
+
+/// Tunable economy parameters — PDA seeds: [b"game_params"]
+///
+/// Replaces the compile-time economy constants so the admin can retune the game
+/// without redeploying (EVM `setConfig` parity).  Seeded with the defaults from
+/// `constants.rs` by `initialize_params`; updated by `set_config`.
+#[account]
+#[derive(Debug)]
+pub struct GameParams {
+    /// KILL (raw) charged per unit spawned
+    pub spawn_cost: u64,
+
+    /// KILL (raw) charged per move
+    pub move_cost: u64,
+
+    /// Bounty multiplier ceiling (also the age at which decay bottoms out)
+    pub max_multiplier: u64,
+
+    /// Slots between each multiplier step
+    pub slots_per_multiplier: u64,
+
+    /// Bounty cap as a fraction of the vault balance, in basis points
+    pub global_cap_bps: u64,
+
+    /// Share of every bounty, spawn fee and move fee burned, in basis points
+    pub burn_bps: u64,
+
+    /// Units spawned per free Reaper
+    pub reaper_threshold: u64,
+
+    /// Canonical bump used to re-derive this PDA cheaply
+    pub bump: u8,
+
+    /// Blend a stack's `spawn_slot` by power when it is reinforced (spawn,
+    /// claim) or merged into (move), instead of keeping the original slot.
+    pub blend_spawn_slot: bool,
+}
+
+impl GameParams {
+    /// Account discriminator (8) + fields
+    pub const SPACE: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    /// The parameters read by the `kill_math` formulas.
+    pub fn math(&self) -> kill_math::Params {
+        kill_math::Params {
+            spawn_cost: self.spawn_cost,
+            max_multiplier: self.max_multiplier,
+            slots_per_multiplier: self.slots_per_multiplier,
+            global_cap_bps: self.global_cap_bps,
+            burn_bps: self.burn_bps,
+        }
+    }
 }
 
-/// Per-agent, per-position stack — PDA seeds: [b"agent_stack", agent.key(), stack_id as [u8;2] LE]
+/// Per-agent, per-position stack —
+/// PDA seeds: [b"agent_stack", season as [u8;4] LE, agent.key(), stack_id as [u8;2] LE]
 ///
 /// stack_id encodes a position in a 6×6×6 grid:
 ///   x = stack_id % 6
@@ -41,8 +238,9 @@ impl GameConfig {
 ///   z = stack_id / 36
 /// Valid range: 0–215.
 ///
-/// Each agent can own one stack per grid cell (up to 216 stacks per agent).
-/// Stacks with units == 0 && reapers == 0 are considered empty/defeated.
+/// Each agent can own one stack per grid cell per season (up to 216 stacks per
+/// agent per season).  Stacks with units == 0 && reapers == 0 are considered
+/// empty/defeated.
 #[account]
 #[derive(Debug)]
 pub struct AgentStack {
@@ -66,11 +264,488 @@ pub struct AgentStack {
 
     /// Canonical bump stored for cheap PDA re-derivation
     pub bump: u8,
+
+    /// Season this stack belongs to (also part of its seeds)
+    pub season: u32,
+
+    /// Units spawned onto this stack since its last reaper was granted
+    /// (always < `reaper_threshold` after a spawn)
+    pub reaper_progress: u64,
+
+    /// Units committed to a pending strike (`commit_kill`).  They still
+    /// defend the stack but cannot be moved or sent by `kill` until
+    /// `reveal_kill` or `refund_commit` releases them.
+    pub locked_units: u64,
+
+    /// Reapers committed to a pending strike — see `locked_units`
+    pub locked_reapers: u64,
 }
 
 impl AgentStack {
     /// Account discriminator (8) + fields
-    pub const SPACE: usize = 8 + 32 + 2 + 8 + 8 + 8 + 8 + 1;
+    pub const SPACE: usize = 8 + 32 + 2 + 8 + 8 + 8 + 8 + 1 + 4 + 8 + 8 + 8;
+
+    /// Size of stacks created before seasons, at the unseasoned seeds
+    /// [b"agent_stack", agent, stack_id] (moved over by `migrate_stack`).
+    pub const LEGACY_SPACE: usize = 8 + 32 + 2 + 8 + 8 + 8 + 8 + 1;
+
+    /// Add spawned (or claimed) `units` plus the reapers they complete: one
+    /// per `params.reaper_threshold` units, counted across calls through
+    /// `reaper_progress`.  The new forces date from `slot` (see `add_forces`).
+    /// Returns the reapers granted.
+    pub fn add_spawned_units(&mut self, units: u64, params: &GameParams, slot: u64) -> Option<u64> {
+        let (reapers, progress) =
+            kill_math::accrue_reapers(self.reaper_progress, units, params.reaper_threshold);
+        self.add_forces(units, reapers, slot, params.blend_spawn_slot)?;
+        self.reaper_progress = progress;
+        Some(reapers)
+    }
+
+    /// Add `units` and `reapers` dating from `slot`.  With `blend`,
+    /// `spawn_slot` moves toward `slot` by their share of the stack's raw
+    /// power (`kill_math::blend_spawn_slot`); otherwise it is kept.
+    pub fn add_forces(&mut self, units: u64, reapers: u64, slot: u64, blend: bool) -> Option<()> {
+        if blend {
+            self.spawn_slot = kill_math::blend_spawn_slot(&self.math(), kill_math::power(units, reapers), slot);
+        }
+        self.units = self.units.checked_add(units)?;
+        self.reapers = self.reapers.checked_add(reapers)?;
+        Some(())
+    }
+
+    /// Units and reapers not locked by a pending commitment — the most
+    /// `move_units` or `kill` may take from the stack.
+    pub fn unlocked(&self) -> (u64, u64) {
+        (
+            self.units.saturating_sub(self.locked_units),
+            self.reapers.saturating_sub(self.locked_reapers),
+        )
+    }
+
+    /// Release up to `units` and `reapers` of the lock (a lock already cut
+    /// by `remove_losses` releases only what is left).
+    pub fn unlock(&mut self, units: u64, reapers: u64) {
+        self.locked_units = self.locked_units.saturating_sub(units);
+        self.locked_reapers = self.locked_reapers.saturating_sub(reapers);
+    }
+
+    /// Remove forces lost while defending.  Losses come out of the unlocked
+    /// forces first; the lock shrinks to whatever survives.
+    pub fn remove_losses(&mut self, units: u64, reapers: u64) {
+        self.units = self.units.saturating_sub(units);
+        self.reapers = self.reapers.saturating_sub(reapers);
+        self.locked_units = self.locked_units.min(self.units);
+        self.locked_reapers = self.locked_reapers.min(self.reapers);
+    }
+
+    /// The fields read by the `kill_math` formulas.
+    pub fn math(&self) -> kill_math::Stack {
+        kill_math::Stack {
+            units: self.units,
+            reapers: self.reapers,
+            spawn_slot: self.spawn_slot,
+        }
+    }
+}
+
+/// One game season — PDA seeds: [b"season", id as [u8;4] little-endian]
+///
+/// Created by `start_season`.  Gameplay is only accepted while
+/// `start_slot <= slot < end_slot`; `end_season` then freezes it and records
+/// every agent's SeasonStanding as final.
+#[account]
+#[derive(Debug)]
+pub struct Season {
+    /// Sequential id (1, 2, …)
+    pub id: u32,
+
+    /// First slot at which gameplay is accepted
+    pub start_slot: u64,
+
+    /// First slot at which gameplay is rejected
+    pub end_slot: u64,
+
+    /// Set by `end_season`; no further changes once true
+    pub ended: bool,
+
+    /// Attacker wins during the season
+    pub total_kills: u64,
+
+    /// Agents with a SeasonStanding in this season
+    pub agents: u32,
+
+    /// Standings recorded as final by `end_season`
+    pub results_recorded: u32,
+
+    /// Canonical bump
+    pub bump: u8,
+}
+
+impl Season {
+    /// Account discriminator (8) + fields
+    pub const SPACE: usize = 8 + 4 + 8 + 8 + 1 + 8 + 4 + 4 + 1;
+
+    /// True while gameplay is accepted at `slot`.
+    pub fn is_active(&self, slot: u64) -> bool {
+        !self.ended && slot >= self.start_slot && slot < self.end_slot
+    }
+}
+
+/// One agent's results for one season —
+/// PDA seeds: [b"season_standing", season as [u8;4] LE, agent.key()]
+///
+/// The per-season counterpart of AgentProfile, created the first time the
+/// agent acts in the season.  Frozen by `end_season`.
+#[account]
+#[derive(Debug)]
+pub struct SeasonStanding {
+    /// Season id
+    pub season: u32,
+
+    /// Owner wallet
+    pub agent: Pubkey,
+
+    /// KILL paid for spawns and moves this season
+    pub kill_spent: u64,
+
+    /// Net bounty received this season (as attacker and as defender)
+    pub bounty_earned: u64,
+
+    /// Units lost in combat this season
+    pub units_lost: u64,
+
+    /// Reapers lost in combat this season
+    pub reapers_lost: u64,
+
+    /// Kills won as the attacker this season
+    pub kills_won: u64,
+
+    /// Set once `end_season` has recorded this standing as final
+    pub finalized: bool,
+
+    /// Canonical bump
+    pub bump: u8,
+}
+
+impl SeasonStanding {
+    /// Account discriminator (8) + fields
+    pub const SPACE: usize = 8 + 4 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+}
+
+/// Per-agent lifetime P&L — PDA seeds: [b"agent_profile", agent.key()]
+///
+/// Created lazily the first time an agent spawns (or is involved in a move/kill
+/// after this account type was introduced).  Gives agents the ground truth for
+/// ROI = bounties received − KILL spent without scanning logs (EVM
+/// `agentTotalProfit` parity, extended with spend and loss tracking).
+#[account]
+#[derive(Debug)]
+pub struct AgentProfile {
+    /// Owner wallet
+    pub agent: Pubkey,
+
+    /// Lifetime KILL paid into the vault for spawns and moves
+    pub kill_spent: u64,
+
+    /// Lifetime net bounty received as the attacker
+    pub bounty_as_attacker: u64,
+
+    /// Lifetime net bounty received as the defender
+    pub bounty_as_defender: u64,
+
+    /// Lifetime units lost in combat (attacking or defending)
+    pub units_lost: u64,
+
+    /// Lifetime reapers lost in combat (attacking or defending)
+    pub reapers_lost: u64,
+
+    /// Kills won as the attacker
+    pub kills_won: u64,
+
+    /// Canonical bump stored for cheap PDA re-derivation
+    pub bump: u8,
+}
+
+impl AgentProfile {
+    /// Account discriminator (8) + fields
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+}
+
+/// Per-cell occupancy index —
+/// PDA seeds: [b"cell_index", season as [u8;4] LE, stack_id as [u8;2] LE]
+///
+/// Lists every agent holding a non-empty AgentStack at this grid position, so
+/// hunters can find targets with one account read instead of scanning every
+/// AgentStack (EVM `stackHeads`/`stackNodes` / `getFullStack` parity).
+/// Kept in sync by spawn, move_units and kill; grows in CELL_INDEX_GROWTH-slot
+/// steps (the agent adding the occupant pays the extra rent) and never shrinks.
+#[account]
+#[derive(Debug)]
+pub struct CellIndex {
+    /// Grid index (0–215)
+    pub stack_id: u16,
+
+    /// Canonical bump stored for cheap PDA re-derivation
+    pub bump: u8,
+
+    /// Season this index belongs to (also part of its seeds).  Sits before
+    /// the Vec so `space_for` stays a simple base + per-occupant size.
+    pub season: u32,
+
+    /// Agents with a non-empty stack on this cell (unordered)
+    pub occupants: Vec<Pubkey>,
+}
+
+impl CellIndex {
+    /// Account discriminator (8) + fixed fields + Vec length prefix (4)
+    pub const BASE_SPACE: usize = 8 + 2 + 1 + 4 + 4;
+
+    /// Account size needed to hold `occupants` entries.
+    pub const fn space_for(occupants: usize) -> usize {
+        Self::BASE_SPACE + 32 * occupants
+    }
+}
+
+/// Top agents by bounty received — PDA seeds: [b"leaderboard"]
+///
+/// Ranks up to LEADERBOARD_SIZE agents by lifetime bounty received (attacker
+/// plus defender payouts, net of burn — the AgentProfile totals).  `kill`
+/// re-ranks the attacker and defender whenever either is paid, so other
+/// programs and UIs can read the standings without scanning events.
+/// Entries are kept sorted, highest bounty first.
+#[account]
+#[derive(Debug)]
+pub struct Leaderboard {
+    /// Canonical bump
+    pub bump: u8,
+
+    /// Ranked entries, highest bounty first (at most LEADERBOARD_SIZE)
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub agent: Pubkey,
+    pub bounty: u64,
+}
+
+impl Leaderboard {
+    /// Account discriminator (8) + bump + Vec prefix (4) + full entry list
+    pub const SPACE: usize = 8 + 1 + 4 + (32 + 8) * LEADERBOARD_SIZE;
+
+    /// Record `agent`'s new lifetime bounty and restore the ordering.
+    ///
+    /// A bounty only grows, so an existing entry can only move up; a new agent
+    /// enters at the bottom if there is room or it beats the lowest entry.
+    /// One scan plus one insertion pass — O(LEADERBOARD_SIZE) either way.
+    pub fn record(&mut self, agent: Pubkey, bounty: u64) {
+        let mut idx = match self.entries.iter().position(|e| e.agent == agent) {
+            Some(i) => {
+                self.entries[i].bounty = bounty;
+                i
+            }
+            None if self.entries.len() < LEADERBOARD_SIZE => {
+                self.entries.push(LeaderboardEntry { agent, bounty });
+                self.entries.len() - 1
+            }
+            None => {
+                let last = self.entries.len() - 1;
+                if bounty <= self.entries[last].bounty {
+                    return;
+                }
+                self.entries[last] = LeaderboardEntry { agent, bounty };
+                last
+            }
+        };
+        while idx > 0 && self.entries[idx - 1].bounty < self.entries[idx].bounty {
+            self.entries.swap(idx - 1, idx);
+            idx -= 1;
+        }
+    }
+}
+
+/// Merkle airdrop configuration — PDA seeds: [b"airdrop"]
+///
+/// Allow-listed wallets claim `airdrop_amount / spawn_cost` free units on a
+/// stack of their choice (EVM `setMerkleRoot` / `claim` parity).  Leaves are
+/// keccak256(wallet pubkey); proofs use OpenZeppelin's sorted-pair hashing so
+/// the same tree tooling works for both chains.
+#[account]
+#[derive(Debug)]
+pub struct Airdrop {
+    /// Current allow-list root.  All zeroes = no airdrop active.
+    pub merkle_root: [u8; 32],
+
+    /// KILL value of each claim, converted to units at claim time
+    pub airdrop_amount: u64,
+
+    /// Number of successful claims
+    pub total_claims: u64,
+
+    /// Canonical bump stored for cheap PDA re-derivation
+    pub bump: u8,
+}
+
+impl Airdrop {
+    /// Account discriminator (8) + fields
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 1;
+}
+
+/// One-per-wallet airdrop claim record — PDA seeds: [b"airdrop_claim", claimer.key()]
+///
+/// Created with `init`, so a second claim fails before the handler runs.
+#[account]
+#[derive(Debug)]
+pub struct AirdropClaim {
+    /// Wallet that claimed
+    pub claimer: Pubkey,
+
+    /// Stack the units were spawned on
+    pub stack_id: u16,
+
+    /// Units granted
+    pub units: u64,
+
+    /// Slot at which the claim occurred
+    pub slot: u64,
+
+    /// Canonical bump
+    pub bump: u8,
+}
+
+impl AirdropClaim {
+    /// Account discriminator (8) + fields
+    pub const SPACE: usize = 8 + 32 + 2 + 8 + 8 + 1;
+}
+
+/// Pending two-phase strike — PDA seeds: [b"kill_commit", attacker_stack.key()]
+///
+/// Created by `commit_kill`, which locks the committed units and reapers on
+/// the attacker's stack (`AgentStack::locked_units`/`locked_reapers`): they
+/// stay there and still defend it, but cannot be moved or sent by `kill`.
+/// `hash` is
+/// keccak256(attacker_stack_id LE ‖ defender ‖ sent_units LE ‖ sent_reapers LE ‖ salt),
+/// so the target stays secret until `reveal_kill`, which releases the lock
+/// and strikes with the revealed amounts.  Unrevealed commitments release it
+/// through `refund_commit` once the window has passed.
+#[account]
+#[derive(Debug)]
+pub struct KillCommitment {
+    /// Attacking wallet
+    pub attacker: Pubkey,
+
+    /// Season of the attacker's stack
+    pub season: u32,
+
+    /// Attacker's grid position
+    pub stack_id: u16,
+
+    /// Commitment hash
+    pub hash: [u8; 32],
+
+    /// Units locked on the attacker's stack
+    pub units: u64,
+
+    /// Reapers locked on the attacker's stack
+    pub reapers: u64,
+
+    /// Slot of `commit_kill`
+    pub commit_slot: u64,
+
+    /// Canonical bump
+    pub bump: u8,
+}
+
+impl KillCommitment {
+    /// Account discriminator (8) + fields
+    pub const SPACE: usize = 8 + 32 + 4 + 2 + 32 + 8 + 8 + 8 + 1;
+}
+
+/// Session key for an agent — PDA seeds: [b"delegation", owner.key()]
+///
+/// Lets a hot `delegate` key sign spawn/move_units/kill for `owner` until
+/// `expiry_slot`, limited to the `DELEGATE_*` flags in `allowed`.  Stacks,
+/// profiles and payouts stay tied to the owner.  KILL fees are paid with this
+/// PDA as SPL delegate on the owner's token account (approved for
+/// `spend_cap` in `set_delegation`); `spent` tracks usage against the cap.
+#[account]
+#[derive(Debug)]
+pub struct Delegation {
+    /// Wallet the delegate acts for
+    pub owner: Pubkey,
+
+    /// Hot key allowed to sign for the owner
+    pub delegate: Pubkey,
+
+    /// First slot at which the delegation is no longer valid
+    pub expiry_slot: u64,
+
+    /// `DELEGATE_*` flags for the instructions the delegate may call
+    pub allowed: u8,
+
+    /// Most KILL the delegate may spend on fees
+    pub spend_cap: u64,
+
+    /// KILL spent by the delegate so far
+    pub spent: u64,
+
+    /// Canonical bump
+    pub bump: u8,
+}
+
+impl Delegation {
+    /// Account discriminator (8) + fields
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 1 + 8 + 8 + 1;
+}
+
+// ── Return data ───────────────────────────────────────────────────────────────
+
+/// Preview of a strike, returned by `quote_kill` — the same values `kill`
+/// would apply in the same slot.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KillQuote {
+    /// Attacker's effective power percentage after age decay (5–100)
+    pub attacker_decay_pct: u64,
+    /// Defender's effective power percentage after age decay (5–100)
+    pub defender_decay_pct: u64,
+    /// Defender's raw power: units + reapers × THERMAL_PARITY
+    pub defender_power: u64,
+    pub won: bool,
+    /// Attacker units surviving the strike
+    pub remaining_units: u64,
+    /// Attacker reapers surviving the strike
+    pub remaining_reapers: u64,
+    pub attacker_units_lost: u64,
+    pub attacker_reapers_lost: u64,
+    pub defender_units_lost: u64,
+    pub defender_reapers_lost: u64,
+    /// Defender's full pending bounty before scaling
+    pub pending_bounty: u64,
+    /// Share of the pending bounty released by the power destroyed
+    pub battle_pool: u64,
+    /// Payout to the attacker (after burn deduction)
+    pub attacker_bounty: u64,
+    /// Payout to the defender (after burn deduction)
+    pub defender_bounty: u64,
+    /// Total amount burned from vault across both bounties
+    pub total_burned: u64,
+    pub slot: u64,
+}
+
+/// A stack's current bounty and combat standing, returned by `quote_bounty`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BountyQuote {
+    pub agent: Pubkey,
+    pub stack_id: u16,
+    /// Raw power: units + reapers × THERMAL_PARITY
+    pub power: u64,
+    /// Age multiplier applied to the bounty (1..max_multiplier)
+    pub multiplier: u64,
+    /// Effective power percentage after age decay (5–100)
+    pub decay_pct: u64,
+    /// Bounty paid out if the stack is destroyed now (before burn)
+    pub pending_bounty: u64,
+    pub slot: u64,
 }
 
 // ── Events ────────────────────────────────────────────────────────────────────
@@ -83,6 +758,14 @@ pub struct StackSpawned {
     pub units: u64,
     pub reapers: u64,
     pub slot: u64,
+    /// Share of the spawn fee deposited in the vault
+    pub deposited: u64,
+    /// Share of the spawn fee burned
+    pub burned: u64,
+    /// Units added by this spawn (`units` is the stack's new total)
+    pub spawned_units: u64,
+    /// Reapers this spawn completed (`reapers` is the stack's new total)
+    pub spawned_reapers: u64,
 }
 
 #[event]
@@ -93,6 +776,217 @@ pub struct StackMoved {
     pub units: u64,
     pub reapers: u64,
     pub slot: u64,
+    /// Share of the move fee deposited in the vault
+    pub deposited: u64,
+    /// Share of the move fee burned
+    pub burned: u64,
+}
+
+#[event]
+pub struct GlobalStats {
+    pub total_units_killed: u64,
+    pub total_reapers_killed: u64,
+    pub total_kill_added: u64,
+    pub total_kill_extracted: u64,
+    pub total_kill_burned: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    /// `Pubkey::default()` when a pending handover is cancelled
+    pub pending_admin: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AdminAccepted {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub slot: u64,
+}
+
+/// Role held by a key other than the admin — see GameConfig.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Pauser,
+    Treasurer,
+    ParamsAuthority,
+}
+
+#[event]
+pub struct SeasonStarted {
+    pub season: u32,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct SeasonEndChanged {
+    pub season: u32,
+    pub old_end_slot: u64,
+    pub end_slot: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct SeasonEnded {
+    pub season: u32,
+    pub total_kills: u64,
+    pub agents: u32,
+    pub slot: u64,
+}
+
+#[event]
+pub struct SeasonResult {
+    pub season: u32,
+    pub agent: Pubkey,
+    pub kill_spent: u64,
+    pub bounty_earned: u64,
+    pub units_lost: u64,
+    pub reapers_lost: u64,
+    pub kills_won: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub paused: bool,
+    pub pause_mask: u8,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RoleUpdated {
+    pub role: Role,
+    pub previous: Pubkey,
+    pub authority: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct WithdrawLimitsUpdated {
+    pub delay_slots: u64,
+    pub epoch_cap: u64,
+    /// Slot the new limits replace the current ones
+    pub effective_slot: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct WithdrawQueued {
+    pub id: u64,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub executable_slot: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct WithdrawExecuted {
+    pub id: u64,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct WithdrawCancelled {
+    pub id: u64,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub spawn_cost: u64,
+    pub move_cost: u64,
+    pub max_multiplier: u64,
+    pub slots_per_multiplier: u64,
+    pub global_cap_bps: u64,
+    pub burn_bps: u64,
+    pub reaper_threshold: u64,
+    pub blend_spawn_slot: bool,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AirdropUpdated {
+    pub merkle_root: [u8; 32],
+    pub airdrop_amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Claimed {
+    pub claimer: Pubkey,
+    pub stack_id: u16,
+    pub units: u64,
+    pub reapers: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct KillCommitted {
+    pub attacker: Pubkey,
+    pub stack_id: u16,
+    pub units: u64,
+    pub reapers: u64,
+    /// Last slot at which `reveal_kill` is accepted
+    pub reveal_deadline: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct CommitRefunded {
+    pub attacker: Pubkey,
+    pub stack_id: u16,
+    pub units: u64,
+    pub reapers: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegationSet {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub expiry_slot: u64,
+    pub allowed: u8,
+    pub spend_cap: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegationRevoked {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    /// KILL the delegate spent before revocation
+    pub spent: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct StackMigrated {
+    pub agent: Pubkey,
+    pub stack_id: u16,
+    /// Season the stack was moved into
+    pub season: u32,
+    pub units: u64,
+    pub reapers: u64,
+    /// Lamports of the legacy account returned to the agent
+    pub rent_returned: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct StackClosed {
+    pub agent: Pubkey,
+    pub stack_id: u16,
+    /// Lamports returned to the agent
+    pub rent_returned: u64,
+    pub slot: u64,
 }
 
 #[event]
@@ -8,12 +8,15 @@
 //! * `initialize_token` — creates the SPL mint with this program's PDA as the
 //!   mint authority, so nothing can mint outside this program.
 //! * `mint_to` — admin-only mint that checks cumulative supply against the cap.
-//! * `transfer_admin` — hand off admin rights to a new wallet.
+//! * `propose_admin` / `accept_admin` — two-step handover of admin rights; the
+//!   new wallet must sign before control moves.
+//! * `migrate_config` — grow a TokenConfig created by an older program version.
 //!
 //! HARD_CAP: 66,666,666,666 KILL × 10^6 decimals = 66_666_666_666_000_000 raw.
 //! (EVM uses 18 decimals; Solana tokens conventionally use 6, like USDC.)
 
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
 
 // PLACEHOLDER — after first `anchor build`, run:
@@ -47,10 +50,31 @@ pub struct TokenConfig {
     pub cap: u64,
     /// Canonical PDA bump
     pub bump: u8,
+    /// Admin proposed by `propose_admin`; takes over once it signs
+    /// `accept_admin`.  `Pubkey::default()` when no handover is pending.
+    /// Appended after `bump` so older accounts can be grown by `migrate_config`.
+    pub pending_admin: Pubkey,
 }
 
 impl TokenConfig {
-    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 32;
+}
+
+// ── Events ────────────────────────────────────────────────────────────────────
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    /// `Pubkey::default()` when a pending handover is cancelled
+    pub pending_admin: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AdminAccepted {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub slot: u64,
 }
 
 // ── Error codes ───────────────────────────────────────────────────────────────
@@ -61,6 +85,8 @@ pub enum TokenError {
     CapExceeded,
     #[msg("Unauthorized — admin only")]
     Unauthorized,
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
 }
 
 // ── Program ───────────────────────────────────────────────────────────────────
@@ -85,6 +111,7 @@ pub mod kill_token {
         config.total_minted = 0;
         config.cap = HARD_CAP;
         config.bump = ctx.bumps.token_config;
+        config.pending_admin = Pubkey::default();
         Ok(())
     }
 
@@ -128,16 +155,96 @@ pub mod kill_token {
         Ok(())
     }
 
-    /// Transfer admin rights to a new wallet.
-    /// Equivalent to EVM `transferOwnership(address newOwner)`.
-    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
-        require!(
-            ctx.accounts.token_config.admin == ctx.accounts.admin.key(),
-            TokenError::Unauthorized
-        );
-        ctx.accounts.token_config.admin = new_admin;
+    /// Propose `new_admin` as the next admin (step 1 of 2).
+    /// Equivalent to EVM `Ownable2Step.transferOwnership(address newOwner)`:
+    /// control does not move until `new_admin` signs `accept_admin`.
+    /// Proposing `Pubkey::default()` cancels a pending handover.
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.token_config;
+        config.pending_admin = new_admin;
+
+        emit!(AdminProposed {
+            admin: config.admin,
+            pending_admin: new_admin,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Take over as admin (step 2 of 2).  Must be signed by the pending admin.
+    /// Equivalent to EVM `Ownable2Step.acceptOwnership()`.
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let config = &mut ctx.accounts.token_config;
+        let previous_admin = config.admin;
+        config.admin = config.pending_admin;
+        config.pending_admin = Pubkey::default();
+
+        emit!(AdminAccepted {
+            previous_admin,
+            new_admin: config.admin,
+            slot: Clock::get()?.slot,
+        });
         Ok(())
     }
+
+    /// Grow a TokenConfig created before `pending_admin` existed to the current
+    /// `TokenConfig::SPACE`.  The added tail is zero-filled (no pending admin).
+    /// No-op if the account is already current.
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        let info = ctx.accounts.token_config.to_account_info();
+        let (config, old_len) = load_padded::<TokenConfig>(&info, TokenConfig::SPACE)?;
+        require_keys_eq!(config.admin, ctx.accounts.admin.key(), TokenError::Unauthorized);
+
+        if old_len >= TokenConfig::SPACE {
+            return Ok(());
+        }
+        grow_account(
+            &info,
+            TokenConfig::SPACE,
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Decode a program-owned `T` that may predate fields appended to its layout:
+/// the data is zero-padded to `space` first, so missing fields read as zero.
+/// Checks owner and discriminator; also returns the account's current length.
+fn load_padded<T: AccountDeserialize>(account: &AccountInfo, space: usize) -> Result<(T, usize)> {
+    require_keys_eq!(*account.owner, crate::ID, TokenError::Unauthorized);
+    let mut data = account.try_borrow_data()?.to_vec();
+    let len = data.len();
+    data.resize(len.max(space), 0);
+    Ok((T::try_deserialize(&mut data.as_slice())?, len))
+}
+
+/// Grow a program-owned account to `new_len` bytes, first topping up its rent
+/// exemption from `payer`.  The added tail is zero-filled.
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.resize(new_len)?;
+    Ok(())
 }
 
 // ── Accounts contexts ─────────────────────────────────────────────────────────
@@ -202,7 +309,7 @@ pub struct MintTokens<'info> {
 }
 
 #[derive(Accounts)]
-pub struct TransferAdmin<'info> {
+pub struct ProposeAdmin<'info> {
     #[account(
         mut,
         seeds = [b"token_config"],
@@ -213,3 +320,35 @@ pub struct TransferAdmin<'info> {
 
     pub admin: Signer<'info>,
 }
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"token_config"],
+        bump = token_config.bump,
+        constraint = token_config.pending_admin != Pubkey::default() @ TokenError::NotPendingAdmin,
+        constraint = token_config.pending_admin == new_admin.key() @ TokenError::NotPendingAdmin,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Possibly an older, shorter layout — loaded with `load_padded`.
+    /// Seeds pin the address; the handler checks the admin.
+    #[account(
+        mut,
+        seeds = [b"token_config"],
+        bump,
+    )]
+    pub token_config: UncheckedAccount<'info>,
+
+    /// Pays the extra rent for the grown account.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
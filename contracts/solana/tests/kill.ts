@@ -34,15 +34,17 @@ import {
 import {
   TOKEN_PROGRAM_ID,
   getAccount,
+  getMint,
   getOrCreateAssociatedTokenAccount,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 import type { KillToken }  from "../target/types/kill_token";
 import type { KillGame }   from "../target/types/kill_game";
 import type { KillFaucet } from "../target/types/kill_faucet";
 
-// ── Constants (must match programs/kill_*/src/constants.rs) ──────────────────
+// ── Constants (must match the GameParams defaults in programs/kill_*/src/constants.rs) ──
 const SPAWN_COST     = new BN(20_000_000);          // 20 KILL per unit @ 6 decimals
 const REAPER_THRESHOLD = new BN(666);               // units required for 1 free reaper
 const MOVE_COST      = new BN(100_000_000);         // 100 KILL @ 6 decimals
@@ -50,6 +52,9 @@ const HARD_CAP       = new BN("666000000000000000"); // 666B KILL (matches EVM)
 const THERMAL_PARITY = new BN(666);
 const BURN_BPS       = new BN(666);
 const BPS_DENOM      = new BN(10_000);
+const DELEGATE_SPAWN = 1 << 0;                      // Delegation.allowed flags
+const DELEGATE_KILL  = 1 << 2;
+const NO_GUARDS      = { maxDefenderPower: null, minAttackerBounty: null, requireWin: false };
 
 // ── Grid adjacency helpers ────────────────────────────────────────────────────
 // Stack ID layout: id = x + y*6 + z*36  (6×6×6 grid)
@@ -81,6 +86,14 @@ describe("KILL System", () => {
     [Buffer.from("game_config")],
     gameProg.programId
   );
+  const [gameParamsPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("game_params")],
+    gameProg.programId
+  );
+  const [leaderboardPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("leaderboard")],
+    gameProg.programId
+  );
   const [faucetConfigPda] = PublicKey.findProgramAddressSync(
     [Buffer.from("faucet_config")],
     faucetProg.programId
@@ -89,12 +102,59 @@ describe("KILL System", () => {
   // admin ATA is populated in the first kill_token test
   let adminAta: PublicKey;
 
+  // Current season — started right after initialize_params, bumped by the Seasons tests
+  let season = 1;
+
+  function seasonBuf(s: number): Buffer {
+    const buf = Buffer.alloc(4);
+    buf.writeUInt32LE(s);
+    return buf;
+  }
+
+  // ── Shared helper: derive Season PDA ─────────────────────────────────────────
+  function seasonPda(s: number = season): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("season"), seasonBuf(s)],
+      gameProg.programId
+    );
+    return pda;
+  }
+
+  // ── Shared helper: derive SeasonStanding PDA ─────────────────────────────────
+  function standingPda(agent: PublicKey, s: number = season): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("season_standing"), seasonBuf(s), agent.toBuffer()],
+      gameProg.programId
+    );
+    return pda;
+  }
+
   // ── Shared helper: derive AgentStack PDA ─────────────────────────────────────
-  function stackPda(agent: PublicKey, stackId: number): PublicKey {
+  function stackPda(agent: PublicKey, stackId: number, s: number = season): PublicKey {
+    const buf = Buffer.alloc(2);
+    buf.writeUInt16LE(stackId);
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("agent_stack"), seasonBuf(s), agent.toBuffer(), buf],
+      gameProg.programId
+    );
+    return pda;
+  }
+
+  // ── Shared helper: derive AgentProfile PDA ───────────────────────────────────
+  function profilePda(agent: PublicKey): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("agent_profile"), agent.toBuffer()],
+      gameProg.programId
+    );
+    return pda;
+  }
+
+  // ── Shared helper: derive CellIndex PDA ──────────────────────────────────────
+  function cellPda(stackId: number, s: number = season): PublicKey {
     const buf = Buffer.alloc(2);
     buf.writeUInt16LE(stackId);
     const [pda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("agent_stack"), agent.toBuffer(), buf],
+      [Buffer.from("cell_index"), seasonBuf(s), buf],
       gameProg.programId
     );
     return pda;
@@ -114,26 +174,50 @@ describe("KILL System", () => {
       .rpc();
   }
 
+  // ── Shared helper: derive Delegation PDA ────────────────────────────────────
+  function delegationPda(owner: PublicKey): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("delegation"), owner.toBuffer()],
+      gameProg.programId
+    );
+    return pda;
+  }
+
+  // Gameplay is signed by the agent (no delegation) or by its delegate
+  function authorityAccounts(agent: Keypair, signer: Keypair) {
+    return {
+      delegation: signer === agent ? null : delegationPda(agent.publicKey),
+      authority:  signer.publicKey,
+    };
+  }
+
   // ── Shared helper: spawn units for an agent ───────────────────────────────────
   async function spawnFor(
     agent: Keypair,
     agentAta: PublicKey,
     stackId: number,
-    units: BN
+    units: BN,
+    signer: Keypair = agent
   ) {
-    await gameProg.methods
+    return gameProg.methods
       .spawn(stackId, units)
       .accounts({
         gameConfig:        gameConfigPda,
+        gameParams:        gameParamsPda,
+        season:            seasonPda(),
         agentStack:        stackPda(agent.publicKey, stackId),
+        cellIndex:         cellPda(stackId),
+        agentProfile:      profilePda(agent.publicKey),
+        seasonStanding:    standingPda(agent.publicKey),
         agentTokenAccount: agentAta,
         gameVault:         gameVaultKp.publicKey,
         killMint:          killMintKp.publicKey,
         agent:             agent.publicKey,
+        ...authorityAccounts(agent, signer),
         tokenProgram:      TOKEN_PROGRAM_ID,
         systemProgram:     SystemProgram.programId,
       })
-      .signers([agent])
+      .signers([signer])
       .rpc();
   }
 
@@ -144,22 +228,31 @@ describe("KILL System", () => {
     fromStackId: number,
     toStackId: number,
     units: BN,
-    reapers: BN
+    reapers: BN,
+    closeEmpty = false,
+    signer: Keypair = agent
   ) {
-    await gameProg.methods
-      .moveUnits(fromStackId, toStackId, units, reapers)
+    return gameProg.methods
+      .moveUnits(fromStackId, toStackId, units, reapers, closeEmpty)
       .accounts({
         gameConfig:        gameConfigPda,
+        gameParams:        gameParamsPda,
+        season:            seasonPda(),
         fromStack:         stackPda(agent.publicKey, fromStackId),
         toStack:           stackPda(agent.publicKey, toStackId),
+        fromCell:          cellPda(fromStackId),
+        toCell:            cellPda(toStackId),
+        agentProfile:      profilePda(agent.publicKey),
+        seasonStanding:    standingPda(agent.publicKey),
         agentTokenAccount: agentAta,
         gameVault:         gameVaultKp.publicKey,
         killMint:          killMintKp.publicKey,
         agent:             agent.publicKey,
+        ...authorityAccounts(agent, signer),
         tokenProgram:      TOKEN_PROGRAM_ID,
         systemProgram:     SystemProgram.programId,
       })
-      .signers([agent])
+      .signers([signer])
       .rpc();
   }
 
@@ -171,23 +264,37 @@ describe("KILL System", () => {
     defenderAta: PublicKey,
     stackId: number,
     sentUnits: BN,
-    sentReapers: BN
+    sentReapers: BN,
+    closeEmpty = false,
+    signer: Keypair = attacker,
+    guards: any = NO_GUARDS
   ) {
     return gameProg.methods
-      .kill(stackId, stackId, sentUnits, sentReapers)
+      .kill(stackId, stackId, sentUnits, sentReapers, closeEmpty, guards)
       .accounts({
         gameConfig:           gameConfigPda,
+        gameParams:           gameParamsPda,
+        season:               seasonPda(),
         attackerStack:        stackPda(attacker.publicKey, stackId),
+        commitment:           null,
         defenderStack:        stackPda(defenderPubkey, stackId),
+        cellIndex:            cellPda(stackId),
+        attackerProfile:      profilePda(attacker.publicKey),
+        defenderProfile:      profilePda(defenderPubkey),
+        attackerStanding:     standingPda(attacker.publicKey),
+        defenderStanding:     standingPda(defenderPubkey),
+        leaderboard:          leaderboardPda,
         attackerTokenAccount: attackerAta,
         defenderTokenAccount: defenderAta,
         gameVault:            gameVaultKp.publicKey,
         killMint:             killMintKp.publicKey,
         attacker:             attacker.publicKey,
+        ...authorityAccounts(attacker, signer),
         defender:             defenderPubkey,
         tokenProgram:         TOKEN_PROGRAM_ID,
+        systemProgram:        SystemProgram.programId,
       })
-      .signers([attacker])
+      .signers([signer])
       .rpc();
   }
 
@@ -212,6 +319,58 @@ describe("KILL System", () => {
     return [kp, ata];
   }
 
+  // ── Shared helper: two-step admin handover round trip ─────────────────────────
+  // Hands admin to a fresh key and back again, checking that control only moves
+  // once the proposed key signs accept_admin.  Leaves `admin` in control.
+  async function adminHandoverRoundTrip(prog: Program<any>, configKey: string, configPda: PublicKey) {
+    const [next] = await newUser(new BN(0));
+    const [stranger] = await newUser(new BN(0));
+    const fetchCfg = () => (prog.account as any)[configKey].fetch(configPda);
+
+    await prog.methods
+      .proposeAdmin(next.publicKey)
+      .accounts({ [configKey]: configPda, admin: admin.publicKey })
+      .rpc();
+    let cfg = await fetchCfg();
+    assert.equal(cfg.pendingAdmin.toBase58(), next.publicKey.toBase58(), "pending admin recorded");
+    assert.equal(cfg.admin.toBase58(), admin.publicKey.toBase58(), "control has not moved yet");
+
+    let threw = false;
+    try {
+      await prog.methods
+        .acceptAdmin()
+        .accounts({ [configKey]: configPda, newAdmin: stranger.publicKey })
+        .signers([stranger])
+        .rpc();
+    } catch (err: any) {
+      threw = true;
+      assert.include(err.toString(), "NotPendingAdmin");
+    }
+    assert.isTrue(threw, "only the pending admin can accept");
+
+    await prog.methods
+      .acceptAdmin()
+      .accounts({ [configKey]: configPda, newAdmin: next.publicKey })
+      .signers([next])
+      .rpc();
+    cfg = await fetchCfg();
+    assert.equal(cfg.admin.toBase58(), next.publicKey.toBase58(), "new admin in control");
+    assert.equal(cfg.pendingAdmin.toBase58(), PublicKey.default.toBase58(), "pending admin cleared");
+
+    // Hand control back so later tests keep working.
+    await prog.methods
+      .proposeAdmin(admin.publicKey)
+      .accounts({ [configKey]: configPda, admin: next.publicKey })
+      .signers([next])
+      .rpc();
+    await prog.methods
+      .acceptAdmin()
+      .accounts({ [configKey]: configPda, newAdmin: admin.publicKey })
+      .rpc();
+    cfg = await fetchCfg();
+    assert.equal(cfg.admin.toBase58(), admin.publicKey.toBase58(), "admin restored");
+  }
+
   // ═══════════════════════════════════════════════════════════════════════════
   // 1. kill_token
   // ═══════════════════════════════════════════════════════════════════════════
@@ -256,6 +415,11 @@ describe("KILL System", () => {
       assert.equal(ataInfo.amount.toString(), mintAmount.toString(), "tokens arrived");
       console.log("  ✓ Minted 1,000,000 KILL to admin");
     });
+
+    it("propose_admin / accept_admin — control moves only after the new key signs", async () => {
+      await adminHandoverRoundTrip(tokenProg, "tokenConfig", tokenConfigPda);
+      console.log("  ✓ Two-step token admin handover");
+    });
   });
 
   // ═══════════════════════════════════════════════════════════════════════════
@@ -286,8 +450,78 @@ describe("KILL System", () => {
       console.log("  ✓ Game vault:", gameVaultKp.publicKey.toBase58());
     });
 
+    it("migrate_config — no-op on an account already at the current layout", async () => {
+      const before = await provider.connection.getAccountInfo(gameConfigPda);
+      await gameProg.methods
+        .migrateConfig()
+        .accounts({
+          gameConfig:    gameConfigPda,
+          admin:         admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      const after = await provider.connection.getAccountInfo(gameConfigPda);
+      assert.equal(after!.data.length, before!.data.length, "size unchanged");
+      console.log("  ✓ migrate_config left a current GameConfig untouched");
+    });
+
+    it("initialize_params — creates GameParams PDA seeded with the default economy", async () => {
+      await gameProg.methods
+        .initializeParams()
+        .accounts({
+          gameConfig:    gameConfigPda,
+          gameParams:    gameParamsPda,
+          admin:         admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const params = await gameProg.account.gameParams.fetch(gameParamsPda);
+      assert.equal(params.spawnCost.toString(),       SPAWN_COST.toString(),       "spawn_cost default");
+      assert.equal(params.moveCost.toString(),        MOVE_COST.toString(),        "move_cost default");
+      assert.equal(params.burnBps.toString(),         BURN_BPS.toString(),         "burn_bps default");
+      assert.equal(params.reaperThreshold.toString(), REAPER_THRESHOLD.toString(), "reaper_threshold default");
+      console.log("  ✓ GameParams initialized with defaults");
+    });
+
+    it("start_season — opens season 1 on a fresh grid", async () => {
+      const slot = await provider.connection.getSlot();
+      await gameProg.methods
+        .startSeason(new BN(slot), new BN(slot + 10_000_000))
+        .accounts({
+          gameConfig:      gameConfigPda,
+          season:          seasonPda(1),
+          paramsAuthority: admin.publicKey,
+          systemProgram:   SystemProgram.programId,
+        })
+        .rpc();
+
+      const cfg = await gameProg.account.gameConfig.fetch(gameConfigPda);
+      const s1  = await gameProg.account.season.fetch(seasonPda(1));
+      assert.equal(cfg.currentSeason, 1, "current_season advanced");
+      assert.equal(cfg.seasonEndSlot.toString(), s1.endSlot.toString(), "end slot mirrored");
+      assert.isFalse(s1.ended, "season open");
+      console.log("  ✓ Season 1 started");
+    });
+
+    it("initialize_leaderboard — creates an empty Leaderboard PDA", async () => {
+      await gameProg.methods
+        .initializeLeaderboard()
+        .accounts({
+          gameConfig:    gameConfigPda,
+          leaderboard:   leaderboardPda,
+          admin:         admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const board = await gameProg.account.leaderboard.fetch(leaderboardPda);
+      assert.equal(board.entries.length, 0, "starts empty");
+    });
+
     // ── spawn (baseline) ──────────────────────────────────────────────────────
     it("spawn — deploys 666 units to grid position 0, auto-grants 1 reaper [test 6]", async () => {
+      const supplyBefore = (await getMint(provider.connection, killMintKp.publicKey)).supply;
       await spawnFor(admin as any as Keypair, adminAta, 0, new BN(666));
 
       const stack = await gameProg.account.agentStack.fetch(stackPda(admin.publicKey, 0));
@@ -297,9 +531,13 @@ describe("KILL System", () => {
       assert.isTrue(stack.spawnSlot.toNumber() > 0, "spawn_slot recorded [test 6]");
 
       const vault = await getAccount(provider.connection, gameVaultKp.publicKey);
-      const expectedCost = new BN(666).mul(SPAWN_COST);
-      assert.equal(vault.amount.toString(), expectedCost.toString(), "vault funded (666 × SPAWN_COST)");
-      console.log("  ✓ Admin stack spawned at grid[0]; 1 auto-reaper granted; spawn_slot recorded");
+      const cost   = new BN(666).mul(SPAWN_COST);
+      const burned = cost.mul(BURN_BPS).divn(10_000);
+      assert.equal(vault.amount.toString(), cost.sub(burned).toString(), "vault funded (666 × SPAWN_COST net of burn)");
+
+      const supplyAfter = (await getMint(provider.connection, killMintKp.publicKey)).supply;
+      assert.equal((supplyBefore - supplyAfter).toString(), burned.toString(), "burn_bps of the fee burned");
+      console.log("  ✓ Admin stack spawned at grid[0]; 1 auto-reaper granted; spawn_slot recorded; fee burn applied");
     });
 
     // ── Spawn mechanics ───────────────────────────────────────────────────────
@@ -340,6 +578,165 @@ describe("KILL System", () => {
         assert.equal(after.spawnSlot.toString(), slotBefore.toString(), "spawn_slot preserved");
         console.log("  ✓ Reinforcement preserved spawn_slot; units now 15, no reapers");
       });
+
+      it("auto-reaper: 665 + 665 units at stack 3 accrue 1 reaper across calls", async () => {
+        await spawnFor(userC, userCata, 3, new BN(665));
+        const first = await gameProg.account.agentStack.fetch(stackPda(userC.publicKey, 3));
+        assert.equal(first.reapers.toString(),        "0",   "665 < threshold");
+        assert.equal(first.reaperProgress.toString(), "665", "progress banked");
+
+        await spawnFor(userC, userCata, 3, new BN(665));
+        const second = await gameProg.account.agentStack.fetch(stackPda(userC.publicKey, 3));
+        assert.equal(second.units.toString(),          "1330", "units stored");
+        assert.equal(second.reapers.toString(),        "1",    "reaper completed by the reinforcement");
+        assert.equal(second.reaperProgress.toString(), "664",  "remainder carried");
+        console.log("  ✓ 665 + 665 units at stack 3 → 1 reaper, 664 toward the next");
+      });
+
+      it("migrate_stack — moves a pre-season stack into the current season", async () => {
+        // Preloaded from tests/fixtures/legacy_agent_stack.json (see Anchor.toml):
+        // the old 75-byte layout at the unseasoned seeds, as spawn created it
+        // before seasons existed.
+        const agent   = new PublicKey(Buffer.alloc(32, 0x4c));
+        const stackId = 205;
+        const [legacyPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("agent_stack"), agent.toBuffer(), Buffer.from([stackId, 0])],
+          gameProg.programId
+        );
+        const legacy = await provider.connection.getAccountInfo(legacyPda);
+        assert.equal(legacy!.data.length, 75, "baseline layout");
+        const agentBefore = await provider.connection.getBalance(agent);
+
+        await gameProg.methods
+          .migrateStack(stackId)
+          .accounts({
+            gameConfig:    gameConfigPda,
+            gameParams:    gameParamsPda,
+            legacyStack:   legacyPda,
+            agentStack:    stackPda(agent, stackId),
+            cellIndex:     cellPda(stackId),
+            agent,
+            payer:         admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        const stack = await gameProg.account.agentStack.fetch(stackPda(agent, stackId));
+        assert.equal(stack.units.toString(),          "42",   "units carried over");
+        assert.equal(stack.reapers.toString(),        "2",    "reapers carried over");
+        assert.equal(stack.spawnSlot.toString(),      "1000", "spawn_slot kept");
+        assert.equal(stack.season,                    season, "moved into the current season");
+        assert.equal(stack.reaperProgress.toString(), "0",    "no progress banked");
+
+        const cell = await gameProg.account.cellIndex.fetch(cellPda(stackId));
+        assert.include(cell.occupants.map((k: PublicKey) => k.toBase58()), agent.toBase58(), "listed");
+
+        assert.isNull(await provider.connection.getAccountInfo(legacyPda), "legacy account closed");
+        const agentAfter = await provider.connection.getBalance(agent);
+        assert.equal(agentAfter - agentBefore, legacy!.lamports, "legacy rent returned to the agent");
+        console.log("  ✓ migrate_stack moved a pre-season stack (42 units, 2 reapers) into season", season);
+      });
+    });
+
+    // ── Batch spawn ─────────────────────────────────────────────────────────────
+    describe("spawn_batch", () => {
+      it("spawns across several cells with one aggregate transfer", async () => {
+        const [user, userAta] = await newUser(new BN(500_000_000_000));
+        const entries = [
+          { stackId: 30, units: new BN(10) },
+          { stackId: 31, units: new BN(666) },
+          { stackId: 32, units: new BN(5) },
+        ];
+        const balBefore = (await getAccount(provider.connection, userAta)).amount;
+
+        await gameProg.methods
+          .spawnBatch(entries)
+          .accounts({
+            gameConfig:        gameConfigPda,
+            gameParams:        gameParamsPda,
+            season:            seasonPda(),
+            agentProfile:      profilePda(user.publicKey),
+            seasonStanding:    standingPda(user.publicKey),
+            agentTokenAccount: userAta,
+            gameVault:         gameVaultKp.publicKey,
+            killMint:          killMintKp.publicKey,
+            agent:             user.publicKey,
+            delegation:        null,
+            authority:         user.publicKey,
+            tokenProgram:      TOKEN_PROGRAM_ID,
+            systemProgram:     SystemProgram.programId,
+          })
+          .remainingAccounts(entries.flatMap(e => [
+            { pubkey: stackPda(user.publicKey, e.stackId), isWritable: true, isSigner: false },
+            { pubkey: cellPda(e.stackId),                  isWritable: true, isSigner: false },
+          ]))
+          .signers([user])
+          .rpc();
+
+        for (const e of entries) {
+          const stack = await gameProg.account.agentStack.fetch(stackPda(user.publicKey, e.stackId));
+          assert.equal(stack.units.toString(), e.units.toString(), `units at stack ${e.stackId}`);
+          const cell = await gameProg.account.cellIndex.fetch(cellPda(e.stackId));
+          assert.include(cell.occupants.map((k: PublicKey) => k.toBase58()), user.publicKey.toBase58());
+        }
+        const reaperStack = await gameProg.account.agentStack.fetch(stackPda(user.publicKey, 31));
+        assert.equal(reaperStack.reapers.toString(), "1", "auto-reaper per cell");
+
+        const balAfter = (await getAccount(provider.connection, userAta)).amount;
+        const expected = new BN(681).mul(SPAWN_COST);
+        assert.equal((BigInt(balBefore) - BigInt(balAfter)).toString(), expected.toString(), "Σunits × SPAWN_COST debited");
+        console.log("  ✓ spawn_batch deployed 3 cells in one transaction");
+      });
+
+      it("a full batch of new cells fits the default compute budget; longer ones are rejected", async () => {
+        const MAX_SPAWN_BATCH = 8;
+        const [user, userAta] = await newUser(new BN(500_000_000_000));
+        const batch = (first: number, count: number) => {
+          const entries = Array.from({ length: count }, (_, i) => ({ stackId: first + i, units: new BN(1) }));
+          return gameProg.methods
+            .spawnBatch(entries)
+            .accounts({
+              gameConfig:        gameConfigPda,
+              gameParams:        gameParamsPda,
+              season:            seasonPda(),
+              agentProfile:      profilePda(user.publicKey),
+              seasonStanding:    standingPda(user.publicKey),
+              agentTokenAccount: userAta,
+              gameVault:         gameVaultKp.publicKey,
+              killMint:          killMintKp.publicKey,
+              agent:             user.publicKey,
+              delegation:        null,
+              authority:         user.publicKey,
+              tokenProgram:      TOKEN_PROGRAM_ID,
+              systemProgram:     SystemProgram.programId,
+            })
+            .remainingAccounts(entries.flatMap(e => [
+              { pubkey: stackPda(user.publicKey, e.stackId), isWritable: true, isSigner: false },
+              { pubkey: cellPda(e.stackId),                  isWritable: true, isSigner: false },
+            ]))
+            .signers([user])
+            .rpc();
+        };
+
+        // Every stack (and most cells) is new, so each entry pays both bump searches
+        await batch(180, MAX_SPAWN_BATCH);
+        for (let id = 180; id < 180 + MAX_SPAWN_BATCH; id++) {
+          const stack = await gameProg.account.agentStack.fetch(stackPda(user.publicKey, id));
+          assert.equal(stack.units.toString(), "1", `units at stack ${id}`);
+        }
+        // Reinforcing the same cells checks stored bumps instead
+        await batch(180, MAX_SPAWN_BATCH);
+
+        let threw = false;
+        try {
+          await batch(190, MAX_SPAWN_BATCH + 1);
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), "BatchTooLarge");
+        }
+        assert.isTrue(threw, "expected BatchTooLarge");
+        console.log("  ✓ 8 new cells in one spawn_batch; 9 rejected");
+      });
     });
 
     // ── Combat — defender wins ────────────────────────────────────────────────
@@ -498,6 +895,59 @@ describe("KILL System", () => {
         assert.isTrue(cfg.totalKills.toNumber() >= 1, "at least one kill recorded");
         console.log("  ✓ total_kills:", cfg.totalKills.toString());
       });
+
+      it("global economy counters track spawns, casualties, payouts and burns", async () => {
+        const cfg   = await gameProg.account.gameConfig.fetch(gameConfigPda);
+        const vault = await getAccount(provider.connection, gameVaultKp.publicKey);
+
+        assert.isTrue(cfg.totalUnitsKilled.toNumber() >= 10, "defender units destroyed counted");
+        assert.isTrue(cfg.totalKillBurned.gtn(0),            "burns counted");
+        // Vault balance is exactly what came in minus what left
+        const expected = BigInt(cfg.totalKillAdded.toString())
+          - BigInt(cfg.totalKillExtracted.toString())
+          - BigInt(cfg.totalKillBurned.toString());
+        assert.equal(vault.amount.toString(), expected.toString(), "added − extracted − burned = vault");
+        console.log("  ✓ GlobalStats counters reconcile with the vault balance");
+      });
+
+      it("agent profiles record spend, bounty, losses and kills for both sides", async () => {
+        const atk = await gameProg.account.agentProfile.fetch(profilePda(userB.publicKey));
+        const def = await gameProg.account.agentProfile.fetch(profilePda(userA.publicKey));
+
+        assert.equal(atk.killSpent.toString(), new BN(1000).mul(SPAWN_COST).toString(), "attacker spend = spawn cost");
+        assert.isTrue(atk.bountyAsAttacker.gtn(0),        "attacker bounty recorded");
+        assert.equal(atk.killsWon.toString(), "1",        "attacker kill recorded");
+        assert.equal(atk.unitsLost.toString(), "0",       "attacker lost nothing");
+
+        assert.equal(def.killSpent.toString(), new BN(10).mul(SPAWN_COST).toString(), "defender spend = spawn cost");
+        assert.equal(def.unitsLost.toString(), "10",      "defender losses recorded");
+        assert.equal(def.killsWon.toString(), "0",        "defender has no kills");
+        console.log("  ✓ AgentProfile P&L recorded for attacker and defender");
+      });
+
+      it("leaderboard ranks the paid attacker by lifetime bounty, highest first", async () => {
+        const board = await gameProg.account.leaderboard.fetch(leaderboardPda);
+        const atk   = await gameProg.account.agentProfile.fetch(profilePda(userB.publicKey));
+        const entry = board.entries.find((e: any) => e.agent.equals(userB.publicKey));
+        assert.isDefined(entry, "attacker listed");
+        assert.equal(
+          entry!.bounty.toString(),
+          atk.bountyAsAttacker.add(atk.bountyAsDefender).toString(),
+          "bounty = profile total"
+        );
+        for (let i = 1; i < board.entries.length; i++) {
+          assert.isTrue(board.entries[i - 1].bounty.gte(board.entries[i].bounty), "sorted descending");
+        }
+        console.log("  ✓ Leaderboard entries:", board.entries.length);
+      });
+
+      it("cell index lists the surviving attacker and drops the wiped-out defender", async () => {
+        const cell = await gameProg.account.cellIndex.fetch(cellPda(DEF_STACK));
+        const occupants = cell.occupants.map((k: PublicKey) => k.toBase58());
+        assert.include(occupants,    userB.publicKey.toBase58(), "attacker still occupies the cell");
+        assert.notInclude(occupants, userA.publicKey.toBase58(), "defender delisted");
+        console.log("  ✓ CellIndex occupants:", occupants.length);
+      });
     });
 
     // ── Combat — reaper power bonus ────────────────────────────────────────────
@@ -563,6 +1013,12 @@ describe("KILL System", () => {
 
         const dst = await gameProg.account.agentStack.fetch(stackPda(userD.publicKey, DST));
         assert.equal(dst.units.toString(), "10", "all 10 units at destination");
+
+        const srcCell = await gameProg.account.cellIndex.fetch(cellPda(SRC));
+        const dstCell = await gameProg.account.cellIndex.fetch(cellPda(DST));
+        const me = userD.publicKey.toBase58();
+        assert.notInclude(srcCell.occupants.map((k: PublicKey) => k.toBase58()), me, "delisted from emptied source cell");
+        assert.include(dstCell.occupants.map((k: PublicKey) => k.toBase58()),    me, "listed on destination cell");
         console.log("  ✓ Full move completed: source empty, 10 units at DST");
       });
 
@@ -624,13 +1080,612 @@ describe("KILL System", () => {
       });
     });
 
+    // ── Closing empty stacks ────────────────────────────────────────────────────
+    describe("Closing empty stacks", () => {
+      // Stacks: A=40 (4,0,1)  B=41 (5,0,1) — adjacent; fight at C=42
+      const A = 40;
+      const B = 41;
+      const C = 42;
+
+      let userE: Keypair, userEata: PublicKey;
+      let userF: Keypair, userFata: PublicKey;
+
+      async function closeStackFor(agent: Keypair, stackId: number) {
+        await gameProg.methods
+          .closeStack(season, stackId)
+          .accounts({
+            season:        seasonPda(),
+            agentStack:    stackPda(agent.publicKey, stackId),
+            cellIndex:     cellPda(stackId),
+            agent:         agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([agent])
+          .rpc();
+      }
+
+      before(async () => {
+        [userE, userEata] = await newUser(new BN(5_000_000_000_000));
+        [userF, userFata] = await newUser(new BN(5_000_000_000_000));
+        await spawnFor(userE, userEata, A, new BN(10));
+      });
+
+      it("close_stack rejects a stack that still holds units with StackNotEmpty", async () => {
+        let threw = false;
+        try {
+          await closeStackFor(userE, A);
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), "StackNotEmpty");
+        }
+        assert.isTrue(threw, "expected StackNotEmpty to be thrown");
+      });
+
+      it("move_units with close_empty closes the emptied source and refunds its rent", async () => {
+        const pda = stackPda(userE.publicKey, A);
+        const rent = await provider.connection.getBalance(pda);
+        assert.isTrue(rent > 0, "source stack holds rent");
+
+        await moveUnitsFor(userE, userEata, A, B, new BN(10), new BN(0), true);
+
+        assert.isNull(await provider.connection.getAccountInfo(pda), "source stack closed");
+        const srcCell = await gameProg.account.cellIndex.fetch(cellPda(A));
+        assert.notInclude(srcCell.occupants.map((k: PublicKey) => k.toBase58()), userE.publicKey.toBase58());
+        console.log("  ✓ Full move with close_empty closed the source stack");
+      });
+
+      it("close_stack closes an empty stack and returns its rent", async () => {
+        await moveUnitsFor(userE, userEata, B, A, new BN(10), new BN(0));
+        const pda = stackPda(userE.publicKey, B);
+        const rent = await provider.connection.getBalance(pda);
+        const before = await provider.connection.getBalance(userE.publicKey);
+
+        await closeStackFor(userE, B);
+
+        assert.isNull(await provider.connection.getAccountInfo(pda), "stack closed");
+        const after = await provider.connection.getBalance(userE.publicKey);
+        assert.isTrue(after > before, "rent returned to the agent (net of the tx fee)");
+        assert.isTrue(after - before <= rent, "no more than the stack's rent returned");
+
+        // The position can be re-used: spawning re-creates the stack.
+        await spawnFor(userE, userEata, B, new BN(3));
+        const respawned = await gameProg.account.agentStack.fetch(pda);
+        assert.equal(respawned.units.toString(), "3");
+        console.log("  ✓ close_stack reclaimed rent; re-spawn re-created the stack");
+      });
+
+      it("kill with close_empty closes the wiped-out defender, rent to the defender", async () => {
+        await spawnFor(userE, userEata, C, new BN(10));
+        await spawnFor(userF, userFata, C, new BN(1000));
+        const pda = stackPda(userE.publicKey, C);
+        const rent = await provider.connection.getBalance(pda);
+        const before = await provider.connection.getBalance(userE.publicKey);
+
+        await doKill(userF, userFata, userE.publicKey, userEata, C, new BN(1000), new BN(0), true);
+
+        assert.isNull(await provider.connection.getAccountInfo(pda), "defender stack closed");
+        const after = await provider.connection.getBalance(userE.publicKey);
+        assert.equal(after - before, rent, "defender received the rent");
+
+        const atk = await gameProg.account.agentStack.fetch(stackPda(userF.publicKey, C));
+        assert.equal(atk.units.toString(), "1000", "surviving attacker stack stays open");
+        const def = await gameProg.account.agentProfile.fetch(profilePda(userE.publicKey));
+        assert.equal(def.unitsLost.toString(), "10", "defender profile still records the loss");
+        console.log("  ✓ kill with close_empty closed the wiped-out defender stack");
+      });
+    });
+
+    // ── Delegated signers (session keys) ───────────────────────────────────────
+    describe("Delegated signers", () => {
+      // Stacks: A=50 (2,2,1)  B=51 (3,2,1) — adjacent
+      const A = 50;
+      const B = 51;
+      const CAP = new BN(15).mul(SPAWN_COST);
+
+      let owner: Keypair, ownerAta: PublicKey;
+      let victim: Keypair, victimAta: PublicKey;
+      const hot = Keypair.generate();
+
+      async function expectError(p: Promise<any>, code: string) {
+        let threw = false;
+        try {
+          await p;
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), code);
+        }
+        assert.isTrue(threw, `expected ${code} to be thrown`);
+      }
+
+      before(async () => {
+        [owner, ownerAta]   = await newUser(new BN(5_000_000_000_000));
+        [victim, victimAta] = await newUser(new BN(5_000_000_000_000));
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(hot.publicKey, 2 * LAMPORTS_PER_SOL)
+        );
+        const slot = await provider.connection.getSlot();
+        await gameProg.methods
+          .setDelegation(hot.publicKey, new BN(slot + 100_000), DELEGATE_SPAWN | DELEGATE_KILL, CAP)
+          .accounts({
+            gameConfig:        gameConfigPda,
+            delegation:        delegationPda(owner.publicKey),
+            ownerTokenAccount: ownerAta,
+            owner:             owner.publicKey,
+            tokenProgram:      TOKEN_PROGRAM_ID,
+            systemProgram:     SystemProgram.programId,
+          })
+          .signers([owner])
+          .rpc();
+      });
+
+      it("a delegate spawns for the owner, paid from the owner's tokens", async () => {
+        const balBefore = (await getAccount(provider.connection, ownerAta)).amount;
+        await spawnFor(owner, ownerAta, A, new BN(10), hot);
+
+        const stack = await gameProg.account.agentStack.fetch(stackPda(owner.publicKey, A));
+        assert.isTrue(stack.agent.equals(owner.publicKey), "stack belongs to the owner");
+        assert.equal(stack.units.toString(), "10", "units spawned");
+
+        const balAfter = (await getAccount(provider.connection, ownerAta)).amount;
+        const cost = new BN(10).mul(SPAWN_COST);
+        assert.equal((balBefore - balAfter).toString(), cost.toString(), "owner paid the spawn cost");
+
+        const d = await gameProg.account.delegation.fetch(delegationPda(owner.publicKey));
+        assert.equal(d.spent.toString(), cost.toString(), "spend tracked");
+        console.log("  ✓ Delegate spawned on the owner's behalf");
+      });
+
+      it("spending past the cap fails with DelegationCapExceeded", async () => {
+        await expectError(spawnFor(owner, ownerAta, A, new BN(10), hot), "DelegationCapExceeded");
+      });
+
+      it("an instruction outside the allowed mask fails with DelegationNotAllowed", async () => {
+        await expectError(
+          moveUnitsFor(owner, ownerAta, A, B, new BN(1), new BN(0), false, hot),
+          "DelegationNotAllowed"
+        );
+      });
+
+      it("a key that is not the delegate is rejected with Unauthorized", async () => {
+        const stranger = Keypair.generate();
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(stranger.publicKey, LAMPORTS_PER_SOL)
+        );
+        await expectError(spawnFor(owner, ownerAta, A, new BN(1), stranger), "Unauthorized");
+      });
+
+      it("a delegate kills for the owner; the bounty goes to the owner", async () => {
+        await spawnFor(victim, victimAta, A, new BN(1));
+        const balBefore = (await getAccount(provider.connection, ownerAta)).amount;
+        await doKill(owner, ownerAta, victim.publicKey, victimAta, A, new BN(10), new BN(0), false, hot);
+
+        const def = await gameProg.account.agentStack.fetch(stackPda(victim.publicKey, A));
+        assert.equal(def.units.toString(), "0", "victim wiped out");
+        const balAfter = (await getAccount(provider.connection, ownerAta)).amount;
+        assert.isTrue(balAfter >= balBefore, "payout (if any) credited to the owner");
+      });
+
+      it("revoke_delegation closes the PDA and the delegate loses access", async () => {
+        await gameProg.methods
+          .revokeDelegation()
+          .accounts({
+            gameConfig:        gameConfigPda,
+            delegation:        delegationPda(owner.publicKey),
+            ownerTokenAccount: ownerAta,
+            owner:             owner.publicKey,
+            tokenProgram:      TOKEN_PROGRAM_ID,
+          })
+          .signers([owner])
+          .rpc();
+        assert.isNull(await provider.connection.getAccountInfo(delegationPda(owner.publicKey)), "closed");
+        const ata = await getAccount(provider.connection, ownerAta);
+        assert.isNull(ata.delegate, "SPL approval revoked");
+
+        let threw = false;
+        try {
+          await spawnFor(owner, ownerAta, A, new BN(1), hot);
+        } catch {
+          threw = true;
+        }
+        assert.isTrue(threw, "revoked delegate cannot spawn");
+        console.log("  ✓ Delegation revoked");
+      });
+    });
+
+    // ── Commit-reveal kill ─────────────────────────────────────────────────────
+    describe("Commit-reveal kill", () => {
+      const STACK = 56;
+      const REVEAL_WINDOW = 150;
+
+      let atk: Keypair, atkAta: PublicKey;
+      let def: Keypair, defAta: PublicKey;
+
+      const commitmentPda = (agent: PublicKey, stackId: number) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("kill_commit"), stackPda(agent, stackId).toBuffer()],
+          gameProg.programId
+        )[0];
+
+      function strikeHash(stackId: number, defender: PublicKey, units: BN, reapers: BN, salt: Buffer): number[] {
+        const id = Buffer.alloc(2);
+        id.writeUInt16LE(stackId);
+        return Array.from(keccak_256(Buffer.concat([
+          id,
+          defender.toBuffer(),
+          units.toArrayLike(Buffer, "le", 8),
+          reapers.toArrayLike(Buffer, "le", 8),
+          salt,
+        ])));
+      }
+
+      async function commit(units: BN, hash: number[]) {
+        await gameProg.methods
+          .commitKill(STACK, units, new BN(0), hash)
+          .accounts({
+            gameConfig:    gameConfigPda,
+            season:        seasonPda(),
+            attackerStack: stackPda(atk.publicKey, STACK),
+            commitment:    commitmentPda(atk.publicKey, STACK),
+            attacker:      atk.publicKey,
+            delegation:    null,
+            authority:     atk.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([atk])
+          .rpc();
+      }
+
+      async function reveal(units: BN, salt: Buffer) {
+        await gameProg.methods
+          .revealKill(STACK, def.publicKey, units, new BN(0), Array.from(salt), NO_GUARDS)
+          .accounts({
+            gameConfig:           gameConfigPda,
+            gameParams:           gameParamsPda,
+            season:               seasonPda(),
+            attackerStack:        stackPda(atk.publicKey, STACK),
+            commitment:           commitmentPda(atk.publicKey, STACK),
+            defenderStack:        stackPda(def.publicKey, STACK),
+            cellIndex:            cellPda(STACK),
+            attackerProfile:      profilePda(atk.publicKey),
+            defenderProfile:      profilePda(def.publicKey),
+            attackerStanding:     standingPda(atk.publicKey),
+            defenderStanding:     standingPda(def.publicKey),
+            leaderboard:          leaderboardPda,
+            attackerTokenAccount: atkAta,
+            defenderTokenAccount: defAta,
+            gameVault:            gameVaultKp.publicKey,
+            killMint:             killMintKp.publicKey,
+            attacker:             atk.publicKey,
+            delegation:           null,
+            authority:            atk.publicKey,
+            defender:             def.publicKey,
+            tokenProgram:         TOKEN_PROGRAM_ID,
+            systemProgram:        SystemProgram.programId,
+          })
+          .signers([atk])
+          .rpc();
+      }
+
+      async function nextSlot(after: number) {
+        while ((await provider.connection.getSlot()) <= after) {
+          await new Promise((r) => setTimeout(r, 400));
+        }
+      }
+
+      before(async () => {
+        [atk, atkAta] = await newUser(new BN(5_000_000_000_000));
+        [def, defAta] = await newUser(new BN(5_000_000_000_000));
+        await spawnFor(atk, atkAta, STACK, new BN(20));
+        await spawnFor(def, defAta, STACK, new BN(1));
+      });
+
+      it("commit_kill locks the committed units on the stack", async () => {
+        const salt = Buffer.alloc(32, 7);
+        await commit(new BN(19), strikeHash(STACK, def.publicKey, new BN(19), new BN(0), salt));
+
+        const stack = await gameProg.account.agentStack.fetch(stackPda(atk.publicKey, STACK));
+        assert.equal(stack.units.toString(),       "20", "units stay on the stack");
+        assert.equal(stack.lockedUnits.toString(), "19", "committed units locked");
+        const c = await gameProg.account.killCommitment.fetch(commitmentPda(atk.publicKey, STACK));
+        assert.equal(c.units.toString(), "19", "commitment records the units");
+
+        let threw = false;
+        try {
+          await doKill(atk, atkAta, def.publicKey, defAta, STACK, new BN(2), new BN(0));
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), "InsufficientBalance");
+        }
+        assert.isTrue(threw, "a plain kill cannot send locked units");
+
+        await nextSlot(c.commitSlot.toNumber());
+        threw = false;
+        try {
+          await reveal(new BN(19), Buffer.alloc(32, 8));
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), "CommitmentMismatch");
+        }
+        assert.isTrue(threw, "wrong salt rejected");
+      });
+
+      it("reveal_kill resolves the strike and closes the commitment", async () => {
+        await reveal(new BN(19), Buffer.alloc(32, 7));
+
+        assert.isNull(
+          await provider.connection.getAccountInfo(commitmentPda(atk.publicKey, STACK)),
+          "commitment closed"
+        );
+        const defStack = await gameProg.account.agentStack.fetch(stackPda(def.publicKey, STACK));
+        assert.equal(defStack.units.toString(), "0", "defender wiped out");
+        const atkStack = await gameProg.account.agentStack.fetch(stackPda(atk.publicKey, STACK));
+        assert.equal(atkStack.units.toString(),       "20", "sent units survived");
+        assert.equal(atkStack.lockedUnits.toString(), "0",  "lock released");
+        console.log("  ✓ Committed strike revealed and resolved");
+      });
+
+      const refund = () =>
+        gameProg.methods
+          .refundCommit()
+          .accounts({
+            commitment:    commitmentPda(atk.publicKey, STACK),
+            attackerStack: stackPda(atk.publicKey, STACK),
+            attacker:      atk.publicKey,
+          })
+          .rpc();
+
+      it("an unrevealed commitment is refundable only after the window", async () => {
+        const salt = Buffer.alloc(32, 9);
+        await commit(new BN(5), strikeHash(STACK, def.publicKey, new BN(5), new BN(0), salt));
+
+        let threw = false;
+        try {
+          await refund();
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), "CommitmentActive");
+        }
+        assert.isTrue(threw, "expected CommitmentActive while the window is open");
+
+        const c = await gameProg.account.killCommitment.fetch(commitmentPda(atk.publicKey, STACK));
+        await nextSlot(c.commitSlot.toNumber() + REVEAL_WINDOW);
+        await refund();
+
+        const stack = await gameProg.account.agentStack.fetch(stackPda(atk.publicKey, STACK));
+        assert.equal(stack.units.toString(),       "20", "units untouched");
+        assert.equal(stack.lockedUnits.toString(), "0",  "lock released");
+        console.log("  ✓ Expired commitment refunded");
+      });
+
+      it("locked units still defend, and a refund after the stack is closed just closes the commitment", async () => {
+        const salt = Buffer.alloc(32, 10);
+        await commit(new BN(15), strikeHash(STACK, def.publicKey, new BN(15), new BN(0), salt));
+
+        // The defender strikes back; all 20 units (15 locked) take the hit
+        await spawnFor(def, defAta, STACK, new BN(100));
+        await doKill(def, defAta, atk.publicKey, atkAta, STACK, new BN(100), new BN(0), true);
+        assert.isNull(
+          await provider.connection.getAccountInfo(stackPda(atk.publicKey, STACK)),
+          "wiped-out stack closed"
+        );
+        const cell = await gameProg.account.cellIndex.fetch(cellPda(STACK));
+        assert.notInclude(cell.occupants.map((k: PublicKey) => k.toBase58()), atk.publicKey.toBase58());
+
+        const c = await gameProg.account.killCommitment.fetch(commitmentPda(atk.publicKey, STACK));
+        await nextSlot(c.commitSlot.toNumber() + REVEAL_WINDOW);
+        await refund();
+
+        assert.isNull(
+          await provider.connection.getAccountInfo(commitmentPda(atk.publicKey, STACK)),
+          "commitment closed"
+        );
+        assert.isNull(
+          await provider.connection.getAccountInfo(stackPda(atk.publicKey, STACK)),
+          "nothing re-created"
+        );
+        console.log("  ✓ Locked units were lost defending; refund closed the commitment");
+      });
+    });
+
+    // ── Kill slippage guards ────────────────────────────────────────────────────
+    describe("Kill slippage guards", () => {
+      const STACK = 57;
+      let atk: Keypair, atkAta: PublicKey;
+      let def: Keypair, defAta: PublicKey;
+
+      before(async () => {
+        [atk, atkAta] = await newUser(new BN(200_000_000_000));
+        [def, defAta] = await newUser(new BN(200_000_000_000));
+        await spawnFor(def, defAta, STACK, new BN(100));
+        await spawnFor(atk, atkAta, STACK, new BN(300));
+      });
+
+      async function expectGuardError(sent: BN, guards: any, code: string) {
+        let threw = false;
+        try {
+          await doKill(atk, atkAta, def.publicKey, defAta, STACK, sent, new BN(0), false, atk, guards);
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), code);
+        }
+        assert.isTrue(threw, `expected ${code}`);
+      }
+
+      it("reverts with a distinct error for each violated guard", async () => {
+        // Defender holds 100 raw power
+        await expectGuardError(new BN(300), { ...NO_GUARDS, maxDefenderPower: new BN(99) }, "DefenderTooStrong");
+        await expectGuardError(
+          new BN(300),
+          { ...NO_GUARDS, minAttackerBounty: new BN("18446744073709551615") },
+          "BountyBelowMinimum"
+        );
+        // 50*10=500 < 100*11=1100 → would lose
+        await expectGuardError(new BN(50), { ...NO_GUARDS, requireWin: true }, "StrikeWouldLose");
+
+        const defStack = await gameProg.account.agentStack.fetch(stackPda(def.publicKey, STACK));
+        assert.equal(defStack.units.toString(), "100", "reverted strikes leave the defender intact");
+        console.log("  ✓ Slippage guards rejected with distinct errors");
+      });
+
+      it("a strike within its guards resolves normally", async () => {
+        await doKill(atk, atkAta, def.publicKey, defAta, STACK, new BN(300), new BN(0), false, atk, {
+          maxDefenderPower:  new BN(100),
+          minAttackerBounty: new BN(1),
+          requireWin:        true,
+        });
+        const defStack = await gameProg.account.agentStack.fetch(stackPda(def.publicKey, STACK));
+        assert.equal(defStack.units.toString(), "0", "defender wiped out");
+        console.log("  ✓ Guarded strike resolved");
+      });
+    });
+
+    // ── Quote views ─────────────────────────────────────────────────────────────
+    describe("Quote views", () => {
+      const STACK = 58;
+
+      it("quote_kill and quote_bounty match the executed strike", async () => {
+        const [def, defAta] = await newUser(new BN(200_000_000_000));
+        const [atk, atkAta] = await newUser(new BN(200_000_000_000));
+        await spawnFor(def, defAta, STACK, new BN(100));
+        await spawnFor(atk, atkAta, STACK, new BN(300));
+
+        const bounty = await gameProg.methods
+          .quoteBounty()
+          .accounts({
+            gameConfig: gameConfigPda,
+            gameParams: gameParamsPda,
+            stack:      stackPda(def.publicKey, STACK),
+            gameVault:  gameVaultKp.publicKey,
+          })
+          .view();
+        assert.equal(bounty.power.toString(), "100");
+        assert.equal(bounty.multiplier.toString(), "1");
+        assert.equal(bounty.decayPct.toString(), "100");
+
+        const quote = await gameProg.methods
+          .quoteKill(new BN(300), new BN(0))
+          .accounts({
+            gameConfig:    gameConfigPda,
+            gameParams:    gameParamsPda,
+            attackerStack: stackPda(atk.publicKey, STACK),
+            defenderStack: stackPda(def.publicKey, STACK),
+            gameVault:     gameVaultKp.publicKey,
+          })
+          .view();
+        assert.isTrue(quote.won, "300 vs 100 wins");
+        assert.equal(quote.defenderUnitsLost.toString(), "100");
+        assert.equal(quote.pendingBounty.toString(), bounty.pendingBounty.toString());
+
+        const before = (await getAccount(provider.connection, atkAta)).amount;
+        await doKill(atk, atkAta, def.publicKey, defAta, STACK, new BN(300), new BN(0));
+        const after = (await getAccount(provider.connection, atkAta)).amount;
+        assert.equal(
+          (BigInt(after) - BigInt(before)).toString(),
+          quote.attackerBounty.toString(),
+          "payout equals the quote"
+        );
+        console.log(`  ✓ Quoted payout ${quote.attackerBounty.toString()} matched the strike`);
+      });
+    });
+
+    // ── Merkle airdrop claim ────────────────────────────────────────────────────
+    describe("Airdrop claim_units [EVM claim parity]", () => {
+      const STACK = 20;
+      const AIRDROP_AMOUNT = new BN(3_000_000_000); // 3,000 KILL → 150 units
+
+      const [airdropPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("airdrop")],
+        gameProg.programId
+      );
+      const claimPda = (claimer: PublicKey) => PublicKey.findProgramAddressSync(
+        [Buffer.from("airdrop_claim"), claimer.toBuffer()],
+        gameProg.programId
+      )[0];
+
+      // OpenZeppelin sorted-pair hashing, leaf = keccak256(pubkey)
+      const leaf = (k: PublicKey) => Buffer.from(keccak_256(k.toBuffer()));
+      const pair = (a: Buffer, b: Buffer) =>
+        Buffer.from(keccak_256(Buffer.compare(a, b) <= 0 ? Buffer.concat([a, b]) : Buffer.concat([b, a])));
+
+      let listed: Keypair, other: Keypair, outsider: Keypair;
+
+      async function claimFor(claimer: Keypair, proof: Buffer[]) {
+        await gameProg.methods
+          .claimUnits(proof.map(p => Array.from(p)), STACK)
+          .accounts({
+            gameConfig:    gameConfigPda,
+            gameParams:    gameParamsPda,
+            airdrop:       airdropPda,
+            season:        seasonPda(),
+            claimRecord:   claimPda(claimer.publicKey),
+            agentStack:    stackPda(claimer.publicKey, STACK),
+            cellIndex:     cellPda(STACK),
+            agentProfile:  profilePda(claimer.publicKey),
+            claimer:       claimer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([claimer])
+          .rpc();
+      }
+
+      before(async () => {
+        [listed]   = await newUser(new BN(0));
+        [other]    = await newUser(new BN(0));
+        [outsider] = await newUser(new BN(0));
+
+        const root = pair(leaf(listed.publicKey), leaf(other.publicKey));
+        await gameProg.methods
+          .setMerkleRoot(Array.from(root), AIRDROP_AMOUNT)
+          .accounts({
+            gameConfig:    gameConfigPda,
+            airdrop:          airdropPda,
+            paramsAuthority:  admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      });
+
+      it("listed wallet claims airdrop_amount / spawn_cost units on its chosen stack", async () => {
+        await claimFor(listed, [leaf(other.publicKey)]);
+
+        const stack = await gameProg.account.agentStack.fetch(stackPda(listed.publicKey, STACK));
+        assert.equal(stack.units.toString(), AIRDROP_AMOUNT.div(SPAWN_COST).toString(), "units granted");
+        const record = await gameProg.account.airdropClaim.fetch(claimPda(listed.publicKey));
+        assert.equal(record.stackId, STACK, "claim recorded");
+        console.log("  ✓ Airdrop claim spawned", stack.units.toString(), "units");
+      });
+
+      it("second claim from the same wallet fails", async () => {
+        let threw = false;
+        try {
+          await claimFor(listed, [leaf(other.publicKey)]);
+        } catch (_) {
+          threw = true;
+        }
+        assert.isTrue(threw, "expected claim record init to fail");
+        console.log("  ✓ Double claim rejected");
+      });
+
+      it("wallet outside the tree fails with InvalidProof", async () => {
+        let threw = false;
+        try {
+          await claimFor(outsider, [leaf(other.publicKey)]);
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), "InvalidProof");
+        }
+        assert.isTrue(threw, "expected InvalidProof error");
+        console.log("  ✓ Non-listed wallet rejected");
+      });
+    });
+
     // ── Admin functions ────────────────────────────────────────────────────────
     describe("Admin functions [tests 16-20]", () => {
 
       it("admin can pause the game (test 16 analog)", async () => {
         await gameProg.methods
           .setPaused(true)
-          .accounts({ gameConfig: gameConfigPda, admin: admin.publicKey })
+          .accounts({ gameConfig: gameConfigPda, pauser: admin.publicKey })
           .rpc();
 
         const cfg = await gameProg.account.gameConfig.fetch(gameConfigPda);
@@ -653,7 +1708,7 @@ describe("KILL System", () => {
       it("admin can unpause the game", async () => {
         await gameProg.methods
           .setPaused(false)
-          .accounts({ gameConfig: gameConfigPda, admin: admin.publicKey })
+          .accounts({ gameConfig: gameConfigPda, pauser: admin.publicKey })
           .rpc();
 
         const cfg = await gameProg.account.gameConfig.fetch(gameConfigPda);
@@ -661,6 +1716,46 @@ describe("KILL System", () => {
         console.log("  ✓ Game unpaused — gameplay resumed");
       });
 
+      it("pause mask freezes single instructions with distinct errors", async () => {
+        const PAUSE_SPAWN = 1, PAUSE_KILL = 4;
+        const setMask = (mask: number) =>
+          gameProg.methods
+            .setPauseMask(mask)
+            .accounts({ gameConfig: gameConfigPda, pauser: admin.publicKey })
+            .rpc();
+        const expectError = async (p: Promise<any>, code: string) => {
+          let threw = false;
+          try { await p; } catch (err: any) {
+            threw = true;
+            assert.include(err.toString(), code);
+          }
+          assert.isTrue(threw, `expected ${code}`);
+        };
+
+        // Stacks: 0 (admin's) and 1 — adjacent
+        const [user, userAta] = await newUser(new BN(100_000_000_000));
+        await spawnFor(user, userAta, 0, new BN(10));
+
+        // Kill frozen; spawning and moving still work.
+        await setMask(PAUSE_KILL);
+        await expectError(
+          doKill(user, userAta, admin.publicKey, adminAta, 0, new BN(10), new BN(0)),
+          "KillPaused"
+        );
+        await spawnFor(user, userAta, 0, new BN(1));
+
+        // New deposits frozen; players can still retreat.
+        await setMask(PAUSE_SPAWN);
+        await expectError(spawnFor(user, userAta, 0, new BN(1)), "SpawnPaused");
+        await moveUnitsFor(user, userAta, 0, 1, new BN(11), new BN(0));
+
+        await expectError(setMask(0x80), "InvalidParams");
+        await setMask(0);
+        const cfg = await gameProg.account.gameConfig.fetch(gameConfigPda);
+        assert.equal(cfg.pauseMask, 0, "mask cleared");
+        console.log("  ✓ Pause mask: kill-only and spawn-only freezes");
+      });
+
       it("pending bounty is non-zero (scales with power × SPAWN_COST) [test 18]", async () => {
         // Verify getPendingBounty view via the on-chain stack
         // We check the stack we spawned: admin at stack 0 had 666 units, 1 reaper
@@ -672,46 +1767,257 @@ describe("KILL System", () => {
         console.log("  ✓ Admin stack has power → pending bounty scales with age [test 18]");
       });
 
-      it("admin can withdraw from the game vault (test 19)", async () => {
-        const balBefore = (await getAccount(provider.connection, adminAta)).amount;
-        const amount    = new BN(1_000_000); // 1 KILL
+      it("admin can retune the economy with set_config", async () => {
+        const defaults = await gameProg.account.gameParams.fetch(gameParamsPda);
+        const args = {
+          spawnCost:          defaults.spawnCost,
+          moveCost:           new BN(50_000_000),
+          maxMultiplier:      defaults.maxMultiplier,
+          slotsPerMultiplier: defaults.slotsPerMultiplier,
+          globalCapBps:       defaults.globalCapBps,
+          burnBps:            defaults.burnBps,
+          reaperThreshold:    defaults.reaperThreshold,
+          blendSpawnSlot:     defaults.blendSpawnSlot,
+        };
 
         await gameProg.methods
-          .adminWithdraw(amount)
+          .setConfig(args)
+          .accounts({ gameConfig: gameConfigPda, gameParams: gameParamsPda, paramsAuthority: admin.publicKey })
+          .rpc();
+        let params = await gameProg.account.gameParams.fetch(gameParamsPda);
+        assert.equal(params.moveCost.toString(), "50000000", "move_cost updated");
+
+        // Restore defaults so later suites see the documented economy
+        await gameProg.methods
+          .setConfig({ ...args, moveCost: defaults.moveCost })
+          .accounts({ gameConfig: gameConfigPda, gameParams: gameParamsPda, paramsAuthority: admin.publicKey })
+          .rpc();
+        params = await gameProg.account.gameParams.fetch(gameParamsPda);
+        assert.equal(params.moveCost.toString(), MOVE_COST.toString(), "move_cost restored");
+        console.log("  ✓ set_config updated and restored move_cost");
+      });
+
+      it("set_config rejects out-of-bounds parameters with InvalidParams", async () => {
+        const params = await gameProg.account.gameParams.fetch(gameParamsPda);
+        let threw = false;
+        try {
+          await gameProg.methods
+            .setConfig({
+              spawnCost:          new BN(0),
+              moveCost:           params.moveCost,
+              maxMultiplier:      params.maxMultiplier,
+              slotsPerMultiplier: params.slotsPerMultiplier,
+              globalCapBps:       params.globalCapBps,
+              burnBps:            params.burnBps,
+              reaperThreshold:    params.reaperThreshold,
+              blendSpawnSlot:     params.blendSpawnSlot,
+            })
+            .accounts({ gameConfig: gameConfigPda, gameParams: gameParamsPda, paramsAuthority: admin.publicKey })
+            .rpc();
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), "InvalidParams");
+        }
+        assert.isTrue(threw, "expected InvalidParams error");
+        console.log("  ✓ Zero spawn_cost rejected");
+      });
+
+      it("blend_spawn_slot weights spawn_slot by power on reinforcement and merge", async () => {
+        const defaults = await gameProg.account.gameParams.fetch(gameParamsPda);
+        assert.isFalse(defaults.blendSpawnSlot, "off by default");
+        const setBlend = (blendSpawnSlot: boolean) =>
+          gameProg.methods
+            .setConfig({
+              spawnCost:          defaults.spawnCost,
+              moveCost:           defaults.moveCost,
+              maxMultiplier:      defaults.maxMultiplier,
+              slotsPerMultiplier: defaults.slotsPerMultiplier,
+              globalCapBps:       defaults.globalCapBps,
+              burnBps:            defaults.burnBps,
+              reaperThreshold:    defaults.reaperThreshold,
+              blendSpawnSlot,
+            })
+            .accounts({ gameConfig: gameConfigPda, gameParams: gameParamsPda, paramsAuthority: admin.publicKey })
+            .rpc();
+        const txSlot = async (sig: string) =>
+          BigInt((await provider.connection.getTransaction(sig, {
+            commitment: "confirmed",
+            maxSupportedTransactionVersion: 0,
+          }))!.slot);
+        const spawnSlotOf = async (user: Keypair, stackId: number) =>
+          BigInt((await gameProg.account.agentStack.fetch(stackPda(user.publicKey, stackId))).spawnSlot.toString());
+
+        await setBlend(true);
+        try {
+          // Stacks 20 and 21 — adjacent (x 2 → 3)
+          const [user, userAta] = await newUser(new BN(100_000_000_000));
+
+          // 666 units + 1 reaper twice: equal power, so the midpoint
+          await spawnFor(user, userAta, 20, new BN(666));
+          const first = await spawnSlotOf(user, 20);
+          const reinforced = await txSlot(await spawnFor(user, userAta, 20, new BN(666)));
+          assert.equal(await spawnSlotOf(user, 20), (first + reinforced) / 2n, "reinforcement blended");
+
+          // Merge 666 units (power 666) into a 1332-power stack
+          const merged = await spawnSlotOf(user, 20);
+          await spawnFor(user, userAta, 21, new BN(666));
+          const held = await spawnSlotOf(user, 21);
+          await moveUnitsFor(user, userAta, 20, 21, new BN(666), new BN(0));
+          assert.equal(
+            await spawnSlotOf(user, 21),
+            (1332n * held + 666n * merged) / 1998n,
+            "merge blended with the moved forces' spawn slot"
+          );
+          assert.equal(await spawnSlotOf(user, 20), merged, "source keeps its slot");
+        } finally {
+          await setBlend(false);
+        }
+        console.log("  ✓ blend_spawn_slot averaged spawn_slot on spawn and move");
+      });
+
+      // ── Timelocked, rate-limited vault withdrawals ──────────────────────────
+      const withdrawalPda = (id: BN) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("withdrawal"), id.toArrayLike(Buffer, "le", 8)],
+          gameProg.programId
+        )[0];
+
+      async function queueWithdraw(amount: BN, signer: Keypair = admin): Promise<BN> {
+        const id = (await gameProg.account.gameConfig.fetch(gameConfigPda)).withdrawalNonce;
+        await gameProg.methods
+          .queueWithdraw(amount)
           .accounts({
-            gameConfig:   gameConfigPda,
-            gameVault:    gameVaultKp.publicKey,
-            destination:  adminAta,
-            admin:        admin.publicKey,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            gameConfig:        gameConfigPda,
+            pendingWithdrawal: withdrawalPda(id),
+            destination:       adminAta,
+            treasurer:         signer.publicKey,
+            systemProgram:     SystemProgram.programId,
           })
+          .signers([signer])
           .rpc();
+        return id;
+      }
 
-        const balAfter = (await getAccount(provider.connection, adminAta)).amount;
+      async function executeWithdraw(id: BN) {
+        await gameProg.methods
+          .executeWithdraw(id)
+          .accounts({
+            gameConfig:        gameConfigPda,
+            pendingWithdrawal: withdrawalPda(id),
+            gameVault:         gameVaultKp.publicKey,
+            destination:       adminAta,
+            treasurer:         admin.publicKey,
+            tokenProgram:      TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+      }
+
+      async function cancelWithdraw(id: BN) {
+        await gameProg.methods
+          .cancelWithdraw(id)
+          .accounts({
+            gameConfig:        gameConfigPda,
+            pendingWithdrawal: withdrawalPda(id),
+            treasurer:         admin.publicKey,
+          })
+          .rpc();
+      }
+
+      it("queued withdrawal cannot execute before its delay; cancel closes it", async () => {
+        const cfg = await gameProg.account.gameConfig.fetch(gameConfigPda);
+        assert.isTrue(cfg.withdrawDelaySlots.toNumber() > 0, "default delay set at initialize");
+
+        const id = await queueWithdraw(new BN(1_000_000));
+        let threw = false;
+        try {
+          await executeWithdraw(id);
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), "WithdrawalLocked");
+        }
+        assert.isTrue(threw, "expected WithdrawalLocked");
+
+        await cancelWithdraw(id);
+        assert.isNull(await provider.connection.getAccountInfo(withdrawalPda(id)), "pending withdrawal closed");
+        console.log("  ✓ Early execute rejected; queued withdrawal cancelled");
+      });
+
+      async function setWithdrawLimits(delay: BN, cap: BN) {
+        await gameProg.methods
+          .setWithdrawLimits(delay, cap)
+          .accounts({ gameConfig: gameConfigPda, admin: admin.publicKey })
+          .rpc();
+      }
+
+      it("withdraw limits are bounded and staged behind the current delay (test 19)", async () => {
+        const MIN_DELAY = 54_000;
+        const MAX_CAP   = new BN("10000000000000");
+        for (const [delay, cap] of [
+          [new BN(2), new BN(5_000_000)],
+          [new BN(MIN_DELAY - 1), new BN(5_000_000)],
+          [new BN(MIN_DELAY), MAX_CAP.addn(1)],
+        ]) {
+          let threw = false;
+          try {
+            await setWithdrawLimits(delay, cap);
+          } catch (err: any) {
+            threw = true;
+            assert.include(err.toString(), "InvalidParams");
+          }
+          assert.isTrue(threw, `expected InvalidParams for ${delay}/${cap}`);
+        }
+
+        const before = await gameProg.account.gameConfig.fetch(gameConfigPda);
+        const slot   = await provider.connection.getSlot();
+        await setWithdrawLimits(new BN(MIN_DELAY), new BN(5_000_000));
+
+        const cfg = await gameProg.account.gameConfig.fetch(gameConfigPda);
+        assert.equal(cfg.withdrawDelaySlots.toString(), before.withdrawDelaySlots.toString(), "live delay unchanged");
+        assert.equal(cfg.withdrawEpochCap.toString(), before.withdrawEpochCap.toString(), "live cap unchanged");
+        assert.equal(cfg.stagedWithdrawDelaySlots.toNumber(), MIN_DELAY);
+        assert.equal(cfg.stagedWithdrawEpochCap.toNumber(), 5_000_000);
+        assert.isAtLeast(
+          cfg.withdrawLimitsEffectiveSlot.toNumber(),
+          slot + before.withdrawDelaySlots.toNumber(),
+          "staged limits wait out the current delay"
+        );
+
+        // Queued withdrawals keep using the live delay until the staged one applies.
+        const id = await queueWithdraw(new BN(1_000_000));
+        const pending = await gameProg.account.pendingWithdrawal.fetch(withdrawalPda(id));
         assert.equal(
-          (BigInt(balAfter) - BigInt(balBefore)).toString(),
-          amount.toString(),
-          "admin received withdrawal"
+          pending.executableSlot.sub(pending.queuedSlot).toString(),
+          before.withdrawDelaySlots.toString()
         );
-        console.log("  ✓ Admin withdrew 1 KILL from vault");
+        await cancelWithdraw(id);
+        console.log("  ✓ Withdraw limits bounded; new limits staged behind the current delay");
       });
 
-      it("non-admin withdraw fails with Unauthorized (test 20)", async () => {
+      it("withdrawals beyond the per-epoch cap fail with WithdrawCapExceeded", async () => {
+        const cfg = await gameProg.account.gameConfig.fetch(gameConfigPda);
+
+        // Single request above the live cap is rejected at queue time.
+        let threw = false;
+        try {
+          await queueWithdraw(cfg.withdrawEpochCap.addn(1));
+        } catch (err: any) {
+          threw = true;
+          assert.include(err.toString(), "WithdrawCapExceeded");
+        }
+        assert.isTrue(threw, "expected WithdrawCapExceeded at queue");
+
+        // The staged 5 KILL cap is not live yet, so 6 KILL still queues.
+        const id = await queueWithdraw(new BN(6_000_000));
+        await cancelWithdraw(id);
+        console.log("  ✓ Per-epoch withdrawal cap enforced");
+      });
+
+      it("non-admin queue_withdraw fails with Unauthorized (test 20)", async () => {
         const [impostor] = await newUser(new BN(0));
 
         let threw = false;
         try {
-          await gameProg.methods
-            .adminWithdraw(new BN(1))
-            .accounts({
-              gameConfig:   gameConfigPda,
-              gameVault:    gameVaultKp.publicKey,
-              destination:  adminAta,
-              admin:        impostor.publicKey,
-              tokenProgram: TOKEN_PROGRAM_ID,
-            })
-            .signers([impostor])
-            .rpc();
+          await queueWithdraw(new BN(1), impostor);
         } catch (err: any) {
           threw = true;
           assert.include(err.toString(), "Unauthorized");
@@ -719,6 +2025,59 @@ describe("KILL System", () => {
         assert.isTrue(threw, "expected Unauthorized error");
         console.log("  ✓ Non-admin withdraw correctly rejected");
       });
+
+      it("roles: pauser, treasurer and params authority are separate keys", async () => {
+        const [hot]  = await newUser(new BN(0));
+        const [cold] = await newUser(new BN(0));
+        const setRole = (method: string, key: PublicKey) =>
+          (gameProg.methods as any)[method](key)
+            .accounts({ gameConfig: gameConfigPda, admin: admin.publicKey })
+            .rpc();
+        const expectUnauthorized = async (p: Promise<any>, what: string) => {
+          let threw = false;
+          try { await p; } catch (err: any) {
+            threw = true;
+            assert.include(err.toString(), "Unauthorized");
+          }
+          assert.isTrue(threw, what);
+        };
+
+        await setRole("setPauser", hot.publicKey);
+        await setRole("setTreasurer", cold.publicKey);
+        let cfg = await gameProg.account.gameConfig.fetch(gameConfigPda);
+        assert.equal(cfg.pauser.toBase58(),    hot.publicKey.toBase58(),  "pauser assigned");
+        assert.equal(cfg.treasurer.toBase58(), cold.publicKey.toBase58(), "treasurer assigned");
+
+        // The hot key can pause and unpause ...
+        for (const paused of [true, false]) {
+          await gameProg.methods
+            .setPaused(paused)
+            .accounts({ gameConfig: gameConfigPda, pauser: hot.publicKey })
+            .signers([hot])
+            .rpc();
+        }
+        // ... but the admin no longer can, and the hot key cannot touch the treasury.
+        await expectUnauthorized(
+          gameProg.methods.setPaused(true)
+            .accounts({ gameConfig: gameConfigPda, pauser: admin.publicKey })
+            .rpc(),
+          "admin is not the pauser"
+        );
+        await expectUnauthorized(queueWithdraw(new BN(1), hot), "pauser is not the treasurer");
+        await expectUnauthorized(queueWithdraw(new BN(1)), "admin is not the treasurer");
+
+        // Hand the roles back for later tests.
+        await setRole("setPauser", admin.publicKey);
+        await setRole("setTreasurer", admin.publicKey);
+        cfg = await gameProg.account.gameConfig.fetch(gameConfigPda);
+        assert.equal(cfg.pauser.toBase58(), admin.publicKey.toBase58(), "pauser restored");
+        console.log("  ✓ Role checks: hot key pauses, only the treasurer withdraws");
+      });
+
+      it("propose_admin / accept_admin — control moves only after the new key signs", async () => {
+        await adminHandoverRoundTrip(gameProg, "gameConfig", gameConfigPda);
+        console.log("  ✓ Two-step game admin handover");
+      });
     });
 
   }); // end kill_game
@@ -936,6 +2295,156 @@ describe("KILL System", () => {
     });
   });
 
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Seasons — runs last against kill_game: it ends season 1
+  // ═══════════════════════════════════════════════════════════════════════════
+  describe("Seasons", () => {
+    const STACK = 60;
+    let user: Keypair, userAta: PublicKey;
+
+    async function setSeasonEnd(endSlot: number) {
+      await gameProg.methods
+        .setSeasonEnd(new BN(endSlot))
+        .accounts({
+          gameConfig:      gameConfigPda,
+          season:          seasonPda(),
+          paramsAuthority: admin.publicKey,
+        })
+        .rpc();
+    }
+
+    async function expectSetSeasonEndFails(endSlot: number, code: string) {
+      let threw = false;
+      try {
+        await setSeasonEnd(endSlot);
+      } catch (err: any) {
+        threw = true;
+        assert.include(err.toString(), code);
+      }
+      assert.isTrue(threw, `expected ${code} to be thrown`);
+    }
+
+    before(async () => {
+      [user, userAta] = await newUser(new BN(5_000_000_000_000));
+      await spawnFor(user, userAta, STACK, new BN(10));
+    });
+
+    it("gameplay records a SeasonStanding and counts the agent", async () => {
+      const standing = await gameProg.account.seasonStanding.fetch(standingPda(user.publicKey));
+      assert.equal(standing.season, 1, "standing season");
+      assert.equal(standing.killSpent.toString(), new BN(10).mul(SPAWN_COST).toString(), "kill_spent");
+      assert.isFalse(standing.finalized, "not final yet");
+      const s1 = await gameProg.account.season.fetch(seasonPda());
+      assert.isTrue(s1.agents > 0, "agents counted");
+    });
+
+    it("end_season before the end slot fails with SeasonNotOver", async () => {
+      let threw = false;
+      try {
+        await gameProg.methods.endSeason(season).accounts({ season: seasonPda() }).rpc();
+      } catch (err: any) {
+        threw = true;
+        assert.include(err.toString(), "SeasonNotOver");
+      }
+      assert.isTrue(threw, "expected SeasonNotOver to be thrown");
+    });
+
+    it("set_season_end cannot move the end slot into the past", async () => {
+      await expectSetSeasonEndFails((await provider.connection.getSlot()) - 10, "InvalidParams");
+    });
+
+    it("after the end slot gameplay fails with SeasonInactive", async () => {
+      const endSlot = (await provider.connection.getSlot()) + 2;
+      await setSeasonEnd(endSlot);
+      while ((await provider.connection.getSlot()) < endSlot) {
+        await new Promise((r) => setTimeout(r, 400));
+      }
+      // Past its end slot the season is final — it cannot be reopened
+      await expectSetSeasonEndFails(endSlot + 1_000, "SeasonInactive");
+
+      let threw = false;
+      try {
+        await spawnFor(user, userAta, STACK, new BN(1));
+      } catch (err: any) {
+        threw = true;
+        assert.include(err.toString(), "SeasonInactive");
+      }
+      assert.isTrue(threw, "expected SeasonInactive to be thrown");
+    });
+
+    it("end_season freezes the season and finalizes standings", async () => {
+      await gameProg.methods
+        .endSeason(season)
+        .accounts({ season: seasonPda() })
+        .remainingAccounts([
+          { pubkey: standingPda(user.publicKey), isWritable: true, isSigner: false },
+        ])
+        .rpc();
+
+      const s1 = await gameProg.account.season.fetch(seasonPda());
+      assert.isTrue(s1.ended, "season ended");
+      assert.equal(s1.resultsRecorded, 1, "one result recorded");
+      const standing = await gameProg.account.seasonStanding.fetch(standingPda(user.publicKey));
+      assert.isTrue(standing.finalized, "standing final");
+
+      // Repeating is harmless — already-final standings are skipped
+      await gameProg.methods
+        .endSeason(season)
+        .accounts({ season: seasonPda() })
+        .remainingAccounts([
+          { pubkey: standingPda(user.publicKey), isWritable: true, isSigner: false },
+        ])
+        .rpc();
+      const again = await gameProg.account.season.fetch(seasonPda());
+      assert.equal(again.resultsRecorded, 1, "not double counted");
+      console.log("  ✓ Season 1 ended and standings finalized");
+    });
+
+    it("a stack from an ended season can be closed while it still holds units", async () => {
+      const pda = stackPda(user.publicKey, STACK);
+      await gameProg.methods
+        .closeStack(season, STACK)
+        .accounts({
+          season:        seasonPda(),
+          agentStack:    pda,
+          cellIndex:     cellPda(STACK),
+          agent:         user.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.isNull(await provider.connection.getAccountInfo(pda), "stack closed");
+      const cell = await gameProg.account.cellIndex.fetch(cellPda(STACK));
+      assert.notInclude(
+        cell.occupants.map((k: PublicKey) => k.toBase58()),
+        user.publicKey.toBase58(),
+        "closed stack delisted"
+      );
+    });
+
+    it("start_season opens season 2 on a fresh grid", async () => {
+      const slot = await provider.connection.getSlot();
+      await gameProg.methods
+        .startSeason(new BN(slot), new BN(slot + 10_000_000))
+        .accounts({
+          gameConfig:      gameConfigPda,
+          season:          seasonPda(2),
+          paramsAuthority: admin.publicKey,
+          systemProgram:   SystemProgram.programId,
+        })
+        .rpc();
+      season = 2;
+
+      await spawnFor(user, userAta, STACK, new BN(5));
+      const stack = await gameProg.account.agentStack.fetch(stackPda(user.publicKey, STACK));
+      assert.equal(stack.season, 2, "stack belongs to season 2");
+      assert.equal(stack.units.toString(), "5", "fresh stack — season 1 units not carried over");
+      const cell = await gameProg.account.cellIndex.fetch(cellPda(STACK));
+      assert.equal(cell.occupants.length, 1, "fresh cell index");
+      console.log("  ✓ Season 2 started on a fresh grid");
+    });
+  });
+
   // ═══════════════════════════════════════════════════════════════════════════
   // 4. kill_faucet
   // ═══════════════════════════════════════════════════════════════════════════
@@ -991,5 +2500,10 @@ describe("KILL System", () => {
       );
       console.log("  ✓ Claimed 10 KILL from faucet (10% of 100)");
     });
+
+    it("propose_admin / accept_admin — control moves only after the new key signs", async () => {
+      await adminHandoverRoundTrip(faucetProg, "faucetConfig", faucetConfigPda);
+      console.log("  ✓ Two-step faucet admin handover");
+    });
   });
 });
@@ -80,11 +80,38 @@ function gameConfigPDA() {
     );
 }
 
-function agentStackPDA(agentPubkey, stackId) {
+// Stacks and cells are season-scoped; pass gameConfig.currentSeason for live play.
+function seasonBuf(season) {
+    const buf = Buffer.alloc(4);
+    buf.writeUInt32LE(season);
+    return buf;
+}
+
+function seasonPDA(season) {
+    return web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("season"), seasonBuf(season)], GAME_ID
+    );
+}
+
+function seasonStandingPDA(season, agentPubkey) {
+    return web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("season_standing"), seasonBuf(season), agentPubkey.toBuffer()], GAME_ID
+    );
+}
+
+function agentStackPDA(season, agentPubkey, stackId) {
+    const idBuf = Buffer.alloc(2);
+    idBuf.writeUInt16LE(stackId);
+    return web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("agent_stack"), seasonBuf(season), agentPubkey.toBuffer(), idBuf], GAME_ID
+    );
+}
+
+function cellIndexPDA(season, stackId) {
     const idBuf = Buffer.alloc(2);
     idBuf.writeUInt16LE(stackId);
     return web3.PublicKey.findProgramAddressSync(
-        [Buffer.from("agent_stack"), agentPubkey.toBuffer(), idBuf], GAME_ID
+        [Buffer.from("cell_index"), seasonBuf(season), idBuf], GAME_ID
     );
 }
 
@@ -137,7 +164,7 @@ async function setup() {
         myKillATA:      killATA(wallet.publicKey),
         gameConfigAddr: gameConfigPDA()[0],
         // helpers
-        gameConfigPDA, agentStackPDA, faucetConfigPDA, claimRecordPDA, killATA,
+        gameConfigPDA, seasonPDA, seasonStandingPDA, agentStackPDA, cellIndexPDA, faucetConfigPDA, claimRecordPDA, killATA,
         fmtKill, fmtSol, txLink,
         KILL_MINT, DECIMALS,
         cfg
@@ -145,5 +172,5 @@ async function setup() {
 }
 
 module.exports = { setup, loadWallet, makeProvider, loadPrograms,
-    gameConfigPDA, agentStackPDA, faucetConfigPDA, claimRecordPDA, killATA,
+    gameConfigPDA, seasonPDA, seasonStandingPDA, agentStackPDA, cellIndexPDA, faucetConfigPDA, claimRecordPDA, killATA,
     fmtKill, fmtSol, txLink, KILL_MINT, DECIMALS, cfg };
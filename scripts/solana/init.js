@@ -8,11 +8,14 @@
 //   1. Generates the KILL SPL mint keypair (saved to ~/.config/solana/kill-mint.json)
 //   2. initialize_token  — creates the SPL mint with 6 decimals, PDA mint authority
 //   3. initialize_game   — creates GameConfig PDA + game vault token account
-//   4. initialize_faucet — creates FaucetConfig PDA + faucet vault token account
+//   4. initialize_params — creates GameParams PDA with the default economy
+//   5. start_season      — opens season 1 (SEASON_SLOTS long, ~30 days by default)
+//   6. initialize_leaderboard — creates the Leaderboard PDA (top agents by bounty)
+//   7. initialize_faucet — creates FaucetConfig PDA + faucet vault token account
 //
 // Re-running is safe: each step is skipped if the account already exists.
 
-const { setup, gameConfigPDA, faucetConfigPDA, cfg } = require("./common");
+const { setup, gameConfigPDA, seasonPDA, faucetConfigPDA, cfg } = require("./common");
 const CONFIG_PATH = path.join(__dirname, "config.json");
 const anchor = require("@coral-xyz/anchor");
 const { web3 } = anchor;
@@ -20,6 +23,9 @@ const { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } = require("@solana/spl-t
 const fs   = require("fs");
 const path = require("path");
 
+// Season length in slots (~400 ms each); override with SEASON_SLOTS=<n>
+const SEASON_SLOTS = parseInt(process.env.SEASON_SLOTS || "6480000");
+
 const MINT_KEYPAIR_PATH = path.join(
     (process.env.HOME || "~"),
     ".config/solana/kill-mint.json"
@@ -58,6 +64,8 @@ async function main() {
 
     const [tokenConfig]  = web3.PublicKey.findProgramAddressSync([Buffer.from("token_config")],  KILL_TOKEN_PROGRAM_ID);
     const [gameConfig]   = web3.PublicKey.findProgramAddressSync([Buffer.from("game_config")],    KILL_GAME_ID);
+    const [gameParams]   = web3.PublicKey.findProgramAddressSync([Buffer.from("game_params")],    KILL_GAME_ID);
+    const [leaderboard]  = web3.PublicKey.findProgramAddressSync([Buffer.from("leaderboard")],    KILL_GAME_ID);
     const [faucetConfig] = web3.PublicKey.findProgramAddressSync([Buffer.from("faucet_config")],  KILL_FAUCET_ID);
 
     // New keypairs for the vault token accounts (signers on init)
@@ -110,7 +118,68 @@ async function main() {
         console.log(`   Tx         : ${txLink(tx)}`);
     }
 
-    // ── 3. initialize_faucet ──────────────────────────────────────────────────
+    // ── 3. initialize_params ──────────────────────────────────────────────────
+    const paramsExist = await connection.getAccountInfo(gameParams);
+    if (paramsExist) {
+        console.log("✅ kill_game params already initialized — skipping");
+    } else {
+        console.log("⏳ Initializing kill_game params...");
+        const tx = await killGame.methods
+            .initializeParams()
+            .accounts({
+                gameConfig,
+                gameParams,
+                admin:         wallet.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .signers([wallet])
+            .rpc();
+        console.log(`✅ kill_game params initialized`);
+        console.log(`   Tx : ${txLink(tx)}`);
+    }
+
+    // ── 4. start_season ───────────────────────────────────────────────────────
+    const gc = await killGame.account.gameConfig.fetch(gameConfig);
+    if (gc.currentSeason > 0) {
+        console.log(`✅ season ${gc.currentSeason} already started — skipping`);
+    } else {
+        console.log("⏳ Starting season 1...");
+        const slot = await connection.getSlot();
+        const tx = await killGame.methods
+            .startSeason(new anchor.BN(slot), new anchor.BN(slot + SEASON_SLOTS))
+            .accounts({
+                gameConfig,
+                season:          seasonPDA(1)[0],
+                paramsAuthority: wallet.publicKey,
+                systemProgram:   web3.SystemProgram.programId,
+            })
+            .signers([wallet])
+            .rpc();
+        console.log(`✅ season 1 started (ends at slot ${slot + SEASON_SLOTS})`);
+        console.log(`   Tx : ${txLink(tx)}`);
+    }
+
+    // ── 5. initialize_leaderboard ─────────────────────────────────────────────
+    const boardExists = await connection.getAccountInfo(leaderboard);
+    if (boardExists) {
+        console.log("✅ kill_game leaderboard already initialized — skipping");
+    } else {
+        console.log("⏳ Initializing kill_game leaderboard...");
+        const tx = await killGame.methods
+            .initializeLeaderboard()
+            .accounts({
+                gameConfig,
+                leaderboard,
+                admin:         wallet.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .signers([wallet])
+            .rpc();
+        console.log(`✅ kill_game leaderboard initialized`);
+        console.log(`   Tx : ${txLink(tx)}`);
+    }
+
+    // ── 6. initialize_faucet ──────────────────────────────────────────────────
     const faucetExists = await connection.getAccountInfo(faucetConfig);
     if (faucetExists) {
         console.log("✅ kill_faucet already initialized — skipping");
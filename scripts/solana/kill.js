@@ -2,6 +2,7 @@
 // node scripts/solana/kill.js <stack_id> <defender_pubkey> <stack_id> <sent_units> [sent_reapers]
 // Attacks a defender on the same stack. Attacker and defender must share the same stack_id.
 // Win → collect bounty. Lose → sent units lost.
+// Optional slippage guards via env: MAX_DEF_POWER, MIN_BOUNTY (raw KILL), REQUIRE_WIN=1.
 //
 // Example:
 //   node scripts/solana/kill.js 35 <defender_wallet_address> 35 500000 0
@@ -23,6 +24,12 @@ async function main() {
         process.exit(1);
     }
 
+    const guards = {
+        maxDefenderPower:  process.env.MAX_DEF_POWER ? new BN(process.env.MAX_DEF_POWER) : null,
+        minAttackerBounty: process.env.MIN_BOUNTY ? new BN(process.env.MIN_BOUNTY) : null,
+        requireWin:        process.env.REQUIRE_WIN === "1",
+    };
+
     const { wallet, connection, killGame, KILL_MINT, txLink } = await setup();
     const { web3 } = require("@coral-xyz/anchor");
     const defenderPubkey = new web3.PublicKey(defenderKey);
@@ -39,22 +46,25 @@ async function main() {
     );
 
     const [gameConfig]      = gameConfigPDA();
-    const [attackerStack]   = agentStackPDA(wallet.publicKey, attackerStackId);
-    const [defenderStack]   = agentStackPDA(defenderPubkey,   defenderStackId);
-
     const gc = await killGame.account.gameConfig.fetch(gameConfig);
 
+    const [attackerStack]   = agentStackPDA(gc.currentSeason, wallet.publicKey, attackerStackId);
+    const [defenderStack]   = agentStackPDA(gc.currentSeason, defenderPubkey,   defenderStackId);
+
     const tx = await killGame.methods
-        .kill(attackerStackId, defenderStackId, sentUnits, sentReapers)
+        .kill(attackerStackId, defenderStackId, sentUnits, sentReapers, false, guards)
         .accounts({
             gameConfig,
             attackerStack,
+            commitment:           null,
             defenderStack,
             attackerTokenAccount: attackerTokenAccount.address,
             defenderTokenAccount: defenderTokenAccount.address,
             gameVault:            gc.gameVault,
             killMint:             KILL_MINT,
             attacker:             wallet.publicKey,
+            delegation:           null,
+            authority:            wallet.publicKey,
             defender:             defenderPubkey,
         })
         .signers([wallet])
@@ -29,11 +29,11 @@ async function main() {
     );
 
     const [gameConfig]  = gameConfigPDA();
-    const [fromStack]   = agentStackPDA(wallet.publicKey, fromId);
-    const [toStack]     = agentStackPDA(wallet.publicKey, toId);
-
     const gc = await killGame.account.gameConfig.fetch(gameConfig);
 
+    const [fromStack]   = agentStackPDA(gc.currentSeason, wallet.publicKey, fromId);
+    const [toStack]     = agentStackPDA(gc.currentSeason, wallet.publicKey, toId);
+
     const tx = await killGame.methods
         .moveUnits(fromId, toId)
         .accounts({
@@ -44,6 +44,8 @@ async function main() {
             gameVault:         gc.gameVault,
             killMint:          KILL_MINT,
             agent:             wallet.publicKey,
+            delegation:        null,
+            authority:         wallet.publicKey,
         })
         .signers([wallet])
         .rpc();
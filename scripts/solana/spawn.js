@@ -2,7 +2,8 @@
 // node scripts/solana/spawn.js <stack_id> <units>
 // Spawns or reinforces a stack at the given grid position (0–215).
 // Costs 20 KILL per unit (SPAWN_COST × units on-chain).
-// One free Reaper is granted automatically per 666 units spawned.
+// One free Reaper is granted automatically per 666 units spawned onto the stack,
+// counted across reinforcements (665 + 1 units also earn one).
 //
 // Example:
 //   node scripts/solana/spawn.js 0 666        — spawn 666 units + 1 free reaper at stack 0
@@ -28,12 +29,24 @@ async function main() {
 
     const costPerUnit = cfg.constants.spawn_cost;       // 20 KILL (human units)
     const totalCost   = Number(units) * costPerUnit;    // already in KILL
-    const autoReapers = units / REAPER_THRESHOLD;
+
+    // Fetch game_vault and the current season from GameConfig
+    const [gameConfig]  = gameConfigPDA();
+    const gc = await killGame.account.gameConfig.fetch(gameConfig);
+
+    // Derive PDAs
+    const [agentStack]  = agentStackPDA(gc.currentSeason, wallet.publicKey, stackId);
+
+    // Units already banked toward the next reaper (an emptied stack starts over)
+    const existing = await killGame.account.agentStack.fetchNullable(agentStack);
+    const occupied = existing && (!existing.units.isZero() || !existing.reapers.isZero());
+    const progress = occupied ? BigInt(existing.reaperProgress.toString()) : 0n;
+    const autoReapers = (progress + units) / REAPER_THRESHOLD;
 
     console.log(`\nWallet      : ${wallet.publicKey.toBase58()}`);
     console.log(`Stack ID    : ${stackId}`);
     console.log(`Units       : ${units.toLocaleString()}`);
-    console.log(`Auto-reapers: ${autoReapers} (1 per 666 units)`);
+    console.log(`Auto-reapers: ${autoReapers} (1 per 666 units, ${progress} already banked)`);
     console.log(`Cost        : ${totalCost.toLocaleString()} KILL (${costPerUnit} KILL × ${units} units)\n`);
 
     // Ensure the agent has an ATA for KILL
@@ -41,13 +54,6 @@ async function main() {
         connection, wallet, KILL_MINT, wallet.publicKey
     );
 
-    // Derive PDAs
-    const [gameConfig]  = gameConfigPDA();
-    const [agentStack]  = agentStackPDA(wallet.publicKey, stackId);
-
-    // Fetch game_vault from GameConfig
-    const gc = await killGame.account.gameConfig.fetch(gameConfig);
-
     const tx = await killGame.methods
         .spawn(stackId, new anchor.BN(units.toString()))
         .accounts({
@@ -57,6 +63,8 @@ async function main() {
             gameVault:         gc.gameVault,
             killMint:          KILL_MINT,
             agent:             wallet.publicKey,
+            delegation:        null,
+            authority:         wallet.publicKey,
         })
         .signers([wallet])
         .rpc();
@@ -1,6 +1,6 @@
 "use strict";
 // node scripts/solana/stacks.js [wallet_pubkey]
-// Lists all on-chain AgentStack accounts for a wallet (defaults to your keypair).
+// Lists the current season's AgentStack accounts for a wallet (defaults to your keypair).
 //
 // Example:
 //   node scripts/solana/stacks.js
@@ -10,7 +10,7 @@ const { setup } = require("./common");
 const anchor = require("@coral-xyz/anchor");
 
 async function main() {
-    const { wallet, killGame } = await setup();
+    const { wallet, killGame, gameConfigAddr } = await setup();
     const { web3 } = require("@coral-xyz/anchor");
 
     // Filter out --wallet <name> flag so it doesn't get parsed as the target pubkey
@@ -23,15 +23,16 @@ async function main() {
 
     console.log(`\nStacks for: ${target.toBase58()}\n`);
 
-    // Fetch all AgentStack accounts owned by this agent
-    const stacks = await killGame.account.agentStack.all([
+    // Fetch all AgentStack accounts owned by this agent, keeping the current season's
+    const gc = await killGame.account.gameConfig.fetch(gameConfigAddr);
+    const stacks = (await killGame.account.agentStack.all([
         {
             memcmp: {
                 offset: 8,           // skip 8-byte Anchor discriminator
                 bytes:  target.toBase58()
             }
         }
-    ]);
+    ])).filter(({ account }) => account.season === gc.currentSeason);
 
     if (stacks.length === 0) {
         console.log("  No stacks found — spawn first.\n");
@@ -41,14 +42,15 @@ async function main() {
     // Grid coords from stack_id:  x = id%6, y = (id/6)%6, z = id/36
     const coord = id => `(${id%6},${Math.floor(id/6)%6},${Math.floor(id/36)})`;
 
-    console.log(`${"ID".padEnd(5)} ${"XYZ".padEnd(9)} ${"Units".padStart(12)} ${"Reapers".padStart(10)}`);
-    console.log("─".repeat(40));
+    console.log(`${"ID".padEnd(5)} ${"XYZ".padEnd(9)} ${"Units".padStart(12)} ${"Reapers".padStart(10)} ${"Progress".padStart(10)}`);
+    console.log("─".repeat(51));
     stacks
         .sort((a, b) => a.account.stackId - b.account.stackId)
         .forEach(({ account: s }) => {
             console.log(
                 `${String(s.stackId).padEnd(5)} ${coord(s.stackId).padEnd(9)} ` +
-                `${s.units.toString().padStart(12)} ${s.reapers.toString().padStart(10)}`
+                `${s.units.toString().padStart(12)} ${s.reapers.toString().padStart(10)} ` +
+                `${s.reaperProgress.toString().padStart(10)}`
             );
         });
     console.log();
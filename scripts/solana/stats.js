@@ -111,8 +111,9 @@ function fmtSol(lamports) {
 
     // ── Vault balances ───────────────────────────────────────────────────────
     console.log(`${CYA}── Vault Balances ──────────────────────────────────────────────────${RES}`);
+    let gc = null;
     try {
-        const gc = await killGame.account.gameConfig.fetch(gameConfigAddr);
+        gc = await killGame.account.gameConfig.fetch(gameConfigAddr);
         const { getAccount } = require("@solana/spl-token");
         const gameVaultAcct = await getAccount(connection, gc.gameVault);
         console.log(`  Game Vault:   ${GRN}${fmtKill(gameVaultAcct.amount)}${RES} KILL`);
@@ -146,12 +147,19 @@ function fmtSol(lamports) {
         console.log(`  ${RED}(error: ${e.message})${RES}`);
     }
 
-    // ── Global stats from Supabase ──────────────────────────────────────────
+    // ── Global stats: on-chain GameConfig counters, Supabase as fallback ─────
     try {
-        const gs = await fetchGlobalStats();
+        const onChain = gc && gc.totalKillAdded !== undefined;
+        const gs = onChain ? {
+            total_units_killed:  gc.totalUnitsKilled.toString(),
+            total_reaper_killed: gc.totalReapersKilled.toString(),
+            kill_added:          gc.totalKillAdded.toString(),
+            kill_extracted:      gc.totalKillExtracted.toString(),
+            kill_burned:         gc.totalKillBurned.toString(),
+        } : await fetchGlobalStats();
         if (gs) {
             console.log("");
-            console.log(`${PNK}── Economy (indexed) ───────────────────────────────────────────────${RES}`);
+            console.log(`${PNK}── Economy (${onChain ? "on-chain" : "indexed"}) ${"─".repeat(onChain ? 50 : 51)}${RES}`);
             console.log(`  Units killed:   ${RED}${Number(gs.total_units_killed).toLocaleString()}${RES}`);
             console.log(`  Reapers killed: ${RED}${Number(gs.total_reaper_killed).toLocaleString()}${RES}`);
             console.log(`  KILL added:     ${YEL}${fmtKill(BigInt(Math.round(Number(gs.kill_added))))}${RES}`);